@@ -0,0 +1,130 @@
+use std::path::Path;
+use std::error::Error;
+
+use crate::utils::{ensure_not_ascii, PaceMmap};
+use crate::header::{Header, TableClass};
+use crate::{PaceData, ThermalScatteringData, PhotoatomicData, PhotonuclearData, DosimetryData};
+
+//=====================================================================
+// Any of the supported ACE tables, chosen from the class letter at the
+// end of the ZAID in the header. Incident charged-particle tables share
+// the continuous-energy neutron layout and are parsed as PaceData.
+//=====================================================================
+#[derive(Clone)]
+pub enum AceTable {
+    ContinuousNeutron(PaceData),
+    ThermalScattering(ThermalScatteringData),
+    Photoatomic(PhotoatomicData),
+    Photonuclear(PhotonuclearData),
+    Dosimetry(DosimetryData),
+    ChargedParticle(PaceData),
+}
+
+impl AceTable {
+    pub fn from_file<P: AsRef<Path>>(file_path: P) -> Result<Self, Box<dyn Error + Send + Sync>> {
+        let path = file_path.as_ref();
+
+        // If we have an ASCII file, request that it first be converted to our own binary format
+        ensure_not_ascii(path)?;
+
+        // The file is only mapped once, the header decides which table to parse from it
+        let mmap = PaceMmap::from_file(path)?;
+        let header = Header::from_PACE(&mmap)?;
+        let table_class = header.table_class()
+            .ok_or_else(|| format!("Table {} in file {} is of an unsupported class", header.zaid, path.display()))?;
+
+        Ok(match table_class {
+            TableClass::ContinuousNeutron => Self::ContinuousNeutron(PaceData::from_PACE(&mmap)?),
+            TableClass::ThermalScattering => Self::ThermalScattering(ThermalScatteringData::from_PACE(&mmap)?),
+            TableClass::Photoatomic => Self::Photoatomic(PhotoatomicData::from_PACE(&mmap)?),
+            TableClass::Photonuclear => Self::Photonuclear(PhotonuclearData::from_PACE(&mmap)?),
+            TableClass::Dosimetry => Self::Dosimetry(DosimetryData::from_PACE(&mmap)?),
+            TableClass::ChargedParticle => Self::ChargedParticle(PaceData::from_PACE(&mmap)?),
+        })
+    }
+
+    // Header of the table
+    pub fn header(&self) -> &Header {
+        match self {
+            Self::ContinuousNeutron(data) | Self::ChargedParticle(data) => &data.header,
+            Self::ThermalScattering(data) => &data.header,
+            Self::Photoatomic(data) => &data.header,
+            Self::Photonuclear(data) => &data.header,
+            Self::Dosimetry(data) => &data.header,
+        }
+    }
+
+    // Class of the table
+    pub fn table_class(&self) -> TableClass {
+        match self {
+            Self::ContinuousNeutron(_) => TableClass::ContinuousNeutron,
+            Self::ThermalScattering(_) => TableClass::ThermalScattering,
+            Self::Photoatomic(_) => TableClass::Photoatomic,
+            Self::Photonuclear(_) => TableClass::Photonuclear,
+            Self::Dosimetry(_) => TableClass::Dosimetry,
+            Self::ChargedParticle(_) => TableClass::ChargedParticle,
+        }
+    }
+
+    // ZAID of the table
+    #[inline]
+    pub fn zaid(&self) -> String {
+        self.header().zaid.clone()
+    }
+
+    // SZAID of the table (version 2.0.0 and later)
+    #[inline]
+    pub fn szaid(&self) -> Option<String> {
+        self.header().szaid.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::utils::{
+        get_parsed_test_file,
+        get_parsed_thermal_test_file,
+        get_parsed_photoatomic_test_file,
+        get_parsed_photonuclear_test_file,
+        get_parsed_dosimetry_test_file,
+        get_parsed_charged_particle_test_file,
+    };
+
+    #[tokio::test]
+    async fn test_table_dispatch() {
+        // Make sure every test file has been converted before loading it without knowing its class
+        let tables = [
+            (get_parsed_test_file().await.szaid(), TableClass::ContinuousNeutron),
+            (get_parsed_thermal_test_file().await.szaid(), TableClass::ThermalScattering),
+            (get_parsed_photoatomic_test_file().await.szaid(), TableClass::Photoatomic),
+            (get_parsed_photonuclear_test_file().await.szaid(), TableClass::Photonuclear),
+            (get_parsed_dosimetry_test_file().await.szaid(), TableClass::Dosimetry),
+            (get_parsed_charged_particle_test_file().await.szaid(), TableClass::ChargedParticle),
+        ];
+
+        for (szaid, table_class) in tables {
+            let szaid = szaid.unwrap();
+            let table = AceTable::from_file(format!("test_nuclear_data_files/{szaid}.pace")).unwrap();
+            assert_eq!(table.table_class(), table_class);
+            assert_eq!(table.szaid(), Some(szaid));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_charged_particle_dispatch() {
+        let _ = get_parsed_charged_particle_test_file().await;
+        let table = AceTable::from_file("test_nuclear_data_files/1003.800o.pace").unwrap();
+        match table {
+            AceTable::ChargedParticle(data) => assert_eq!(data.projectile(), crate::blocks::ParticleType::Deuteron),
+            _ => panic!("Expected a charged-particle table"),
+        }
+    }
+
+    #[test]
+    fn test_reject_ascii() {
+        let result = AceTable::from_file("LICENSE");
+        assert!(result.is_err());
+    }
+}
@@ -127,7 +127,7 @@ impl EquiprobableBinsAngularDistribution {
 
         // Ensure all cos_theta_bins are in the range [-1, 1]
         for &cos_theta in &cos_theta_bins {
-            if cos_theta < -1.0 || cos_theta > 1.0 {
+            if !(-1.0..=1.0).contains(&cos_theta) {
                 return Err(EquiprobableBinsAngularDistributionError::BinOutOfRange(cos_theta));
             }
         }
@@ -64,7 +64,7 @@ impl EnergyDependentAngularDistribution {
     }
 }
 
-impl<'a> std::fmt::Display for EnergyDependentAngularDistribution {
+impl std::fmt::Display for EnergyDependentAngularDistribution {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "EnergyDependentAngularDistribution({} energies)", self.energy.len())
     }
@@ -1,9 +1,15 @@
 mod angular_distribution_types;
 mod energy_angle_distribution;
 
+// Traits
+pub use angular_distribution_types::SampleAngle;
+
 // Structs
 pub use angular_distribution_types::AngularDistribution;
 pub use angular_distribution_types::IsotropicAngularDistribution;
 pub use angular_distribution_types::TabulatedAngularDistribution;
 pub use angular_distribution_types::EquiprobableBinsAngularDistribution;
 pub use energy_angle_distribution::EnergyDependentAngularDistribution;
+
+// Errors
+pub use angular_distribution_types::{TabulatedAngularDistributionError, EquiprobableBinsAngularDistributionError};
@@ -6,10 +6,12 @@ mod xxs;
 pub use izaw::IzawArray;
 pub use nxs::NxsArray;
 pub use jxs::JxsArray;
-pub use xxs::XxsArray;
+pub use xxs::{XxsArray, XxsWords};
 
-pub struct Arrays <'a> {
+// The NXS and JXS arrays of a table along with its XXS array. The XXS array has a lifetime of its
+// own, so that blocks pulled from a memory mapped file can outlive the decoded NXS and JXS arrays.
+pub struct Arrays<'a, 'mmap> {
     pub nxs: &'a NxsArray,
     pub jxs: &'a JxsArray,
-    pub xxs: &'a XxsArray,
+    pub xxs: XxsArray<'mmap>,
 }
\ No newline at end of file
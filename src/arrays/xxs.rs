@@ -1 +1,206 @@
-pub type XxsArray = [f64];
\ No newline at end of file
+use std::ops::Deref;
+
+use crate::pace_error::{PaceError, check_bounds};
+
+// Largest magnitude up to which every integer is exactly representable as an f64
+const MAX_EXACT_INTEGER: f64 = (1_u64 << 53) as f64;
+
+//=====================================================================
+// The XXS array of a table, or a block of data within it, along with
+// the types of its words. Every word of the XXS array of a PACE file
+// is stored as an f64, with integer words holding their (exact)
+// integral value, and a bitmap recording which of the words were
+// integers in the ACE file. Sub-slices keep their place in the bitmap,
+// so integers read from anywhere in a block are checked against it.
+//
+// Data which does not come from a PACE file (for example in tests) has
+// no bitmap, in which case any integral word is taken as an integer.
+//
+// The words themselves are available as a plain f64 slice through
+// Deref, for lengths and float arithmetic.
+//=====================================================================
+#[derive(Debug, Clone, Copy)]
+pub struct XxsArray<'a> {
+    words: &'a [f64],
+    // Bitmap of the integer words, packed into u64s, and the bit of the first word
+    types: Option<&'a [u64]>,
+    first_bit: usize,
+}
+
+impl<'a> XxsArray<'a> {
+    // The words of a PACE file XXS array along with its type bitmap
+    pub fn new(words: &'a [f64], types: &'a [u64]) -> Self {
+        Self { words, types: Some(types), first_bit: 0 }
+    }
+
+    // Words whose types are not known
+    pub fn untyped(words: &'a [f64]) -> Self {
+        Self { words, types: None, first_bit: 0 }
+    }
+
+    // The words, borrowed for as long as the underlying data
+    pub fn words(&self) -> &'a [f64] {
+        self.words
+    }
+
+    // Whether word `index` is an integer, or has an integral value if the types are not known
+    pub fn is_integer(&self, index: usize) -> bool {
+        match self.types {
+            Some(types) => {
+                let bit = self.first_bit + index;
+                types.get(bit / 64).is_some_and(|bits| bits & (1 << (bit % 64)) != 0)
+            },
+            None => self.words.get(index).is_some_and(|word| word.fract() == 0.0),
+        }
+    }
+
+    // Bounds checked sub-slice, starting at `start` and `length` words long
+    pub fn slice(&self, start: usize, length: usize) -> Result<Self, PaceError> {
+        let end = start.checked_add(length).ok_or(PaceError::LocatorOutOfBounds { index: start, length: self.len() })?;
+        check_bounds(end, self.len())?;
+        Ok(self.sub_slice(start, end))
+    }
+
+    // Bounds checked sub-slice from `start` to the end of the words
+    pub fn tail(&self, start: usize) -> Result<Self, PaceError> {
+        if start > self.len() {
+            return Err(PaceError::LocatorOutOfBounds { index: start, length: self.len() });
+        }
+        Ok(self.sub_slice(start, self.len()))
+    }
+
+    // Sub-slice from `start` to `end`, or None if it is out of bounds
+    pub fn get_range(&self, start: usize, end: usize) -> Option<Self> {
+        (start <= end && end <= self.len()).then(|| self.sub_slice(start, end))
+    }
+
+    fn sub_slice(&self, start: usize, end: usize) -> Self {
+        Self { words: &self.words[start..end], types: self.types, first_bit: self.first_bit + start }
+    }
+
+    fn word(&self, index: usize) -> Result<f64, PaceError> {
+        self.words.get(index).copied().ok_or(PaceError::LocatorOutOfBounds { index, length: self.len() })
+    }
+
+    fn word_to_int(&self, index: usize) -> Result<i64, PaceError> {
+        let word = self.word(index)?;
+        if !self.is_integer(index) {
+            return Err(PaceError::FloatAsInteger { index, word });
+        }
+        if word.fract() != 0.0 || word.abs() > MAX_EXACT_INTEGER {
+            return Err(PaceError::NotAnInteger { index, word });
+        }
+        Ok(word as i64)
+    }
+
+    fn word_to_uint(&self, index: usize) -> Result<usize, PaceError> {
+        let int = self.word_to_int(index)?;
+        usize::try_from(int).map_err(|_| PaceError::NotAnInteger { index, word: int as f64 })
+    }
+}
+
+impl Deref for XxsArray<'_> {
+    type Target = [f64];
+
+    fn deref(&self) -> &[f64] {
+        self.words
+    }
+}
+
+//=====================================================================
+// Typed access to the words of the XXS array, or of a block of data
+// within it. Integers are read by checking the type of the word rather
+// than by reinterpreting its bits. Indices are relative to the start of
+// the slice, and reads past its end are errors rather than panics.
+//=====================================================================
+pub trait XxsWords<'a> {
+    // Word `index` as a float
+    fn float(&self, index: usize) -> Result<f64, PaceError>;
+
+    // Word `index` as an integer
+    fn int(&self, index: usize) -> Result<i64, PaceError>;
+
+    // Word `index` as a non-negative integer, such as a count, locator or MT number
+    fn uint(&self, index: usize) -> Result<usize, PaceError>;
+
+    // `length` words starting at `start` as floats
+    fn floats(&self, start: usize, length: usize) -> Result<&'a [f64], PaceError>;
+
+    // `length` words starting at `start` as integers
+    fn ints(&self, start: usize, length: usize) -> Result<Vec<i64>, PaceError>;
+
+    // `length` words starting at `start` as non-negative integers
+    fn uints(&self, start: usize, length: usize) -> Result<Vec<usize>, PaceError>;
+}
+
+impl<'a> XxsWords<'a> for XxsArray<'a> {
+    fn float(&self, index: usize) -> Result<f64, PaceError> {
+        self.word(index)
+    }
+
+    fn int(&self, index: usize) -> Result<i64, PaceError> {
+        self.word_to_int(index)
+    }
+
+    fn uint(&self, index: usize) -> Result<usize, PaceError> {
+        self.word_to_uint(index)
+    }
+
+    fn floats(&self, start: usize, length: usize) -> Result<&'a [f64], PaceError> {
+        Ok(self.slice(start, length)?.words)
+    }
+
+    fn ints(&self, start: usize, length: usize) -> Result<Vec<i64>, PaceError> {
+        self.slice(start, length)?;
+        (start..start + length).map(|index| self.word_to_int(index)).collect()
+    }
+
+    fn uints(&self, start: usize, length: usize) -> Result<Vec<usize>, PaceError> {
+        self.slice(start, length)?;
+        (start..start + length).map(|index| self.word_to_uint(index)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_typed_words() {
+        let words = [12.0, -1.0, -0.0, 2.5, f64::NAN];
+        let data = XxsArray::untyped(&words);
+        assert_eq!(data.int(0).unwrap(), 12);
+        assert_eq!(data.int(1).unwrap(), -1);
+        assert_eq!(data.int(2).unwrap(), 0);
+        assert_eq!(data.uint(2).unwrap(), 0);
+        assert_eq!(data.float(3).unwrap(), 2.5);
+        assert_eq!(data.ints(0, 3).unwrap(), vec![12, -1, 0]);
+
+        // Non-integral words, negative counts and reads past the end of the data
+        assert!(matches!(data.int(3), Err(PaceError::FloatAsInteger { index: 3, .. })));
+        assert!(matches!(data.int(4), Err(PaceError::FloatAsInteger { index: 4, .. })));
+        assert!(matches!(data.uint(1), Err(PaceError::NotAnInteger { index: 1, .. })));
+        assert!(matches!(data.uints(0, 2), Err(PaceError::NotAnInteger { index: 1, .. })));
+        assert!(matches!(data.int(5), Err(PaceError::LocatorOutOfBounds { index: 5, .. })));
+    }
+
+    #[test]
+    fn test_type_bitmap() {
+        // Words 0 and 2 are integers, word 1 is a float which happens to be integral
+        let words = [3.0, 1.0, 7.0, 0.5];
+        let types = [0b101];
+        let data = XxsArray::new(&words, &types);
+        assert_eq!(data.int(0).unwrap(), 3);
+        assert_eq!(data.float(1).unwrap(), 1.0);
+        assert!(matches!(data.int(1), Err(PaceError::FloatAsInteger { index: 1, .. })));
+        assert!(matches!(data.uints(0, 3), Err(PaceError::FloatAsInteger { index: 1, .. })));
+
+        // Sub-slices keep their place in the bitmap
+        let tail = data.tail(1).unwrap();
+        assert!(tail.int(0).is_err());
+        assert_eq!(tail.int(1).unwrap(), 7);
+        assert_eq!(data.slice(2, 2).unwrap().floats(0, 2).unwrap(), &[7.0, 0.5]);
+        assert!(data.slice(3, 2).is_err());
+        assert!(data.tail(5).is_err());
+    }
+}
@@ -0,0 +1,141 @@
+use std::collections::HashMap;
+use std::ops::Deref;
+
+use crate::arrays::{Arrays, XxsArray, XxsWords};
+use crate::blocks::{BlockType, LANDP};
+use crate::blocks::block_traits::{get_block_start, get_block_length_from_next_block, required_block, pull_block_range, PullFromXXS, XxsBlock, Process};
+use crate::pace_error::PaceError;
+use crate::angular_distributions::{
+    AngularDistribution,
+    IsotropicAngularDistribution,
+    EquiprobableBinsAngularDistribution,
+    EnergyDependentAngularDistribution,
+};
+
+type AngularDistributionMap = HashMap<usize, EnergyDependentAngularDistribution>;
+
+// Number of points which bound the 32 equiprobable cosine bins
+const NUM_EQUIPROBABLE_BIN_BOUNDARIES: usize = 33;
+
+
+//=====================================================================
+// ANDP data block
+//
+// Contains energy-dependent angular distributions for all photon
+// production reactions. Unlike the AND block, photon angular
+// distributions are only given as 32 equiprobable cosine bins.
+//=====================================================================
+#[derive(Debug, Clone)]
+pub struct ANDP ( pub AngularDistributionMap );
+
+impl Deref for ANDP {
+    type Target = AngularDistributionMap;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<'a> PullFromXXS<'a> for ANDP {
+    const BLOCK_TYPE: BlockType = BlockType::ANDP;
+
+    fn pull_from_xxs_array(arrays: &Arrays<'_, 'a>) -> Result<Option<XxsBlock<'a>>, PaceError> {
+        // We expect ANDP if NXS(6) (NTRP) != 0
+        let has_photon_production = arrays.nxs.ntrp != 0;
+
+        // Validate that the block is there and get the start index
+        let Some(block_start) = get_block_start(
+            &BlockType::ANDP,
+            arrays,
+            has_photon_production,
+            "ANDP is expected if NXS(6) (NTRP) != 0, but ANDP was not found.".to_string(),
+        )? else {
+            return Ok(None);
+        };
+
+        // Calculate the block length, see the ANDP description in the ACE spec
+        // - The cosine bins of each reaction are found through locators, so we bound the block
+        //   by the start of the next block.
+        let block_length = get_block_length_from_next_block(block_start, arrays);
+
+        // Return the block's raw data as a slice
+        pull_block_range(&Self::BLOCK_TYPE, block_start, block_length, arrays)
+    }
+}
+
+impl<'a> Process<'a> for ANDP {
+    type Dependencies = &'a Option<LANDP>;
+
+    fn process(data: XxsArray<'_>, _arrays: &Arrays, landp: &Option<LANDP>) -> Result<Self, PaceError> {
+        let landp = required_block(landp, BlockType::LANDP)?;
+
+        let distributions = landp
+            .iter()
+            .map(|(&mt, &locator)| {
+                // If the locator is 0, the photons are emitted isotropically at all energies
+                if locator == 0 {
+                    return Ok((mt, EnergyDependentAngularDistribution::new_fully_isotropic()));
+                }
+
+                // Get the energies at which we have angular distributions, followed by their locators
+                let num_energy_points = data.uint(locator - 1)?;
+                let energy = data.floats(locator, num_energy_points)?.to_vec();
+                let distributions = data.uints(locator + num_energy_points, num_energy_points)?
+                    .into_iter()
+                    .map(|bins_locator| match bins_locator {
+                        // If the locator is zero, we have an isotropic distribution
+                        0 => Ok(AngularDistribution::Isotropic(IsotropicAngularDistribution {})),
+                        // Otherwise, we have a 32-bin equiprobable distribution
+                        bins_locator => {
+                            let cos_theta_bins = data.floats(bins_locator - 1, NUM_EQUIPROBABLE_BIN_BOUNDARIES)?;
+                            Ok(AngularDistribution::EquiprobableBins(
+                                EquiprobableBinsAngularDistribution::new(cos_theta_bins.to_vec())?
+                            ))
+                        },
+                    })
+                    .collect::<Result<Vec<_>, PaceError>>()?;
+
+                Ok((mt, EnergyDependentAngularDistribution { energy, distributions }))
+            })
+            .collect::<Result<_, PaceError>>()?;
+
+        Ok(Self(distributions))
+    }
+}
+
+impl std::fmt::Display for ANDP {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ANDP({} reactions)", self.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::utils::get_parsed_test_file;
+    use crate::angular_distributions::SampleAngle;
+    use crate::UnitF64;
+
+    #[tokio::test]
+    async fn test_andp_parsing() {
+        let parsed_ace = get_parsed_test_file().await;
+        let andp = parsed_ace.data_blocks.ANDP.unwrap();
+
+        // Photons from MT 18001 are isotropic at all energies
+        assert_eq!(andp.len(), 2);
+        assert_eq!(andp.get(&18001).unwrap(), &EnergyDependentAngularDistribution::new_fully_isotropic());
+
+        // Photons from MT 18002 are isotropic at low energies, and given as equiprobable bins at high energies
+        let photon_dist = andp.get(&18002).unwrap();
+        assert_eq!(photon_dist.energy, vec![1.0E-11, 3.0E+01]);
+        assert_eq!(photon_dist.distributions[0], AngularDistribution::Isotropic(IsotropicAngularDistribution {}));
+        let bins = match &photon_dist.distributions[1] {
+            AngularDistribution::EquiprobableBins(bins) => bins,
+            _ => panic!("This should be an equiprobable bin distribution"),
+        };
+        assert_eq!(bins.sample_cos_theta(UnitF64(0.0)).unwrap(), -1.0);
+        assert_eq!(bins.sample_cos_theta(UnitF64(0.5)).unwrap(), 0.0);
+        assert_eq!(bins.sample_cos_theta(UnitF64(1.0)).unwrap(), 1.0);
+    }
+}
@@ -1,7 +1,8 @@
-use crate::arrays::Arrays;
+use crate::arrays::{Arrays, XxsArray, XxsWords};
 use crate::interpolation::InterpolationTable;
 use crate::blocks::BlockType;
-use crate::blocks::block_traits::{get_block_start, block_range_to_slice, PullFromXXS, Process};
+use crate::blocks::block_traits::{get_block_start, pull_block_range, PullFromXXS, XxsBlock, Process};
+use crate::pace_error::PaceError;
 
 //=====================================================================
 // BDD data block
@@ -17,17 +18,21 @@ pub struct BDD {
 }
 
 impl<'a> PullFromXXS<'a> for BDD {
-    fn pull_from_xxs_array(arrays: &'a Arrays) -> Option<&'a [f64]> {
+    const BLOCK_TYPE: BlockType = BlockType::BDD;
+
+    fn pull_from_xxs_array(arrays: &Arrays<'_, 'a>) -> Result<Option<XxsBlock<'a>>, PaceError> {
         // We expect BDD if JXS(2) != 0
         let is_fissile = arrays.jxs.get(&BlockType::NU) != 0;
 
         // Validate that the block is there and get the start indexx
-        let block_start = get_block_start(
+        let Some(block_start) = get_block_start(
             &BlockType::BDD,
             arrays,
             is_fissile,
             "BDD is expected if JXS(2) != 0, but BDD was not found.".to_string(),
-        )?;
+        )? else {
+            return Ok(None);
+        };
 
         let mut block_length = 0;
 
@@ -36,19 +41,19 @@ impl<'a> PullFromXXS<'a> for BDD {
             // Account for the decay constant
             block_length += 1;
             // Get the length of the precursor group data
-            let precursor_group_data_length = InterpolationTable::get_table_length(block_start + block_length, arrays.xxs);
+            let precursor_group_data_length = InterpolationTable::get_table_length(block_start + block_length, arrays.xxs)?;
             block_length += precursor_group_data_length;
         }
 
         // Return the block's raw data as a slice
-        Some(block_range_to_slice(block_start, block_length, arrays))
+        pull_block_range(&Self::BLOCK_TYPE, block_start, block_length, arrays)
     }
 }
 
 impl<'a> Process<'a> for BDD {
     type Dependencies = ();
 
-    fn process(data: &[f64], arrays: &Arrays, _dependencies: ()) -> Self {
+    fn process(data: XxsArray<'_>, arrays: &Arrays, _dependencies: ()) -> Result<Self, PaceError> {
         let mut decay_constants = Vec::new();
         let mut precursor_tables = Vec::new();
 
@@ -56,15 +61,15 @@ impl<'a> Process<'a> for BDD {
         let mut offset = 0;
         for _ in 0..arrays.nxs.npcr {
             // Grab the decay constant
-            decay_constants.push(data[offset] * 1e8);
+            decay_constants.push(data.float(offset)? * 1e8);
             offset += 1;
             // Construct the interpolation table which describes probabilities for the precursor group
-            let precursor_group_data_length = InterpolationTable::get_table_length(offset, &data);
-            precursor_tables.push(InterpolationTable::process(&data[offset..offset+precursor_group_data_length]));
+            let precursor_group_data_length = InterpolationTable::get_table_length(offset, data)?;
+            precursor_tables.push(InterpolationTable::process(data.slice(offset, precursor_group_data_length)?)?);
             offset += precursor_group_data_length;
         }
 
-        BDD {decay_constants, precursor_tables}
+        Ok(BDD {decay_constants, precursor_tables})
     }
 }
 
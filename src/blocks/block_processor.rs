@@ -1,7 +1,5 @@
-use std::error::Error;
-use std::time::Instant;
-
 use crate::utils::PaceMmap;
+use crate::header::Header;
 use crate::blocks::{
     ESZ,
     MTR,
@@ -14,9 +12,29 @@ use crate::blocks::{
     TYR,
     LAND,
     AND, // Ensure AND implements a trait for dynamic dispatch
+    LDLW,
+    DLW,
+    GPD,
+    MTRP,
+    LSIGP,
+    SIGP,
+    LANDP,
+    ANDP,
+    LDLWP,
+    DLWP,
+    YP,
+    FIS,
+    LUND,
+    DNEDL,
+    DNED,
+    PTYPE,
+    NTRO,
+    NEXT,
+    ParticleProduction,
 };
 use crate::blocks::block_traits::Parse;
-use crate::arrays::{Arrays, JxsArray, NxsArray, XxsArray};
+use crate::pace_error::PaceError;
+use crate::arrays::{Arrays, JxsArray, NxsArray};
 
 #[derive(Clone, Debug, Default)]
 pub struct DataBlocks {
@@ -31,13 +49,32 @@ pub struct DataBlocks {
     pub TYR: Option<TYR>,
     pub LAND: Option<LAND>,
     pub AND: Option<AND>,
+    pub LDLW: Option<LDLW>,
+    pub DLW: Option<DLW>,
+    pub GPD: Option<GPD>,
+    pub MTRP: Option<MTRP>,
+    pub LSIGP: Option<LSIGP>,
+    pub SIGP: Option<SIGP>,
+    pub LANDP: Option<LANDP>,
+    pub ANDP: Option<ANDP>,
+    pub LDLWP: Option<LDLWP>,
+    pub DLWP: Option<DLWP>,
+    pub YP: Option<YP>,
+    pub FIS: Option<FIS>,
+    pub LUND: Option<LUND>,
+    pub DNEDL: Option<DNEDL>,
+    pub DNED: Option<DNED>,
+    pub PTYPE: Option<PTYPE>,
+    pub NTRO: Option<NTRO>,
+    pub NEXT: Option<NEXT>,
+    pub ParticleProduction: Option<ParticleProduction>,
 }
 
 impl DataBlocks {
-    pub fn from_PACE(mmap: &PaceMmap, nxs_array: &NxsArray, jxs_array: &JxsArray) -> Result<Self, Box<dyn Error>> {
-        // Recall that this array is returned as f64's, we will parse these values back to
-        // integers where appropriate later
-        let xxs_array: &XxsArray = mmap.xxs_array();
+    pub fn from_PACE(mmap: &PaceMmap, header: &Header, nxs_array: &NxsArray, jxs_array: &JxsArray) -> Result<Self, PaceError> {
+        // Recall that this array is returned as f64's along with their types, we will parse these
+        // values back to integers where appropriate later
+        let xxs_array = mmap.xxs_array();
 
         // Construct the Arrays struct
         let arrays = Arrays {
@@ -51,96 +88,100 @@ impl DataBlocks {
         // Blocks which are always present
         // -------------------------------
         // Energy grid
-        let mut start = Instant::now();
-        let esz = ESZ::parse(&arrays, ());
-        println!(
-            "⚛️  ESZ time ⚛️ : {} us",
-            start.elapsed().as_micros()
-        );
+        let esz = timed("ESZ", || ESZ::parse(&arrays, ()))?;
 
         // -------------------------------------------
         // Blocks present if isotope has reactions
         // other than elastic scattering (NXS(4) != 0)
         // -------------------------------------------
         // Reaction MT values
-        start = Instant::now();
-        let mtr = MTR::parse(&arrays, ());
-        println!(
-            "⚛️  MTR time ⚛️ : {} us",
-            start.elapsed().as_micros()
-        );
+        let mtr = timed("MTR", || MTR::parse(&arrays, ()))?;
         // Q values
-        start = Instant::now();
-        let lqr = LQR::parse(&arrays, &mtr);
-        println!(
-            "⚛️  LQR time ⚛️ : {} us",
-            start.elapsed().as_micros()
-        );
+        let lqr = timed("LQR", || LQR::parse(&arrays, &mtr))?;
         // Cross section locations
-        start = Instant::now();
-        let lsig = LSIG::parse(&arrays, ());
-        println!(
-            "⚛️  LSIG time ⚛️ : {} us",
-            start.elapsed().as_micros()
-        );
+        let lsig = timed("LSIG", || LSIG::parse(&arrays, ()))?;
         // Cross section values
-        start = Instant::now();
-        let sig = SIG::parse(&arrays, (&mtr, &lsig, &esz));
-        println!(
-            "⚛️  SIG time ⚛️ : {} us",
-            start.elapsed().as_micros()
-        );
+        let sig = timed("SIG", || SIG::parse(&arrays, (&mtr, &lsig, &esz)))?;
         // Secondary neutron information
-        start = Instant::now();
-        let tyr = TYR::parse(&arrays, &mtr);
-        println!(
-            "⚛️  TYR time ⚛️ : {} us",
-            start.elapsed().as_micros()
-        );
+        let tyr = timed("TYR", || TYR::parse(&arrays, &mtr))?;
+
+        // -------------------------------------------
+        // Blocks present if isotope has unresolved
+        // resonance data (JXS(23) != 0)
+        // -------------------------------------------
+        // Probability tables
+        let lund = timed("LUND", || LUND::parse(&arrays, ()))?;
 
         // -------------------------------------------
         // Blocks present if fission nu data is
         // available (JXS(2) != 0)
         // -------------------------------------------
         // Fission nu values
-        start = Instant::now();
-        let nu = NU::parse(&arrays, ());
-        println!(
-            "⚛️  NU time ⚛️ : {} us",
-            start.elapsed().as_micros()
-        );
+        let nu = timed("NU", || NU::parse(&arrays, ()))?;
         // Fission dnu values
-        start = Instant::now();
-        let dnu = DNU::parse(&arrays, ());
-        println!(
-            "⚛️  DNU time ⚛️ : {} us",
-            start.elapsed().as_micros()
-        );
+        let dnu = timed("DNU", || DNU::parse(&arrays, ()))?;
         // Fission precursor data values
-        start = Instant::now();
-        let bdd = BDD::parse(&arrays, ());
-        println!(
-            "⚛️  BDD time ⚛️ : {} us",
-            start.elapsed().as_micros()
-        );
+        let bdd = timed("BDD", || BDD::parse(&arrays, ()))?;
+        // Delayed neutron energy distribution locations
+        let dnedl = timed("DNEDL", || DNEDL::parse(&arrays, ()))?;
+        // Delayed neutron energy distributions
+        let dned = timed("DNED", || DNED::parse(&arrays, &dnedl))?;
+        // Total fission cross section (JXS(21) != 0)
+        let fis = timed("FIS", || FIS::parse(&arrays, &esz))?;
 
         // --------------------------------------------------------------------------------
         // Blocks which are always present, but where having MTR makes them easier to parse
         // --------------------------------------------------------------------------------
         // Secondary neutron angular distribution locations
-        start = Instant::now();
-        let land = LAND::parse(&arrays, &mtr);
-        println!(
-            "⚛️  LAND time ⚛️ : {} us",
-            start.elapsed().as_micros()
-        );
+        let land = timed("LAND", || LAND::parse(&arrays, &mtr))?;
         // Secondary neutron angular distributions
-        start = Instant::now();
-        let and = AND::parse(&arrays, (&tyr, &land));
-        println!(
-            "⚛️  AND time ⚛️ : {} us",
-            start.elapsed().as_micros()
-        );
+        let and = timed("AND", || AND::parse(&arrays, (&tyr, &land)))?;
+
+        // -------------------------------------------
+        // Blocks present if isotope has reactions
+        // which produce secondary neutrons
+        // (NXS(5) != 0)
+        // -------------------------------------------
+        // Secondary neutron energy distribution locations
+        let ldlw = timed("LDLW", || LDLW::parse(&arrays, &mtr))?;
+        // Secondary neutron energy distributions
+        let dlw = timed("DLW", || DLW::parse(&arrays, (&tyr, &ldlw, &lqr, header.atomic_mass_fraction)))?;
+
+        // -------------------------------------------
+        // Blocks present if isotope has photon
+        // production reactions (NXS(6) != 0)
+        // -------------------------------------------
+        // Total photon production cross section
+        let gpd = timed("GPD", || GPD::parse(&arrays, &esz))?;
+        // Photon production MT values
+        let mtrp = timed("MTRP", || MTRP::parse(&arrays, ()))?;
+        // Photon production cross section locations
+        let lsigp = timed("LSIGP", || LSIGP::parse(&arrays, ()))?;
+        // Photon production cross sections and yields
+        let sigp = timed("SIGP", || SIGP::parse(&arrays, (&mtrp, &lsigp, &esz)))?;
+        // Photon angular distribution locations
+        let landp = timed("LANDP", || LANDP::parse(&arrays, &mtrp))?;
+        // Photon angular distributions
+        let andp = timed("ANDP", || ANDP::parse(&arrays, &landp))?;
+        // Photon energy distribution locations
+        let ldlwp = timed("LDLWP", || LDLWP::parse(&arrays, &mtrp))?;
+        // Photon energy distributions
+        let dlwp = timed("DLWP", || DLWP::parse(&arrays, (&ldlwp, header.atomic_mass_fraction)))?;
+        // Photon production yield multipliers
+        let yp = timed("YP", || YP::parse(&arrays, ()))?;
+
+        // -------------------------------------------
+        // Blocks present if isotope has secondary
+        // particle production data (NXS(7) != 0)
+        // -------------------------------------------
+        // Particle types
+        let ptype = timed("PTYPE", || PTYPE::parse(&arrays, ()))?;
+        // Number of production reactions for each particle type
+        let ntro = timed("NTRO", || NTRO::parse(&arrays, ()))?;
+        // Particle production data locations (IXS array)
+        let next = timed("NEXT", || NEXT::parse(&arrays, ()))?;
+        // Particle production data
+        let particle_production = timed("Particle production", || ParticleProduction::parse(&arrays, (&ptype, &ntro, &next, &esz, &lqr, header.atomic_mass_fraction)))?;
 
         Ok(
             Self {
@@ -155,6 +196,25 @@ impl DataBlocks {
                 TYR: tyr,
                 LAND: land,
                 AND: and,
+                LDLW: ldlw,
+                DLW: dlw,
+                GPD: gpd,
+                MTRP: mtrp,
+                LSIGP: lsigp,
+                SIGP: sigp,
+                LANDP: landp,
+                ANDP: andp,
+                LDLWP: ldlwp,
+                DLWP: dlwp,
+                YP: yp,
+                FIS: fis,
+                LUND: lund,
+                DNEDL: dnedl,
+                DNED: dned,
+                PTYPE: ptype,
+                NTRO: ntro,
+                NEXT: next,
+                ParticleProduction: particle_production,
             }
         )
     }
@@ -165,3 +225,15 @@ impl std::fmt::Display for DataBlocks {
         todo!()
     }
 }
+
+// Parse a block, printing the time taken when the timing feature is enabled
+fn timed<T>(block: &str, parse: impl FnOnce() -> T) -> T {
+    #[cfg(feature = "timing")]
+    let start = std::time::Instant::now();
+    let result = parse();
+    #[cfg(feature = "timing")]
+    println!("⚛️  {} time ⚛️ : {} us", block, start.elapsed().as_micros());
+    #[cfg(not(feature = "timing"))]
+    let _ = block;
+    result
+}
@@ -1,12 +1,14 @@
-use crate::arrays::Arrays;
+use crate::arrays::{Arrays, XxsArray, XxsWords};
 use crate::blocks::BlockType;
+use crate::pace_error::PaceError;
 
 //=====================================================================
 // Every block in the XXS array needs to implement the following traits:
 // - PullFromXXS:
 //     - Pull the data from the XXS array, this should implement
 //       all of the logic needed to determine the end of the block.
-//       we return a slice from the XXS array.
+//       we return a slice from the XXS array, along with the index
+//       the block starts at.
 // - Process:
 //     - Process the data from the XXS array. This is all of the logic
 //       which converts the data from the slice produced by PullFromXXS
@@ -15,12 +17,26 @@ use crate::blocks::BlockType;
 // If both of these traits are implemented, we automatically implement
 // the Parse trait, which calls the two other traits in order to parse
 // the data from the XXS array.
+//
+// Malformed data is reported with a PaceError rather than a panic.
 //=====================================================================
 
+// A block pulled from the XXS array. The data borrows from the XXS array itself rather than
+// from the Arrays, so a block pulled from a memory mapped file lives as long as the mapping.
+#[derive(Debug, Clone, Copy)]
+pub struct XxsBlock<'a> {
+    // Zero indexed XXS index of the first word of the block
+    pub start: usize,
+    pub data: XxsArray<'a>,
+}
+
 // Pull from the XXS array, return a slice of the XXS array if the block exists.
 // If the block does not exist, return None.
 pub trait PullFromXXS<'a> {
-    fn pull_from_xxs_array(arrays: &'a Arrays) -> Option<&'a [f64]>
+    // The block that errors raised while processing the data are attributed to
+    const BLOCK_TYPE: BlockType;
+
+    fn pull_from_xxs_array(arrays: &Arrays<'_, 'a>) -> Result<Option<XxsBlock<'a>>, PaceError>
     where
         Self: Sized;
 }
@@ -32,7 +48,7 @@ pub trait PullFromXXS<'a> {
 pub trait Process<'a> {
     type Dependencies;
 
-    fn process(data: &'a [f64], arrays: &Arrays, dependencies: Self::Dependencies) -> Self
+    fn process(data: XxsArray<'a>, arrays: &Arrays, dependencies: Self::Dependencies) -> Result<Self, PaceError>
     where
         Self: Sized;
 }
@@ -40,16 +56,20 @@ pub trait Process<'a> {
 // Pull a block from the XXS array and process it into the final data structure.
 // This is the main function which is called to parse a block from the XXS array,
 // and it is implemented for all blocks which implement the PullFromXXS and Process traits.
+// Errors raised while processing the block are tagged with the block and its XXS index.
 pub trait Parse<'a>: PullFromXXS<'a> + Process<'a> {
-    fn parse(arrays: &'a Arrays, dependencies: Self::Dependencies) -> Option<Self>
+    fn parse(arrays: &Arrays<'_, 'a>, dependencies: Self::Dependencies) -> Result<Option<Self>, PaceError>
     where
         Self: Sized,
     {
-        if let Some(data) = Self::pull_from_xxs_array(arrays) {
-            Some(Self::process(data, arrays, dependencies))
-        } else {
-            None
-        }
+        // Errors found while working out the extent of the block are given the block's JXS start
+        let jxs_start = arrays.jxs.get(&Self::BLOCK_TYPE).saturating_sub(1);
+        let Some(block) = Self::pull_from_xxs_array(arrays).map_err(|error| error.in_block(Self::BLOCK_TYPE, jxs_start))? else {
+            return Ok(None);
+        };
+        Self::process(block.data, arrays, dependencies)
+            .map(Some)
+            .map_err(|error| error.in_block(Self::BLOCK_TYPE, block.start))
     }
 }
 
@@ -65,39 +85,89 @@ where
 
 // Once the NXS and JXS arrays are loaded, we can use them to determine whether or not
 // a block is expected to be present in the XXS array. This function takes in a boolean
-// indicating whether or not the block is expected to be present, and we panic if the block
-// is not present when it is expected, or if the block is present when it is not expected,
-// both of which are indicative of a bug in the code or a serious error in the PACE file.
-pub fn get_block_start(block_type: &BlockType, arrays: &Arrays, is_expected: bool, panic_message: String) -> Option<usize> {
+// indicating whether or not the block is expected to be present, and we return an error if
+// the block is not present when it is expected, or if the block is present when it is not
+// expected, both of which are indicative of a bug in the code or a serious error in the PACE file.
+pub fn get_block_start(block_type: &BlockType, arrays: &Arrays, is_expected: bool, missing_message: String) -> Result<Option<usize>, PaceError> {
     // If the block type's start index is non-zero, the block is present in the XXS array
     let start_index = arrays.jxs.get(block_type);
-    // The block is expected
-    if is_expected {
-        // Panic if the block is not present - something has gone very wrong.
-        if start_index == 0 {
-            panic!("{}", panic_message);
-        } else {
-            // The block is present, return the start index
-            // Note that the XXS array in the PACE binary format is zero
-            // indexed (which does not match the ACE spec)
-           return Some(start_index - 1);
-        }
-    // The block is not expected
-    } else {
-        // If the block is present, panic - something has gone very wrong.
-        if start_index != 0 {
-            panic!("{}: Block was found when it was not expected.", block_type);
-        } else {
-            // The block is not present, return None
-            return None;
-        }
+    match (is_expected, start_index) {
+        // The block is expected but not present - something has gone very wrong.
+        (true, 0) => Err(PaceError::MissingBlock { block: block_type.clone(), reason: missing_message }),
+        // The block is present, but starts past the end of the XXS array
+        (true, start_index) if start_index > arrays.xxs.len() => Err(PaceError::BlockOutOfBounds {
+            block: block_type.clone(),
+            index: start_index - 1,
+            length: arrays.xxs.len(),
+        }),
+        // The block is present, return the start index
+        // Note that the XXS array in the PACE binary format is zero
+        // indexed (which does not match the ACE spec)
+        (true, start_index) => Ok(Some(start_index - 1)),
+        // The block is not expected and not present
+        (false, 0) => Ok(None),
+        // The block is present when it is not expected - something has gone very wrong.
+        (false, _) => Err(PaceError::UnexpectedBlock(block_type.clone())),
     }
 }
 
-pub fn block_range_to_slice<'a>(block_start: usize, block_length: usize, arrays: &'a Arrays) -> &'a [f64] {
+// A block (or one of its dependencies) which must have been parsed for another block to be processed
+pub fn required_block<T>(block: &Option<T>, block_type: BlockType) -> Result<&T, PaceError> {
+    block.as_ref().ok_or(PaceError::MissingBlock {
+        block: block_type,
+        reason: "it is needed to process another block".to_string(),
+    })
+}
+
+// Read a single integer word of the XXS array while working out the length of a block
+pub fn get_xxs_int(block_type: &BlockType, index: usize, arrays: &Arrays) -> Result<i64, PaceError> {
+    arrays.xxs.int(index).map_err(|error| match error {
+        PaceError::LocatorOutOfBounds { .. } => PaceError::BlockOutOfBounds {
+            block: block_type.clone(),
+            index,
+            length: arrays.xxs.len(),
+        },
+        error => error,
+    })
+}
+
+// Read a single non-negative integer word of the XXS array while working out the length of a block
+pub fn get_xxs_uint(block_type: &BlockType, index: usize, arrays: &Arrays) -> Result<usize, PaceError> {
+    let word = get_xxs_int(block_type, index, arrays)?;
+    usize::try_from(word).map_err(|_| PaceError::NotAnInteger { index, word: word as f64 })
+}
+
+// Some blocks (e.g. DLW) are made up of chains of data linked by internal locators, which makes
+// walking the block to find its end slow and error prone. For these blocks, we bound the block by
+// the start of the next block in the XXS array, or by the end of the XXS array if there is none.
+pub fn get_block_length_from_next_block(block_start: usize, arrays: &Arrays) -> usize {
+    let block_end = arrays.jxs
+        .iter()
+        // END is the location of the last word of the conventional table, not the start of a block
+        .filter(|(block_type, _)| **block_type != BlockType::END)
+        // Note that the JXS array is one indexed
+        .map(|(_, &start_index)| start_index)
+        .filter(|&start_index| start_index > block_start + 1)
+        .min()
+        .map(|start_index| start_index - 1)
+        .unwrap_or(arrays.xxs.len());
+    block_end.min(arrays.xxs.len()).saturating_sub(block_start)
+}
+
+pub fn block_range_to_slice<'a>(block_type: &BlockType, block_start: usize, block_length: usize, arrays: &Arrays<'_, 'a>) -> Result<XxsArray<'a>, PaceError> {
     let mut block_end = block_start + block_length;
     if block_end == arrays.xxs.len() + 1 {
         block_end -= 1;
     }
-    &arrays.xxs[block_start..block_end]
-}
\ No newline at end of file
+    arrays.xxs.get_range(block_start, block_end).ok_or(PaceError::BlockOutOfBounds {
+        block: block_type.clone(),
+        index: block_end - 1,
+        length: arrays.xxs.len(),
+    })
+}
+
+// The block from block_start to block_start + block_length, as returned by PullFromXXS
+pub fn pull_block_range<'a>(block_type: &BlockType, block_start: usize, block_length: usize, arrays: &Arrays<'_, 'a>) -> Result<Option<XxsBlock<'a>>, PaceError> {
+    let data = block_range_to_slice(block_type, block_start, block_length, arrays)?;
+    Ok(Some(XxsBlock { start: block_start, data }))
+}
@@ -0,0 +1,189 @@
+use std::collections::HashMap;
+use std::ops::Deref;
+
+use crate::arrays::{Arrays, XxsArray};
+use crate::interpolation::InterpolationTable;
+use crate::energy_distributions::{SecondaryEnergyDistribution, ReactionKinematics};
+use crate::blocks::{BlockType, TYR, LQR, LDLW, NumberOfExitingNeutrons};
+use crate::blocks::block_traits::{get_block_start, get_block_length_from_next_block, required_block, pull_block_range, PullFromXXS, XxsBlock, Process};
+use crate::pace_error::PaceError;
+
+type EnergyDistributionMap = HashMap<usize, SecondaryEnergyDistribution>;
+
+//=====================================================================
+// DLW data block
+//
+// Contains the secondary energy distributions for all reactions which
+// produce secondary neutrons, given as a chain of energy distribution
+// laws for each reaction. Energy-dependent neutron yields for
+// reactions with |TYR| > 100 are also stored in this block.
+//=====================================================================
+#[derive(Debug, Clone)]
+pub struct DLW {
+    pub distributions: EnergyDistributionMap,
+    pub neutron_yields: HashMap<usize, InterpolationTable>,
+}
+
+impl Deref for DLW {
+    type Target = EnergyDistributionMap;
+
+    fn deref(&self) -> &Self::Target {
+        &self.distributions
+    }
+}
+
+impl<'a> PullFromXXS<'a> for DLW {
+    const BLOCK_TYPE: BlockType = BlockType::DLW;
+
+    fn pull_from_xxs_array(arrays: &Arrays<'_, 'a>) -> Result<Option<XxsBlock<'a>>, PaceError> {
+        // We expect DLW if NXS(5) (NR) != 0
+        let has_secondary_neutrons = arrays.nxs.nr != 0;
+
+        // Validate that the block is there and get the start index
+        let Some(block_start) = get_block_start(
+            &BlockType::DLW,
+            arrays,
+            has_secondary_neutrons,
+            "DLW is expected if NXS(5) (NR) != 0, but DLW was not found.".to_string(),
+        )? else {
+            return Ok(None);
+        };
+
+        // Calculate the block length, see the DLW description in the ACE spec
+        // - The DLW block is a set of chains of laws linked by locators, with each law having its own
+        //   layout. Rather than walking every law, we bound the block by the start of the next block.
+        let block_length = get_block_length_from_next_block(block_start, arrays);
+
+        // Return the block's raw data as a slice
+        pull_block_range(&Self::BLOCK_TYPE, block_start, block_length, arrays)
+    }
+}
+
+impl<'a> Process<'a> for DLW {
+    // The atomic weight ratio from the header is needed for laws which use the reaction kinematics
+    type Dependencies = (&'a Option<TYR>, &'a Option<LDLW>, &'a Option<LQR>, f64);
+
+    fn process(data: XxsArray<'_>, _arrays: &Arrays, dependencies: (&Option<TYR>, &Option<LDLW>, &Option<LQR>, f64)) -> Result<Self, PaceError> {
+        let (tyr, ldlw, lqr, awr) = (
+            required_block(dependencies.0, BlockType::TYR)?,
+            required_block(dependencies.1, BlockType::LDLW)?,
+            required_block(dependencies.2, BlockType::LQR)?,
+            dependencies.3,
+        );
+
+        // Loop over the reactions with secondary neutrons and follow their chain of laws
+        let distributions: EnergyDistributionMap = ldlw
+            .iter()
+            .map(|(mt, &locator)| {
+                let kinematics = ReactionKinematics { awr, q_value: lqr.get(mt).copied().unwrap_or(0.0) };
+                Ok((*mt, SecondaryEnergyDistribution::process(data, locator, kinematics)?))
+            })
+            .collect::<Result<_, PaceError>>()?;
+
+        // Pull out the tabulated neutron yields for reactions which have them
+        let neutron_yields = tyr
+            .iter()
+            .filter_map(|(mt, exiting_neutron_data)| match exiting_neutron_data.neutron_release {
+                NumberOfExitingNeutrons::TabulatedYield(locator) => {
+                    // The yield table has its own interpolation parameters, so we can process it directly
+                    Some(data.tail(locator - 1).and_then(InterpolationTable::process).map(|table| (*mt, table)))
+                },
+                _ => None,
+            })
+            .collect::<Result<_, PaceError>>()?;
+
+        Ok(Self { distributions, neutron_yields })
+    }
+}
+
+impl std::fmt::Display for DLW {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "DLW({} reactions)", self.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::get_parsed_test_file;
+    use crate::helpers::MTNumber;
+    use crate::interpolation::InterpolationScheme;
+    use crate::energy_distributions::{EnergyDistribution, SampleEnergy};
+    use crate::UnitF64;
+
+    #[tokio::test]
+    async fn test_dlw_parsing() {
+        let parsed_ace = get_parsed_test_file().await;
+        let dlw = parsed_ace.data_blocks.DLW.unwrap();
+
+        // Check that the reactions with energy distributions are present
+        assert_eq!(dlw.len(), 1);
+        assert!(dlw.neutron_yields.is_empty());
+        let fission_dist = dlw.get(&(MTNumber::Fission as usize)).unwrap();
+
+        // The fission spectrum is given as an evaporation spectrum followed by a continuous tabular distribution
+        assert_eq!(fission_dist.laws.len(), 2);
+        assert_eq!(fission_dist.laws[0].law(), 9);
+        assert_eq!(fission_dist.laws[1].law(), 4);
+        assert_eq!(fission_dist.laws[0].applicability.interpolate(1.0).unwrap(), 0.5);
+        assert_eq!(fission_dist.laws[1].applicability.interpolate(1.0).unwrap(), 0.5);
+    }
+
+    #[tokio::test]
+    async fn test_dlw_law_parsing() {
+        let parsed_ace = get_parsed_test_file().await;
+        let dlw = parsed_ace.data_blocks.DLW.unwrap();
+        let fission_dist = dlw.get(&(MTNumber::Fission as usize)).unwrap();
+
+        // Evaporation spectrum
+        let evaporation = match &fission_dist.laws[0].distribution {
+            EnergyDistribution::Evaporation(evaporation) => evaporation,
+            _ => panic!("This should be an evaporation spectrum"),
+        };
+        assert_eq!(evaporation.temperature.interpolate(1.0E-11).unwrap(), 1.0);
+        assert_eq!(evaporation.temperature.interpolate(3.0E+01).unwrap(), 2.0);
+        assert_eq!(evaporation.restriction_energy, 0.5);
+
+        // Continuous tabular distribution
+        let tabular = match &fission_dist.laws[1].distribution {
+            EnergyDistribution::ContinuousTabular(tabular) => tabular,
+            _ => panic!("This should be a continuous tabular distribution"),
+        };
+        assert_eq!(tabular.incident_energy.energy, vec![1.0E-11, 3.0E+01]);
+        assert_eq!(tabular.distributions.len(), 2);
+        assert_eq!(tabular.distributions[0].interpolation_scheme, InterpolationScheme::LinLin);
+        assert_eq!(tabular.distributions[0].energy_out, vec![0.0, 1.0, 2.0]);
+        assert_eq!(tabular.distributions[0].pdf, vec![0.5, 0.5, 0.0]);
+        assert_eq!(tabular.distributions[0].cdf, vec![0.0, 0.5, 1.0]);
+        assert_eq!(tabular.distributions[1].interpolation_scheme, InterpolationScheme::Histogram);
+        assert_eq!(tabular.distributions[1].energy_out, vec![0.0, 4.0]);
+        assert_eq!(tabular.distributions[1].pdf, vec![0.25, 0.0]);
+        assert_eq!(tabular.distributions[1].cdf, vec![0.0, 1.0]);
+    }
+
+    #[tokio::test]
+    async fn test_dlw_sampling() {
+        let parsed_ace = get_parsed_test_file().await;
+        let dlw = parsed_ace.data_blocks.DLW.unwrap();
+        let fission_dist = dlw.get(&(MTNumber::Fission as usize)).unwrap();
+
+        // The first random number picks the law, each law is applicable with probability 0.5.
+        // The continuous tabular law is sampled from the lower table at the bottom of the grid.
+        let mut values = vec![0.75, 0.25].into_iter();
+        let mut rng = || UnitF64(values.next().unwrap());
+        let sample = fission_dist.sample_energy(1.0E-11, &mut rng).unwrap();
+        assert!((sample.energy - 0.5).abs() < 1e-12);
+        assert_eq!(sample.cos_theta, None);
+
+        // Neither law can produce neutrons past the end of the interpolated outgoing energy grid
+        let mut state = 1u64;
+        let mut rng = || {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            UnitF64(((state >> 11) as f64 + 0.5) / (1u64 << 53) as f64)
+        };
+        for _ in 0..1000 {
+            let sample = fission_dist.sample_energy(1.0, &mut rng).unwrap();
+            assert!(sample.energy >= 0.0);
+            assert!(sample.energy <= 4.0);
+        }
+    }
+}
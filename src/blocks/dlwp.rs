@@ -0,0 +1,125 @@
+use std::collections::HashMap;
+use std::ops::Deref;
+
+use crate::arrays::{Arrays, XxsArray};
+use crate::energy_distributions::{SecondaryEnergyDistribution, ReactionKinematics};
+use crate::blocks::{BlockType, LDLWP};
+use crate::blocks::block_traits::{get_block_start, get_block_length_from_next_block, required_block, pull_block_range, PullFromXXS, XxsBlock, Process};
+use crate::pace_error::PaceError;
+
+type EnergyDistributionMap = HashMap<usize, SecondaryEnergyDistribution>;
+
+//=====================================================================
+// DLWP data block
+//
+// Contains the energy distributions for all photon production
+// reactions, given as a chain of energy distribution laws for each
+// reaction in the same form as the DLW block.
+//=====================================================================
+#[derive(Debug, Clone)]
+pub struct DLWP ( pub EnergyDistributionMap );
+
+impl Deref for DLWP {
+    type Target = EnergyDistributionMap;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<'a> PullFromXXS<'a> for DLWP {
+    const BLOCK_TYPE: BlockType = BlockType::DLWP;
+
+    fn pull_from_xxs_array(arrays: &Arrays<'_, 'a>) -> Result<Option<XxsBlock<'a>>, PaceError> {
+        // We expect DLWP if NXS(6) (NTRP) != 0
+        let has_photon_production = arrays.nxs.ntrp != 0;
+
+        // Validate that the block is there and get the start index
+        let Some(block_start) = get_block_start(
+            &BlockType::DLWP,
+            arrays,
+            has_photon_production,
+            "DLWP is expected if NXS(6) (NTRP) != 0, but DLWP was not found.".to_string(),
+        )? else {
+            return Ok(None);
+        };
+
+        // Calculate the block length, see the DLWP description in the ACE spec
+        // - As with DLW, we bound the block by the start of the next block.
+        let block_length = get_block_length_from_next_block(block_start, arrays);
+
+        // Return the block's raw data as a slice
+        pull_block_range(&Self::BLOCK_TYPE, block_start, block_length, arrays)
+    }
+}
+
+impl<'a> Process<'a> for DLWP {
+    // The atomic weight ratio from the header is needed for primary photons
+    type Dependencies = (&'a Option<LDLWP>, f64);
+
+    fn process(data: XxsArray<'_>, _arrays: &Arrays, dependencies: (&Option<LDLWP>, f64)) -> Result<Self, PaceError> {
+        let (ldlwp, awr) = (
+            required_block(dependencies.0, BlockType::LDLWP)?,
+            dependencies.1,
+        );
+
+        // Photon production MT values do not have Q values, so only the target mass is needed
+        let kinematics = ReactionKinematics { awr, ..Default::default() };
+        Ok(Self(
+            ldlwp
+                .iter()
+                .map(|(mt, &locator)| Ok((*mt, SecondaryEnergyDistribution::process(data, locator, kinematics)?)))
+                .collect::<Result<_, PaceError>>()?
+        ))
+    }
+}
+
+impl std::fmt::Display for DLWP {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "DLWP({} reactions)", self.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::get_parsed_test_file;
+    use crate::energy_distributions::{EnergyDistribution, SampleEnergy};
+    use crate::UnitF64;
+
+    #[tokio::test]
+    async fn test_dlwp_parsing() {
+        let parsed_ace = get_parsed_test_file().await;
+        let dlwp = parsed_ace.data_blocks.DLWP.unwrap();
+
+        // Both reactions produce discrete photons
+        assert_eq!(dlwp.len(), 2);
+        for mt in [18001, 18002] {
+            let photon_dist = dlwp.get(&mt).unwrap();
+            assert_eq!(photon_dist.laws.len(), 1);
+            assert_eq!(photon_dist.laws[0].law(), 2);
+        }
+
+        let photon = match &dlwp.get(&18001).unwrap().laws[0].distribution {
+            EnergyDistribution::DiscretePhoton(photon) => photon,
+            _ => panic!("This should be a discrete photon distribution"),
+        };
+        assert_eq!(photon.primary_flag, 0);
+        assert_eq!(photon.photon_energy, 2.0);
+        assert_eq!(photon.awr, 99.999);
+    }
+
+    #[tokio::test]
+    async fn test_dlwp_sampling() {
+        let parsed_ace = get_parsed_test_file().await;
+        let dlwp = parsed_ace.data_blocks.DLWP.unwrap();
+        let mut rng = || UnitF64(0.5);
+
+        // Non-primary photons always have the same energy
+        let sample = dlwp.get(&18001).unwrap().sample_energy(1.0, &mut rng).unwrap();
+        assert_eq!(sample.energy, 2.0);
+
+        // Primary photons pick up part of the incident neutron energy
+        let sample = dlwp.get(&18002).unwrap().sample_energy(1.0, &mut rng).unwrap();
+        assert!((sample.energy - (1.0 + 99.999 / 100.999)).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,173 @@
+use std::ops::Deref;
+
+use crate::unitf64::UnitF64;
+use crate::arrays::{Arrays, XxsArray};
+use crate::energy_distributions::{
+    SecondaryEnergyDistribution,
+    ReactionKinematics,
+    SampleEnergy,
+    EnergyDistributionError,
+    interpolate_clamped,
+};
+use crate::blocks::{BlockType, BDD, DNEDL};
+use crate::blocks::block_traits::{get_block_start, get_block_length_from_next_block, required_block, pull_block_range, PullFromXXS, XxsBlock, Process};
+use crate::pace_error::PaceError;
+
+//=====================================================================
+// DNED data block
+//
+// Contains the delayed neutron energy spectra for each precursor
+// group, given as a chain of energy distribution laws in the same form
+// as the DLW block.
+//=====================================================================
+#[derive(Debug, Clone)]
+pub struct DNED ( pub Vec<SecondaryEnergyDistribution> );
+
+impl Deref for DNED {
+    type Target = Vec<SecondaryEnergyDistribution>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<'a> PullFromXXS<'a> for DNED {
+    const BLOCK_TYPE: BlockType = BlockType::DNED;
+
+    fn pull_from_xxs_array(arrays: &Arrays<'_, 'a>) -> Result<Option<XxsBlock<'a>>, PaceError> {
+        // We expect DNED if JXS(26) != 0
+        let has_delayed_spectra = arrays.jxs.get(&BlockType::DNEDL) != 0;
+
+        // Validate that the block is there and get the start index
+        let Some(block_start) = get_block_start(
+            &BlockType::DNED,
+            arrays,
+            has_delayed_spectra,
+            "DNED is expected if JXS(26) != 0, but DNED was not found.".to_string(),
+        )? else {
+            return Ok(None);
+        };
+
+        // Calculate the block length, see the DNED description in the ACE spec
+        // - As with DLW, we bound the block by the start of the next block.
+        let block_length = get_block_length_from_next_block(block_start, arrays);
+
+        // Return the block's raw data as a slice
+        pull_block_range(&Self::BLOCK_TYPE, block_start, block_length, arrays)
+    }
+}
+
+impl<'a> Process<'a> for DNED {
+    type Dependencies = &'a Option<DNEDL>;
+
+    fn process(data: XxsArray<'_>, _arrays: &Arrays, dnedl: &Option<DNEDL>) -> Result<Self, PaceError> {
+        // Delayed neutron spectra do not depend on the reaction kinematics
+        let kinematics = ReactionKinematics::default();
+        Ok(Self(
+            required_block(dnedl, BlockType::DNEDL)?
+                .iter()
+                .map(|&locator| SecondaryEnergyDistribution::process(data, locator, kinematics))
+                .collect::<Result<_, PaceError>>()?
+        ))
+    }
+}
+
+impl DNED {
+    // Sample a delayed neutron for a fission at an incident energy (given in MeV). The precursor
+    // group is sampled from the group probabilities in BDD, the emission time (in seconds) from
+    // the group's decay constant, and the energy from the group's spectrum.
+    pub fn sample_delayed_neutron<R: FnMut() -> UnitF64>(&self, bdd: &BDD, incident_energy: f64, rng: &mut R) -> Result<DelayedNeutron, EnergyDistributionError> {
+        if self.is_empty() {
+            return Err(EnergyDistributionError::EmptyDistribution);
+        }
+        // Every group needs a probability table, a decay constant and a spectrum
+        if bdd.precursor_tables.len() != self.len() || bdd.decay_constants.len() != self.len() {
+            return Err(EnergyDistributionError::PrecursorGroupMismatch {
+                bdd_groups: bdd.precursor_tables.len().min(bdd.decay_constants.len()),
+                dned_groups: self.len(),
+            });
+        }
+
+        // Sample the precursor group, normalizing the group probabilities in case they do not sum to one
+        let probabilities = bdd.precursor_tables
+            .iter()
+            .map(|table| interpolate_clamped(table, incident_energy))
+            .collect::<Result<Vec<f64>, _>>()?;
+        let xi = rng().0 * probabilities.iter().sum::<f64>();
+        let mut cumulative = 0.0;
+        let mut group = self.len() - 1;
+        for (i, probability) in probabilities.iter().enumerate() {
+            cumulative += probability;
+            if xi < cumulative {
+                group = i;
+                break;
+            }
+        }
+
+        // Precursors decay exponentially
+        let emission_time = -rng().0.ln() / bdd.decay_constants[group];
+        let energy = self[group].sample_energy(incident_energy, rng)?.energy;
+
+        Ok(DelayedNeutron { group, emission_time, energy })
+    }
+}
+
+impl std::fmt::Display for DNED {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "DNED({} precursor groups)", self.len())
+    }
+}
+
+// A sampled delayed neutron, the group is zero-indexed
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DelayedNeutron {
+    pub group: usize,
+    pub emission_time: f64,
+    pub energy: f64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::utils::get_parsed_test_file;
+
+    #[tokio::test]
+    async fn test_dned_parsing() {
+        let parsed_ace = get_parsed_test_file().await;
+        let dned = parsed_ace.data_blocks.DNED.unwrap();
+
+        // Each precursor group has a single continuous tabular spectrum
+        assert_eq!(dned.len(), 6);
+        for spectrum in dned.iter() {
+            assert_eq!(spectrum.laws.len(), 1);
+            assert_eq!(spectrum.laws[0].law(), 4);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_delayed_neutron_sampling() {
+        let parsed_ace = get_parsed_test_file().await;
+        let dned = parsed_ace.data_blocks.DNED.unwrap();
+        let bdd = parsed_ace.data_blocks.BDD.unwrap();
+
+        // All groups are equally likely, so 0.5 picks the fourth group. The spectrum of
+        // the fourth group is a single law, uniform up to 0.4 MeV.
+        let mut values = vec![0.5, (-1.0f64).exp(), 0.5, 0.5].into_iter();
+        let mut rng = || UnitF64(values.next().unwrap());
+        let delayed_neutron = dned.sample_delayed_neutron(&bdd, 1.0, &mut rng).unwrap();
+        assert_eq!(delayed_neutron.group, 3);
+        assert!((delayed_neutron.emission_time - 1.0 / 0.09).abs() < 1e-9);
+        assert!((delayed_neutron.energy - 0.2).abs() < 1e-9);
+
+        // BDD and DNED disagreeing on the number of groups is an error rather than a panic
+        let mut short_bdd = bdd.clone();
+        short_bdd.decay_constants.pop();
+        short_bdd.precursor_tables.pop();
+        let mut rng = || UnitF64(0.99);
+        assert!(matches!(
+            dned.sample_delayed_neutron(&short_bdd, 1.0, &mut rng),
+            Err(EnergyDistributionError::PrecursorGroupMismatch { bdd_groups: 5, dned_groups: 6 })
+        ));
+    }
+}
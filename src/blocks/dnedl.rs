@@ -0,0 +1,76 @@
+use std::ops::Deref;
+
+use crate::arrays::{Arrays, XxsArray, XxsWords};
+use crate::blocks::BlockType;
+use crate::blocks::block_traits::{get_block_start, pull_block_range, PullFromXXS, XxsBlock, Process};
+use crate::pace_error::PaceError;
+
+//=====================================================================
+// DNEDL data block
+//
+// Contains location data of the delayed neutron energy distributions
+// for each precursor group.
+//=====================================================================
+#[derive(Debug, Clone, PartialEq)]
+pub struct DNEDL ( pub Vec<usize> );
+
+impl Deref for DNEDL {
+    type Target = Vec<usize>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<'a> PullFromXXS<'a> for DNEDL {
+    const BLOCK_TYPE: BlockType = BlockType::DNEDL;
+
+    fn pull_from_xxs_array(arrays: &Arrays<'_, 'a>) -> Result<Option<XxsBlock<'a>>, PaceError> {
+        // We expect DNEDL if JXS(26) != 0
+        let has_delayed_spectra = arrays.jxs.get(&BlockType::DNEDL) != 0;
+
+        // Validate that the block is there and get the start index
+        let Some(block_start) = get_block_start(
+            &BlockType::DNEDL,
+            arrays,
+            has_delayed_spectra,
+            "DNEDL is expected if JXS(26) != 0, but DNEDL was not found.".to_string(),
+        )? else {
+            return Ok(None);
+        };
+
+        // Calculate the block length, see the DNEDL description in the ACE spec
+        let block_length = arrays.nxs.npcr;
+
+        // Return the block's raw data as a slice
+        pull_block_range(&Self::BLOCK_TYPE, block_start, block_length, arrays)
+    }
+}
+
+impl<'a> Process<'a> for DNEDL {
+    type Dependencies = ();
+
+    fn process(data: XxsArray<'_>, _arrays: &Arrays, _dependencies: ()) -> Result<Self, PaceError> {
+        Ok(Self(data.uints(0, data.len())?))
+    }
+}
+
+impl std::fmt::Display for DNEDL {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "DNEDL({} precursor groups)", self.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::get_parsed_test_file;
+
+    #[tokio::test]
+    async fn test_dnedl_parsing() {
+        let parsed_ace = get_parsed_test_file().await;
+
+        // Check contents
+        let dnedl = parsed_ace.data_blocks.DNEDL.unwrap();
+        assert_eq!(dnedl.0, vec![1, 22, 43, 64, 85, 106]);
+    }
+}
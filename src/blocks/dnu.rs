@@ -1,9 +1,10 @@
 use anyhow::Result;
 
-use crate::arrays::Arrays;
+use crate::arrays::{Arrays, XxsArray};
 use crate::interpolation::InterpolationTable;
 use crate::blocks::BlockType;
-use crate::blocks::block_traits::{get_block_start, block_range_to_slice, PullFromXXS, Process};
+use crate::blocks::block_traits::{get_block_start, pull_block_range, PullFromXXS, XxsBlock, Process};
+use crate::pace_error::PaceError;
 
 //=====================================================================
 // DNU data block
@@ -22,33 +23,37 @@ impl DNU {
 }
 
 impl<'a> PullFromXXS<'a> for DNU {
-    fn pull_from_xxs_array(arrays: &'a Arrays) -> Option<&'a [f64]> {
+    const BLOCK_TYPE: BlockType = BlockType::DNU;
+
+    fn pull_from_xxs_array(arrays: &Arrays<'_, 'a>) -> Result<Option<XxsBlock<'a>>, PaceError> {
         // We expect DNU if JXS(2) != 0
         let is_fissile = arrays.jxs.get(&BlockType::NU) != 0;
 
         // Validate that the block is there and get the start indexx
-        let block_start = get_block_start(
+        let Some(block_start) = get_block_start(
             &BlockType::DNU,
             arrays,
             is_fissile,
             "DNU is expected if JXS(2) != 0, but DNU was not found.".to_string(),
-        )?;
+        )? else {
+            return Ok(None);
+        };
 
         // Calculate the block length, see the DNU description in the ACE spec
         let mut block_length = 1;
-        block_length += InterpolationTable::get_table_length(block_start + block_length, arrays.xxs);
+        block_length += InterpolationTable::get_table_length(block_start + block_length, arrays.xxs)?;
 
         // Return the block's raw data as a slice
-        Some(block_range_to_slice(block_start, block_length, arrays))
+        pull_block_range(&Self::BLOCK_TYPE, block_start, block_length, arrays)
     }
 }
 
 impl<'a> Process<'a> for DNU {
     type Dependencies = ();
 
-    fn process(data: &[f64], _arrays: &Arrays, _dependencies: ()) -> Self {
+    fn process(data: XxsArray<'_>, _arrays: &Arrays, _dependencies: ()) -> Result<Self, PaceError> {
         // Construct the interpolation table which describes probabilities for the precursor group
-        Self(InterpolationTable::process(&data[1..]))
+        Ok(Self(InterpolationTable::process(data.tail(1)?)?))
     }
 }
 
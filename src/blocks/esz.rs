@@ -1,6 +1,7 @@
-use crate::arrays::Arrays;
+use crate::arrays::{Arrays, XxsArray, XxsWords};
 use crate::blocks::BlockType;
-use crate::blocks::block_traits::{get_block_start, block_range_to_slice, PullFromXXS, Process};
+use crate::blocks::block_traits::{get_block_start, pull_block_range, PullFromXXS, XxsBlock, Process};
+use crate::pace_error::PaceError;
 
 //=====================================================================
 // ESZ data block
@@ -20,43 +21,103 @@ pub struct ESZ {
 }
 
 impl<'a> PullFromXXS<'a> for ESZ {
-    fn pull_from_xxs_array(arrays: &'a Arrays) -> Option<&'a [f64]> {
+    const BLOCK_TYPE: BlockType = BlockType::ESZ;
+
+    fn pull_from_xxs_array(arrays: &Arrays<'_, 'a>) -> Result<Option<XxsBlock<'a>>, PaceError> {
         // We always expect ESZ to be present in the ACE file.
         let always_expected = true;
 
         // Get the starting index of the block in the XXS array
-        let block_start = get_block_start(
+        let Some(block_start) = get_block_start(
             &BlockType::ESZ,
             arrays,
             always_expected,
             "Every ACE file should have an ESZ block, but one was not found.".to_string(),
-        )?;
+        )? else {
+            return Ok(None);
+        };
 
         // Calculate the block length, see the ESZ description in the ACE spec
         let num_energies = arrays.nxs.nes;
         let block_length = 5 * num_energies;
 
         // Return the block's raw data as a slice
-        Some(block_range_to_slice(block_start, block_length, arrays))
+        pull_block_range(&Self::BLOCK_TYPE, block_start, block_length, arrays)
     }
 }
 
 impl<'a> Process<'a> for ESZ {
     type Dependencies = ();
 
-    fn process(data: &[f64], arrays: &Arrays, _dependencies: ()) -> Self {
-        let num_energy_points = arrays.nxs.nes;
-        let energy = Vec::from(&data[0..num_energy_points]);
-        let total_xs = Vec::from(&data[num_energy_points..2 * num_energy_points]);
-        let dissapearance_xs = Vec::from(&data[2 * num_energy_points..3 * num_energy_points]);
-        let elastic_xs = Vec::from(&data[3 * num_energy_points..4 * num_energy_points]);
-        let average_heating_numbers = Vec::from(&data[4 * num_energy_points..5 * num_energy_points]);
+    fn process(data: XxsArray<'_>, arrays: &Arrays, _dependencies: ()) -> Result<Self, PaceError> {
+        EszView::process(data, arrays.nxs.nes).map(|view| Self::from(&view))
+    }
+}
+
+impl ESZ {
+    // Borrow the block as an EszView
+    pub fn view(&self) -> EszView<'_> {
+        EszView {
+            energy: &self.energy,
+            total_xs: &self.total_xs,
+            dissapearance_xs: &self.dissapearance_xs,
+            elastic_xs: &self.elastic_xs,
+            average_heating_numbers: &self.average_heating_numbers,
+        }
+    }
+
+    // Elastic scattering cross section at an energy (given in MeV) with linear-linear interpolation,
+    // held constant outside of the energy grid
+    pub fn evaluate_elastic(&self, energy: f64) -> f64 {
+        self.view().evaluate_elastic(energy)
+    }
+}
+
+impl From<&EszView<'_>> for ESZ {
+    fn from(view: &EszView<'_>) -> Self {
         Self {
-            energy,
-            total_xs,
-            dissapearance_xs,
-            elastic_xs,
-            average_heating_numbers,
+            energy: view.energy.to_vec(),
+            total_xs: view.total_xs.to_vec(),
+            dissapearance_xs: view.dissapearance_xs.to_vec(),
+            elastic_xs: view.elastic_xs.to_vec(),
+            average_heating_numbers: view.average_heating_numbers.to_vec(),
+        }
+    }
+}
+
+//=====================================================================
+// Borrowed view of the ESZ block, which holds slices of the XXS array
+// rather than copies of them. Convert to an ESZ to own the data.
+//=====================================================================
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EszView<'a> {
+    pub energy: &'a [f64],
+    pub total_xs: &'a [f64],
+    pub dissapearance_xs: &'a [f64],
+    pub elastic_xs: &'a [f64],
+    pub average_heating_numbers: &'a [f64],
+}
+
+impl<'a> EszView<'a> {
+    // Split the block data into its five arrays of NXS(3) entries
+    pub fn process(data: XxsArray<'a>, num_energies: usize) -> Result<Self, PaceError> {
+        Ok(Self {
+            energy: data.floats(0, num_energies)?,
+            total_xs: data.floats(num_energies, num_energies)?,
+            dissapearance_xs: data.floats(2 * num_energies, num_energies)?,
+            elastic_xs: data.floats(3 * num_energies, num_energies)?,
+            average_heating_numbers: data.floats(4 * num_energies, num_energies)?,
+        })
+    }
+
+    // Elastic scattering cross section at an energy (given in MeV) with linear-linear interpolation,
+    // held constant outside of the energy grid
+    pub fn evaluate_elastic(&self, energy: f64) -> f64 {
+        let (energy_grid, xs) = (self.energy, self.elastic_xs);
+        match energy_grid.partition_point(|&e| e <= energy) {
+            0 => xs.first().copied().unwrap_or(0.0),
+            i if i == energy_grid.len() => xs[i - 1],
+            i => xs[i - 1] + (energy - energy_grid[i - 1]) / (energy_grid[i] - energy_grid[i - 1]) * (xs[i] - xs[i - 1]),
         }
     }
 }
@@ -88,4 +149,14 @@ mod tests {
         assert_eq!(esz.elastic_xs, vec![5.0, 6.0, 7.0]);
         assert_eq!(esz.average_heating_numbers, vec![2.0, 4.0, 6.0]);
     }
+
+    #[tokio::test]
+    async fn test_esz_elastic_evaluation() {
+        let parsed_ace = get_parsed_test_file().await;
+        let esz = parsed_ace.data_blocks.ESZ.unwrap();
+
+        assert_eq!(esz.evaluate_elastic(0.5), 5.0);
+        assert_eq!(esz.evaluate_elastic(1.5), 5.5);
+        assert_eq!(esz.evaluate_elastic(4.0), 7.0);
+    }
 }
\ No newline at end of file
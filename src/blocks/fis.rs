@@ -0,0 +1,99 @@
+use std::ops::Deref;
+
+use crate::arrays::{Arrays, XxsArray, XxsWords};
+use crate::helpers::MTNumber;
+use crate::blocks::{BlockType, ESZ, CrossSection, CrossSectionView};
+use crate::blocks::block_traits::{get_block_start, get_xxs_uint, required_block, pull_block_range, PullFromXXS, XxsBlock, Process};
+use crate::pace_error::{PaceError, get_slice};
+
+//=====================================================================
+// FIS data block
+//
+// Contains the total fission cross section, given in the same form as
+// a single SIG entry. See the ACE format spec for a description of the
+// FIS block.
+//=====================================================================
+#[derive(Debug, Clone)]
+pub struct FIS ( pub CrossSection );
+
+impl Deref for FIS {
+    type Target = CrossSection;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<'a> PullFromXXS<'a> for FIS {
+    const BLOCK_TYPE: BlockType = BlockType::FIS;
+
+    fn pull_from_xxs_array(arrays: &Arrays<'_, 'a>) -> Result<Option<XxsBlock<'a>>, PaceError> {
+        // We expect FIS if JXS(21) != 0
+        let has_total_fission = arrays.jxs.get(&BlockType::FIS) != 0;
+
+        // Get the starting index of the block in the XXS array
+        let Some(block_start) = get_block_start(
+            &BlockType::FIS,
+            arrays,
+            has_total_fission,
+            "FIS is expected if JXS(21) != 0, but FIS was not found.".to_string(),
+        )? else {
+            return Ok(None);
+        };
+
+        // Calculate the block length, see the FIS description in the ACE spec
+        // The block is the energy grid start index, the number of entries, and the cross section values
+        let num_entries = get_xxs_uint(&Self::BLOCK_TYPE, block_start + 1, arrays)?;
+        let block_length = num_entries + 2;
+
+        // Return the block's raw data as a slice
+        pull_block_range(&Self::BLOCK_TYPE, block_start, block_length, arrays)
+    }
+}
+
+impl<'a> Process<'a> for FIS {
+    type Dependencies = &'a Option<ESZ>;
+
+    fn process(data: XxsArray<'_>, _arrays: &Arrays, esz: &Option<ESZ>) -> Result<Self, PaceError> {
+        let esz = required_block(esz, BlockType::ESZ)?;
+        Ok(Self(CrossSection::from(&FIS::process_view(data, &esz.energy)?)))
+    }
+}
+
+impl FIS {
+    // Borrow the total fission cross section out of the block data, with its energies borrowed
+    // from the ESZ energy grid
+    pub fn process_view<'a>(data: XxsArray<'a>, energy: &'a [f64]) -> Result<CrossSectionView<'a>, PaceError> {
+        // Get the first position in the energy grid where we have a cross section value
+        let energy_start_index = data.uint(0)?;
+        // Get the number of entries we have for the cross section
+        let num_xs_values = data.uint(1)?;
+
+        let energy = get_slice(energy, energy_start_index.saturating_sub(1), num_xs_values)?;
+        let xs_val = data.floats(2, num_xs_values)?;
+
+        Ok(CrossSectionView { mt: MTNumber::Fission as usize, energy, xs_val })
+    }
+}
+
+impl std::fmt::Display for FIS {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "FIS({} energies)", self.energy.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::get_parsed_test_file;
+
+    #[tokio::test]
+    async fn test_fis_parsing() {
+        let parsed_ace = get_parsed_test_file().await;
+
+        // Check contents
+        let fis = parsed_ace.data_blocks.FIS.unwrap();
+        assert_eq!(fis.mt, 18);
+        assert_eq!(fis.energy, vec![1.0, 2.0, 3.0]);
+        assert_eq!(fis.xs_val, vec![17.0, 38.0, 100.0]);
+    }
+}
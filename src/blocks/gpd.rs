@@ -0,0 +1,105 @@
+use crate::arrays::{Arrays, XxsArray};
+use crate::blocks::{BlockType, ESZ, CrossSection};
+use crate::blocks::block_traits::{get_block_start, required_block, get_block_length_from_next_block, pull_block_range, PullFromXXS, XxsBlock, Process};
+use crate::pace_error::PaceError;
+
+// MT number for total photon production
+const TOTAL_PHOTON_PRODUCTION_MT: usize = 202;
+// Older files contain a 30 x 20 matrix of equiprobable outgoing photon energies
+const NUM_OUTGOING_ENERGY_GROUPS: usize = 30;
+const NUM_EQUIPROBABLE_ENERGIES: usize = 20;
+
+//=====================================================================
+// GPD data block
+//
+// Contains the total photon production cross section on the main
+// energy grid. Older files also contain a matrix of equiprobable
+// outgoing photon energies for 30 incident neutron energy groups,
+// see the ACE format spec for a description of the GPD block.
+//=====================================================================
+#[derive(Debug, Clone)]
+pub struct GPD {
+    pub total_xs: CrossSection,
+    pub equiprobable_energies: Option<Vec<Vec<f64>>>,
+}
+
+impl<'a> PullFromXXS<'a> for GPD {
+    const BLOCK_TYPE: BlockType = BlockType::GPD;
+
+    fn pull_from_xxs_array(arrays: &Arrays<'_, 'a>) -> Result<Option<XxsBlock<'a>>, PaceError> {
+        // We expect GPD if NXS(6) (NTRP) != 0
+        let has_photon_production = arrays.nxs.ntrp != 0;
+
+        // Validate that the block is there and get the start index
+        let Some(block_start) = get_block_start(
+            &BlockType::GPD,
+            arrays,
+            has_photon_production,
+            "GPD is expected if NXS(6) (NTRP) != 0, but GPD was not found.".to_string(),
+        )? else {
+            return Ok(None);
+        };
+
+        // Calculate the block length, see the GPD description in the ACE spec
+        // - The total photon production cross section is always given on the main energy grid.
+        // - If there is room before the next block, the equiprobable outgoing energies follow.
+        let mut block_length = arrays.nxs.nes;
+        let equiprobable_length = NUM_OUTGOING_ENERGY_GROUPS * NUM_EQUIPROBABLE_ENERGIES;
+        if get_block_length_from_next_block(block_start, arrays) >= block_length + equiprobable_length {
+            block_length += equiprobable_length;
+        }
+
+        // Return the block's raw data as a slice
+        pull_block_range(&Self::BLOCK_TYPE, block_start, block_length, arrays)
+    }
+}
+
+impl<'a> Process<'a> for GPD {
+    type Dependencies = &'a Option<ESZ>;
+
+    fn process(data: XxsArray<'_>, arrays: &Arrays, esz: &Option<ESZ>) -> Result<Self, PaceError> {
+        let num_energy_points = arrays.nxs.nes;
+        let total_xs = CrossSection {
+            mt: TOTAL_PHOTON_PRODUCTION_MT,
+            energy: required_block(esz, BlockType::ESZ)?.energy.clone(),
+            xs_val: data[..num_energy_points].to_vec(),
+        };
+
+        // Pull out the equiprobable outgoing energies for each group, if they are given
+        let equiprobable_energies = if data.len() > num_energy_points {
+            Some(
+                data[num_energy_points..]
+                    .chunks(NUM_EQUIPROBABLE_ENERGIES)
+                    .map(|energies| energies.to_vec())
+                    .collect()
+            )
+        } else {
+            None
+        };
+
+        Ok(Self { total_xs, equiprobable_energies })
+    }
+}
+
+impl std::fmt::Display for GPD {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "GPD({} energies)", self.total_xs.energy.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::get_parsed_test_file;
+
+    #[tokio::test]
+    async fn test_gpd_parsing() {
+        let parsed_ace = get_parsed_test_file().await;
+
+        // Check contents
+        let gpd = parsed_ace.data_blocks.GPD.unwrap();
+        assert_eq!(gpd.total_xs.mt, 202);
+        assert_eq!(gpd.total_xs.energy, vec![1.0, 2.0, 3.0]);
+        assert_eq!(gpd.total_xs.xs_val, vec![0.5, 1.0, 1.5]);
+        assert!(gpd.equiprobable_energies.is_none());
+    }
+}
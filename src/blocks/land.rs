@@ -1,9 +1,10 @@
 use std::collections::HashMap;
 use std::ops::Deref;
 
-use crate::arrays::Arrays;
+use crate::arrays::{Arrays, XxsArray, XxsWords};
 use crate::blocks::{BlockType, MTR, TYR};
-use crate::blocks::block_traits::{get_block_start, block_range_to_slice, PullFromXXS, Process};
+use crate::blocks::block_traits::{get_block_start, pull_block_range, PullFromXXS, XxsBlock, Process};
+use crate::pace_error::PaceError;
 use crate::helpers::MTNumber;
 
 //=====================================================================
@@ -24,49 +25,53 @@ impl Deref for LAND {
 }
 
 impl<'a> PullFromXXS<'a> for LAND {
-    fn pull_from_xxs_array(arrays: &'a Arrays) -> Option<&'a [f64]> {
+    const BLOCK_TYPE: BlockType = BlockType::LAND;
+
+    fn pull_from_xxs_array(arrays: &Arrays<'_, 'a>) -> Result<Option<XxsBlock<'a>>, PaceError> {
         // We always expect LAND.
         let always_expected = true;
 
         // Validate that the block is there and get the start index
-        let block_start = get_block_start(
+        let Some(block_start) = get_block_start(
             &BlockType::LAND,
             arrays,
             always_expected,
             "LAND is always expected, but LAND was not found.".to_string(),
-        )?;
+        )? else {
+            return Ok(None);
+        };
         
         // Calculate the block length, see the LAND description in the ACE spec
         // We will always have data for elastic scattering, so we need to add 1 to the number of reactions
         let block_length = arrays.nxs.nr + 1;
 
         // Return the block's raw data as a slice
-        Some(block_range_to_slice(block_start, block_length, arrays))
+        pull_block_range(&Self::BLOCK_TYPE, block_start, block_length, arrays)
     }
 }
 
 impl<'a> Process<'a> for LAND {
     type Dependencies = &'a Option<MTR>;
 
-    fn process(data: &[f64], _arrays: &Arrays, mtr: &Option<MTR>) -> Self {
+    fn process(data: XxsArray<'_>, _arrays: &Arrays, mtr: &Option<MTR>) -> Result<Self, PaceError> {
         // If we have available cross section identifiers from MTR, use them
-        let mut angular_distribution_locs: HashMap<usize, isize> = if mtr.is_some() {
-            data[1..]
+        let mut angular_distribution_locs: HashMap<usize, isize> = match mtr {
+            Some(mtr) => mtr
                 .iter()
-                .enumerate()
-                .map(|(i, &val)| (
-                    mtr.as_ref().unwrap()[i],
-                    val.to_bits() as isize
-                ))
-                .collect()
-        } else {
-            HashMap::new()
+                .copied()
+                .zip(data.ints(1, data.len().saturating_sub(1))?)
+                .map(|(mt, locator)| (mt, locator as isize))
+                .collect(),
+            None => HashMap::new(),
         };
         
-        // We will always have an angular distribution for elastic scattering
-        angular_distribution_locs.insert(MTNumber::ElasticScattering as usize, data[0].to_bits() as isize);
+        // The first entry is always for elastic scattering. Charged-particle tables may also list
+        // nuclear elastic scattering in MTR, in which case its own entry takes precedence.
+        angular_distribution_locs
+            .entry(MTNumber::ElasticScattering as usize)
+            .or_insert(data.int(0)? as isize);
 
-        Self ( angular_distribution_locs )
+        Ok(Self ( angular_distribution_locs ))
     }
 }
 
@@ -77,15 +82,18 @@ impl LAND {
             // Get the reaction types with neutron release from the TYR block and remove
             // those which are shown in LAND as not having a distribution.
             for mt in tyr_block.keys() {
-                if let Some(&val) = self.get(mt) {
-                    if val != -1 {
-                        mt_vals.push(*mt);
-                    }
+                if let Some(&val) = self.get(mt) && val != -1 {
+                    mt_vals.push(*mt);
                 }
             }
         }
-        // Add in elastic scattering, which is always present in the AND block but is not in TYR
-        mt_vals.push(MTNumber::ElasticScattering as usize);
+        // Add in elastic scattering, which is not in TYR. Neutron tables always give an elastic
+        // distribution, but charged-particle tables do not tabulate Coulomb scattering and mark the
+        // elastic entry with -1.
+        let elastic = MTNumber::ElasticScattering as usize;
+        if !mt_vals.contains(&elastic) && self.get(&elastic).is_some_and(|&val| val != -1) {
+            mt_vals.push(elastic);
+        }
         mt_vals
     }
 }
@@ -0,0 +1,81 @@
+use std::collections::HashMap;
+use std::ops::Deref;
+
+use crate::arrays::{Arrays, XxsArray, XxsWords};
+use crate::blocks::{BlockType, MTRP};
+use crate::blocks::block_traits::{get_block_start, required_block, pull_block_range, PullFromXXS, XxsBlock, Process};
+use crate::pace_error::PaceError;
+
+//=====================================================================
+// LANDP data block
+//
+// Contains location data of angular distributions for all photon
+// production reactions. A locator of 0 means that the photons are
+// emitted isotropically and no distribution is given.
+//=====================================================================
+#[derive(Debug, Clone, PartialEq)]
+pub struct LANDP ( pub HashMap<usize, usize> );
+
+impl Deref for LANDP {
+    type Target = HashMap<usize, usize>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<'a> PullFromXXS<'a> for LANDP {
+    const BLOCK_TYPE: BlockType = BlockType::LANDP;
+
+    fn pull_from_xxs_array(arrays: &Arrays<'_, 'a>) -> Result<Option<XxsBlock<'a>>, PaceError> {
+        // We expect LANDP if NXS(6) (NTRP) != 0
+        let has_photon_production = arrays.nxs.ntrp != 0;
+
+        // Validate that the block is there and get the start index
+        let Some(block_start) = get_block_start(
+            &BlockType::LANDP,
+            arrays,
+            has_photon_production,
+            "LANDP is expected if NXS(6) (NTRP) != 0, but LANDP was not found.".to_string(),
+        )? else {
+            return Ok(None);
+        };
+
+        // Calculate the block length, see the LANDP description in the ACE spec
+        let block_length = arrays.nxs.ntrp;
+
+        // Return the block's raw data as a slice
+        pull_block_range(&Self::BLOCK_TYPE, block_start, block_length, arrays)
+    }
+}
+
+impl<'a> Process<'a> for LANDP {
+    type Dependencies = &'a Option<MTRP>;
+
+    fn process(data: XxsArray<'_>, _arrays: &Arrays, mtrp: &Option<MTRP>) -> Result<Self, PaceError> {
+        let mtrp = required_block(mtrp, BlockType::MTRP)?;
+        Ok(Self(mtrp.iter().copied().zip(data.uints(0, data.len())?).collect()))
+    }
+}
+
+impl std::fmt::Display for LANDP {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "LANDP({} reactions)", self.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::get_parsed_test_file;
+
+    #[tokio::test]
+    async fn test_landp_parsing() {
+        let parsed_ace = get_parsed_test_file().await;
+
+        // Check contents
+        let landp = parsed_ace.data_blocks.LANDP.unwrap();
+        assert_eq!(landp.len(), 2);
+        assert_eq!(landp.get(&18001), Some(&0));
+        assert_eq!(landp.get(&18002), Some(&1));
+    }
+}
@@ -0,0 +1,373 @@
+use std::sync::OnceLock;
+
+use crate::utils::PaceMmap;
+use crate::header::Header;
+use crate::blocks::{
+    ESZ,
+    MTR,
+    LSIG,
+    SIG,
+    LQR,
+    NU,
+    DNU,
+    BDD,
+    TYR,
+    LAND,
+    AND,
+    LDLW,
+    DLW,
+    GPD,
+    MTRP,
+    LSIGP,
+    SIGP,
+    LANDP,
+    ANDP,
+    LDLWP,
+    DLWP,
+    YP,
+    FIS,
+    LUND,
+    DNEDL,
+    DNED,
+    PTYPE,
+    NTRO,
+    NEXT,
+    ParticleProduction,
+    DataBlocks,
+};
+use crate::blocks::block_traits::Parse;
+use crate::pace_error::PaceError;
+use crate::arrays::{Arrays, JxsArray, NxsArray};
+
+//=====================================================================
+// The same blocks as DataBlocks, but each block is only parsed the
+// first time that it is asked for, and is cached from then on. The
+// blocks that a block depends on (e.g. MTR and ESZ for SIG) are parsed
+// along with it. Tools that only need a few blocks out of many files
+// skip the cost of parsing everything else.
+//
+// Each block is returned as a reference to an Option, in the same way
+// as the fields of DataBlocks. A block which fails to parse is not
+// cached, so asking for it again gives the same error.
+//=====================================================================
+pub struct LazyDataBlocks {
+    mmap: PaceMmap,
+    nxs_array: NxsArray,
+    jxs_array: JxsArray,
+    atomic_mass_fraction: f64,
+    ESZ: OnceLock<Option<ESZ>>,
+    MTR: OnceLock<Option<MTR>>,
+    LSIG: OnceLock<Option<LSIG>>,
+    SIG: OnceLock<Option<SIG>>,
+    LQR: OnceLock<Option<LQR>>,
+    NU: OnceLock<Option<NU>>,
+    DNU: OnceLock<Option<DNU>>,
+    BDD: OnceLock<Option<BDD>>,
+    TYR: OnceLock<Option<TYR>>,
+    LAND: OnceLock<Option<LAND>>,
+    AND: OnceLock<Option<AND>>,
+    LDLW: OnceLock<Option<LDLW>>,
+    DLW: OnceLock<Option<DLW>>,
+    GPD: OnceLock<Option<GPD>>,
+    MTRP: OnceLock<Option<MTRP>>,
+    LSIGP: OnceLock<Option<LSIGP>>,
+    SIGP: OnceLock<Option<SIGP>>,
+    LANDP: OnceLock<Option<LANDP>>,
+    ANDP: OnceLock<Option<ANDP>>,
+    LDLWP: OnceLock<Option<LDLWP>>,
+    DLWP: OnceLock<Option<DLWP>>,
+    YP: OnceLock<Option<YP>>,
+    FIS: OnceLock<Option<FIS>>,
+    LUND: OnceLock<Option<LUND>>,
+    DNEDL: OnceLock<Option<DNEDL>>,
+    DNED: OnceLock<Option<DNED>>,
+    PTYPE: OnceLock<Option<PTYPE>>,
+    NTRO: OnceLock<Option<NTRO>>,
+    NEXT: OnceLock<Option<NEXT>>,
+    ParticleProduction: OnceLock<Option<ParticleProduction>>,
+}
+
+// Parse a block on first access, and hand out the cached block afterwards
+fn get_or_parse<T>(cell: &OnceLock<Option<T>>, parse: impl FnOnce() -> Result<Option<T>, PaceError>) -> Result<&Option<T>, PaceError> {
+    if let Some(block) = cell.get() {
+        return Ok(block);
+    }
+    let block = parse()?;
+    Ok(cell.get_or_init(|| block))
+}
+
+impl LazyDataBlocks {
+    pub fn from_PACE(mmap: PaceMmap, header: &Header, nxs_array: &NxsArray, jxs_array: &JxsArray) -> Self {
+        Self {
+            mmap,
+            nxs_array: nxs_array.clone(),
+            jxs_array: jxs_array.clone(),
+            atomic_mass_fraction: header.atomic_mass_fraction,
+            ESZ: OnceLock::new(),
+            MTR: OnceLock::new(),
+            LSIG: OnceLock::new(),
+            SIG: OnceLock::new(),
+            LQR: OnceLock::new(),
+            NU: OnceLock::new(),
+            DNU: OnceLock::new(),
+            BDD: OnceLock::new(),
+            TYR: OnceLock::new(),
+            LAND: OnceLock::new(),
+            AND: OnceLock::new(),
+            LDLW: OnceLock::new(),
+            DLW: OnceLock::new(),
+            GPD: OnceLock::new(),
+            MTRP: OnceLock::new(),
+            LSIGP: OnceLock::new(),
+            SIGP: OnceLock::new(),
+            LANDP: OnceLock::new(),
+            ANDP: OnceLock::new(),
+            LDLWP: OnceLock::new(),
+            DLWP: OnceLock::new(),
+            YP: OnceLock::new(),
+            FIS: OnceLock::new(),
+            LUND: OnceLock::new(),
+            DNEDL: OnceLock::new(),
+            DNED: OnceLock::new(),
+            PTYPE: OnceLock::new(),
+            NTRO: OnceLock::new(),
+            NEXT: OnceLock::new(),
+            ParticleProduction: OnceLock::new(),
+        }
+    }
+
+    fn arrays(&self) -> Arrays<'_, '_> {
+        Arrays { nxs: &self.nxs_array, jxs: &self.jxs_array, xxs: self.mmap.xxs_array() }
+    }
+
+    // Parse every block which has not been parsed yet, and copy all of them into a DataBlocks
+    pub fn to_data_blocks(&self) -> Result<DataBlocks, PaceError> {
+        Ok(DataBlocks {
+            ESZ: self.ESZ()?.clone(),
+            MTR: self.MTR()?.clone(),
+            LSIG: self.LSIG()?.clone(),
+            SIG: self.SIG()?.clone(),
+            LQR: self.LQR()?.clone(),
+            NU: self.NU()?.clone(),
+            DNU: self.DNU()?.clone(),
+            BDD: self.BDD()?.clone(),
+            TYR: self.TYR()?.clone(),
+            LAND: self.LAND()?.clone(),
+            AND: self.AND()?.clone(),
+            LDLW: self.LDLW()?.clone(),
+            DLW: self.DLW()?.clone(),
+            GPD: self.GPD()?.clone(),
+            MTRP: self.MTRP()?.clone(),
+            LSIGP: self.LSIGP()?.clone(),
+            SIGP: self.SIGP()?.clone(),
+            LANDP: self.LANDP()?.clone(),
+            ANDP: self.ANDP()?.clone(),
+            LDLWP: self.LDLWP()?.clone(),
+            DLWP: self.DLWP()?.clone(),
+            YP: self.YP()?.clone(),
+            FIS: self.FIS()?.clone(),
+            LUND: self.LUND()?.clone(),
+            DNEDL: self.DNEDL()?.clone(),
+            DNED: self.DNED()?.clone(),
+            PTYPE: self.PTYPE()?.clone(),
+            NTRO: self.NTRO()?.clone(),
+            NEXT: self.NEXT()?.clone(),
+            ParticleProduction: self.ParticleProduction()?.clone(),
+        })
+    }
+
+    // Energy grid
+    pub fn ESZ(&self) -> Result<&Option<ESZ>, PaceError> {
+        get_or_parse(&self.ESZ, || ESZ::parse(&self.arrays(), ()))
+    }
+
+    // Reaction MT values
+    pub fn MTR(&self) -> Result<&Option<MTR>, PaceError> {
+        get_or_parse(&self.MTR, || MTR::parse(&self.arrays(), ()))
+    }
+
+    // Cross section locations
+    pub fn LSIG(&self) -> Result<&Option<LSIG>, PaceError> {
+        get_or_parse(&self.LSIG, || LSIG::parse(&self.arrays(), ()))
+    }
+
+    // Cross section values
+    pub fn SIG(&self) -> Result<&Option<SIG>, PaceError> {
+        get_or_parse(&self.SIG, || SIG::parse(&self.arrays(), (self.MTR()?, self.LSIG()?, self.ESZ()?)))
+    }
+
+    // Q values
+    pub fn LQR(&self) -> Result<&Option<LQR>, PaceError> {
+        get_or_parse(&self.LQR, || LQR::parse(&self.arrays(), self.MTR()?))
+    }
+
+    // Fission nu values
+    pub fn NU(&self) -> Result<&Option<NU>, PaceError> {
+        get_or_parse(&self.NU, || NU::parse(&self.arrays(), ()))
+    }
+
+    // Fission dnu values
+    pub fn DNU(&self) -> Result<&Option<DNU>, PaceError> {
+        get_or_parse(&self.DNU, || DNU::parse(&self.arrays(), ()))
+    }
+
+    // Fission precursor data values
+    pub fn BDD(&self) -> Result<&Option<BDD>, PaceError> {
+        get_or_parse(&self.BDD, || BDD::parse(&self.arrays(), ()))
+    }
+
+    // Secondary neutron information
+    pub fn TYR(&self) -> Result<&Option<TYR>, PaceError> {
+        get_or_parse(&self.TYR, || TYR::parse(&self.arrays(), self.MTR()?))
+    }
+
+    // Secondary neutron angular distribution locations
+    pub fn LAND(&self) -> Result<&Option<LAND>, PaceError> {
+        get_or_parse(&self.LAND, || LAND::parse(&self.arrays(), self.MTR()?))
+    }
+
+    // Secondary neutron angular distributions
+    pub fn AND(&self) -> Result<&Option<AND>, PaceError> {
+        get_or_parse(&self.AND, || AND::parse(&self.arrays(), (self.TYR()?, self.LAND()?)))
+    }
+
+    // Secondary neutron energy distribution locations
+    pub fn LDLW(&self) -> Result<&Option<LDLW>, PaceError> {
+        get_or_parse(&self.LDLW, || LDLW::parse(&self.arrays(), self.MTR()?))
+    }
+
+    // Secondary neutron energy distributions
+    pub fn DLW(&self) -> Result<&Option<DLW>, PaceError> {
+        get_or_parse(&self.DLW, || DLW::parse(&self.arrays(), (self.TYR()?, self.LDLW()?, self.LQR()?, self.atomic_mass_fraction)))
+    }
+
+    // Total photon production cross section
+    pub fn GPD(&self) -> Result<&Option<GPD>, PaceError> {
+        get_or_parse(&self.GPD, || GPD::parse(&self.arrays(), self.ESZ()?))
+    }
+
+    // Photon production MT values
+    pub fn MTRP(&self) -> Result<&Option<MTRP>, PaceError> {
+        get_or_parse(&self.MTRP, || MTRP::parse(&self.arrays(), ()))
+    }
+
+    // Photon production cross section locations
+    pub fn LSIGP(&self) -> Result<&Option<LSIGP>, PaceError> {
+        get_or_parse(&self.LSIGP, || LSIGP::parse(&self.arrays(), ()))
+    }
+
+    // Photon production cross sections and yields
+    pub fn SIGP(&self) -> Result<&Option<SIGP>, PaceError> {
+        get_or_parse(&self.SIGP, || SIGP::parse(&self.arrays(), (self.MTRP()?, self.LSIGP()?, self.ESZ()?)))
+    }
+
+    // Photon angular distribution locations
+    pub fn LANDP(&self) -> Result<&Option<LANDP>, PaceError> {
+        get_or_parse(&self.LANDP, || LANDP::parse(&self.arrays(), self.MTRP()?))
+    }
+
+    // Photon angular distributions
+    pub fn ANDP(&self) -> Result<&Option<ANDP>, PaceError> {
+        get_or_parse(&self.ANDP, || ANDP::parse(&self.arrays(), self.LANDP()?))
+    }
+
+    // Photon energy distribution locations
+    pub fn LDLWP(&self) -> Result<&Option<LDLWP>, PaceError> {
+        get_or_parse(&self.LDLWP, || LDLWP::parse(&self.arrays(), self.MTRP()?))
+    }
+
+    // Photon energy distributions
+    pub fn DLWP(&self) -> Result<&Option<DLWP>, PaceError> {
+        get_or_parse(&self.DLWP, || DLWP::parse(&self.arrays(), (self.LDLWP()?, self.atomic_mass_fraction)))
+    }
+
+    // Photon production yield multipliers
+    pub fn YP(&self) -> Result<&Option<YP>, PaceError> {
+        get_or_parse(&self.YP, || YP::parse(&self.arrays(), ()))
+    }
+
+    // Total fission cross section
+    pub fn FIS(&self) -> Result<&Option<FIS>, PaceError> {
+        get_or_parse(&self.FIS, || FIS::parse(&self.arrays(), self.ESZ()?))
+    }
+
+    // Probability tables
+    pub fn LUND(&self) -> Result<&Option<LUND>, PaceError> {
+        get_or_parse(&self.LUND, || LUND::parse(&self.arrays(), ()))
+    }
+
+    // Delayed neutron energy distribution locations
+    pub fn DNEDL(&self) -> Result<&Option<DNEDL>, PaceError> {
+        get_or_parse(&self.DNEDL, || DNEDL::parse(&self.arrays(), ()))
+    }
+
+    // Delayed neutron energy distributions
+    pub fn DNED(&self) -> Result<&Option<DNED>, PaceError> {
+        get_or_parse(&self.DNED, || DNED::parse(&self.arrays(), self.DNEDL()?))
+    }
+
+    // Particle types
+    pub fn PTYPE(&self) -> Result<&Option<PTYPE>, PaceError> {
+        get_or_parse(&self.PTYPE, || PTYPE::parse(&self.arrays(), ()))
+    }
+
+    // Number of production reactions for each particle type
+    pub fn NTRO(&self) -> Result<&Option<NTRO>, PaceError> {
+        get_or_parse(&self.NTRO, || NTRO::parse(&self.arrays(), ()))
+    }
+
+    // Particle production data locations (IXS array)
+    pub fn NEXT(&self) -> Result<&Option<NEXT>, PaceError> {
+        get_or_parse(&self.NEXT, || NEXT::parse(&self.arrays(), ()))
+    }
+
+    // Particle production data
+    pub fn ParticleProduction(&self) -> Result<&Option<ParticleProduction>, PaceError> {
+        get_or_parse(&self.ParticleProduction, || ParticleProduction::parse(
+            &self.arrays(),
+            (self.PTYPE()?, self.NTRO()?, self.NEXT()?, self.ESZ()?, self.LQR()?, self.atomic_mass_fraction),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::utils::get_parsed_test_file;
+
+    fn lazy_test_blocks() -> LazyDataBlocks {
+        let mmap = PaceMmap::from_file("test_nuclear_data_files/1100.800nc.pace").unwrap();
+        let header = Header::from_PACE(&mmap).unwrap();
+        let nxs_array = NxsArray::from_PACE(&mmap).unwrap();
+        let jxs_array = JxsArray::from_PACE(&mmap).unwrap();
+        LazyDataBlocks::from_PACE(mmap, &header, &nxs_array, &jxs_array)
+    }
+
+    #[tokio::test]
+    async fn test_blocks_parsed_on_demand() {
+        let parsed_ace = get_parsed_test_file().await;
+        let blocks = lazy_test_blocks();
+
+        // Only the requested block is parsed
+        let esz = blocks.ESZ().unwrap().as_ref().unwrap();
+        assert_eq!(esz.energy, parsed_ace.data_blocks.ESZ.as_ref().unwrap().energy);
+        assert!(blocks.MTR.get().is_none());
+        assert!(blocks.SIG.get().is_none());
+
+        // Asking for SIG parses its dependencies along with it, and the blocks are then cached
+        let sig = blocks.SIG().unwrap().as_ref().unwrap();
+        assert_eq!(sig.get(&18).unwrap().xs_val, parsed_ace.data_blocks.SIG.as_ref().unwrap().get(&18).unwrap().xs_val);
+        assert!(blocks.MTR.get().is_some());
+        assert!(blocks.LSIG.get().is_some());
+        assert!(blocks.TYR.get().is_none());
+        assert!(std::ptr::eq(blocks.ESZ().unwrap(), blocks.ESZ.get().unwrap()));
+
+        // Parsing everything gives the same blocks as the eager parser
+        let data_blocks = blocks.to_data_blocks().unwrap();
+        assert_eq!(data_blocks.MTR, parsed_ace.data_blocks.MTR);
+        assert_eq!(data_blocks.LQR, parsed_ace.data_blocks.LQR);
+        assert_eq!(data_blocks.FIS.map(|fis| fis.xs_val.clone()), parsed_ace.data_blocks.FIS.map(|fis| fis.xs_val.clone()));
+        assert_eq!(data_blocks.AND.map(|and| and.len()), parsed_ace.data_blocks.AND.map(|and| and.len()));
+    }
+}
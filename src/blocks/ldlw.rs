@@ -0,0 +1,81 @@
+use std::collections::HashMap;
+use std::ops::Deref;
+
+use crate::arrays::{Arrays, XxsArray, XxsWords};
+use crate::blocks::{BlockType, MTR};
+use crate::blocks::block_traits::{get_block_start, required_block, pull_block_range, PullFromXXS, XxsBlock, Process};
+use crate::pace_error::PaceError;
+
+//=====================================================================
+// LDLW data block
+//
+// Contains location data of energy distributions for all reactions
+// which produce secondary neutrons. Elastic scattering is not included.
+//=====================================================================
+#[derive(Debug, Clone, PartialEq)]
+pub struct LDLW ( pub HashMap<usize, usize> );
+
+impl Deref for LDLW {
+    type Target = HashMap<usize, usize>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<'a> PullFromXXS<'a> for LDLW {
+    const BLOCK_TYPE: BlockType = BlockType::LDLW;
+
+    fn pull_from_xxs_array(arrays: &Arrays<'_, 'a>) -> Result<Option<XxsBlock<'a>>, PaceError> {
+        // We expect LDLW if NXS(5) (NR) != 0
+        let has_secondary_neutrons = arrays.nxs.nr != 0;
+
+        // Validate that the block is there and get the start index
+        let Some(block_start) = get_block_start(
+            &BlockType::LDLW,
+            arrays,
+            has_secondary_neutrons,
+            "LDLW is expected if NXS(5) (NR) != 0, but LDLW was not found.".to_string(),
+        )? else {
+            return Ok(None);
+        };
+
+        // Calculate the block length, see the LDLW description in the ACE spec
+        let block_length = arrays.nxs.nr;
+
+        // Return the block's raw data as a slice
+        pull_block_range(&Self::BLOCK_TYPE, block_start, block_length, arrays)
+    }
+}
+
+impl<'a> Process<'a> for LDLW {
+    type Dependencies = &'a Option<MTR>;
+
+    fn process(data: XxsArray<'_>, _arrays: &Arrays, mtr: &Option<MTR>) -> Result<Self, PaceError> {
+        // Reactions with secondary neutrons are listed first in MTR, so the first NXS(5)
+        // entries of MTR line up with the locators in LDLW
+        let mtr = required_block(mtr, BlockType::MTR)?;
+        Ok(Self(mtr.iter().copied().zip(data.uints(0, data.len())?).collect()))
+    }
+}
+
+impl std::fmt::Display for LDLW {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "LDLW({} reactions)", self.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{utils::get_parsed_test_file, helpers::MTNumber};
+
+    #[tokio::test]
+    async fn test_ldlw_parsing() {
+        let parsed_ace = get_parsed_test_file().await;
+
+        // Check contents
+        let ldlw = parsed_ace.data_blocks.LDLW.unwrap();
+        assert_eq!(ldlw.len(), 1);
+        assert_eq!(ldlw.get(&(MTNumber::Fission as usize)), Some(&1));
+    }
+}
@@ -0,0 +1,80 @@
+use std::collections::HashMap;
+use std::ops::Deref;
+
+use crate::arrays::{Arrays, XxsArray, XxsWords};
+use crate::blocks::{BlockType, MTRP};
+use crate::blocks::block_traits::{get_block_start, required_block, pull_block_range, PullFromXXS, XxsBlock, Process};
+use crate::pace_error::PaceError;
+
+//=====================================================================
+// LDLWP data block
+//
+// Contains location data of energy distributions for all photon
+// production reactions.
+//=====================================================================
+#[derive(Debug, Clone, PartialEq)]
+pub struct LDLWP ( pub HashMap<usize, usize> );
+
+impl Deref for LDLWP {
+    type Target = HashMap<usize, usize>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<'a> PullFromXXS<'a> for LDLWP {
+    const BLOCK_TYPE: BlockType = BlockType::LDLWP;
+
+    fn pull_from_xxs_array(arrays: &Arrays<'_, 'a>) -> Result<Option<XxsBlock<'a>>, PaceError> {
+        // We expect LDLWP if NXS(6) (NTRP) != 0
+        let has_photon_production = arrays.nxs.ntrp != 0;
+
+        // Validate that the block is there and get the start index
+        let Some(block_start) = get_block_start(
+            &BlockType::LDLWP,
+            arrays,
+            has_photon_production,
+            "LDLWP is expected if NXS(6) (NTRP) != 0, but LDLWP was not found.".to_string(),
+        )? else {
+            return Ok(None);
+        };
+
+        // Calculate the block length, see the LDLWP description in the ACE spec
+        let block_length = arrays.nxs.ntrp;
+
+        // Return the block's raw data as a slice
+        pull_block_range(&Self::BLOCK_TYPE, block_start, block_length, arrays)
+    }
+}
+
+impl<'a> Process<'a> for LDLWP {
+    type Dependencies = &'a Option<MTRP>;
+
+    fn process(data: XxsArray<'_>, _arrays: &Arrays, mtrp: &Option<MTRP>) -> Result<Self, PaceError> {
+        let mtrp = required_block(mtrp, BlockType::MTRP)?;
+        Ok(Self(mtrp.iter().copied().zip(data.uints(0, data.len())?).collect()))
+    }
+}
+
+impl std::fmt::Display for LDLWP {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "LDLWP({} reactions)", self.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::get_parsed_test_file;
+
+    #[tokio::test]
+    async fn test_ldlwp_parsing() {
+        let parsed_ace = get_parsed_test_file().await;
+
+        // Check contents
+        let ldlwp = parsed_ace.data_blocks.LDLWP.unwrap();
+        assert_eq!(ldlwp.len(), 2);
+        assert_eq!(ldlwp.get(&18001), Some(&1));
+        assert_eq!(ldlwp.get(&18002), Some(&12));
+    }
+}
@@ -2,9 +2,10 @@
 use std::collections::HashMap;
 use std::ops::Deref;
 
-use crate::arrays::Arrays;
+use crate::arrays::{Arrays, XxsArray};
 use crate::blocks::{BlockType, MTR};
-use crate::blocks::block_traits::{get_block_start, block_range_to_slice, PullFromXXS, Process};
+use crate::blocks::block_traits::{get_block_start, required_block, pull_block_range, PullFromXXS, XxsBlock, Process};
+use crate::pace_error::PaceError;
 
 //=====================================================================
 // LQR data block
@@ -24,32 +25,37 @@ impl Deref for LQR {
 }
 
 impl<'a> PullFromXXS<'a> for LQR {
-    fn pull_from_xxs_array(arrays: &'a Arrays) -> Option<&'a [f64]> {
+    const BLOCK_TYPE: BlockType = BlockType::LQR;
+
+    fn pull_from_xxs_array(arrays: &Arrays<'_, 'a>) -> Result<Option<XxsBlock<'a>>, PaceError> {
         // We expect LQR if NXS(4) (NTR) != 0
         let has_xs_other_than_elastic = arrays.nxs.ntr != 0;
 
         // Validate that the block is there and get the start index
-        let block_start = get_block_start(
+        let Some(block_start) = get_block_start(
             &BlockType::LQR,
             arrays,
             has_xs_other_than_elastic,
             "LQR is expected if NXS(4) (NTR) != 0, but LQR was not found.".to_string(),
-        )?;
+        )? else {
+            return Ok(None);
+        };
 
         // Calculate the block length, see the LQR description in the ACE spec
         let num_reactions = arrays.nxs.ntr;
         let block_length = num_reactions;
 
         // Return the block's raw data as a slice
-        Some(block_range_to_slice(block_start, block_length, arrays))
+        pull_block_range(&Self::BLOCK_TYPE, block_start, block_length, arrays)
     }
 }
 
 impl<'a> Process<'a> for LQR {
     type Dependencies = &'a Option<MTR>;
 
-    fn process(data: &[f64], _arrays: &Arrays, mtr: &Option<MTR>) -> Self {
-        Self(data.iter().enumerate().map(|(i, &q)| (mtr.as_ref().unwrap()[i], q)).collect())
+    fn process(data: XxsArray<'_>, _arrays: &Arrays, mtr: &Option<MTR>) -> Result<Self, PaceError> {
+        let mtr = required_block(mtr, BlockType::MTR)?;
+        Ok(Self(mtr.iter().zip(data.iter()).map(|(&mt, &q)| (mt, q)).collect()))
     }
 }
 
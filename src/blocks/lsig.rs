@@ -1,8 +1,9 @@
 use std::ops::Deref;
 
-use crate::arrays::Arrays;
+use crate::arrays::{Arrays, XxsArray, XxsWords};
 use crate::blocks::BlockType;
-use crate::blocks::block_traits::{get_block_start, block_range_to_slice, PullFromXXS, Process};
+use crate::blocks::block_traits::{get_block_start, pull_block_range, PullFromXXS, XxsBlock, Process};
+use crate::pace_error::PaceError;
 
 //=====================================================================
 // LSIG data block
@@ -22,32 +23,36 @@ impl Deref for LSIG {
 }
 
 impl<'a> PullFromXXS<'a> for LSIG {
-    fn pull_from_xxs_array(arrays: &'a Arrays) -> Option<&'a [f64]> {
+    const BLOCK_TYPE: BlockType = BlockType::LSIG;
+
+    fn pull_from_xxs_array(arrays: &Arrays<'_, 'a>) -> Result<Option<XxsBlock<'a>>, PaceError> {
         // We expect LSIG if NXS(4) (NTR) != 0.
         let has_xs_other_than_elastic = arrays.nxs.ntr != 0;
 
         // Get the starting index of the block in the XXS array
-        let block_start = get_block_start(
+        let Some(block_start) = get_block_start(
             &BlockType::LSIG,
             arrays,
             has_xs_other_than_elastic,
             "LSIG is expected if NXS(4) (NTR) != 0, but LSIG was not found.".to_string(),
-        )?;
+        )? else {
+            return Ok(None);
+        };
         
         // Calculate the block length, see the LSIG description in the ACE spec
         let num_reactions = arrays.nxs.ntr;
         let block_length = num_reactions;
 
         // Return the block's raw data as a slice
-        Some(block_range_to_slice(block_start, block_length, arrays))
+        pull_block_range(&Self::BLOCK_TYPE, block_start, block_length, arrays)
     }
 }
 
 impl<'a> Process<'a> for LSIG {
     type Dependencies = ();
 
-    fn process(data: &[f64], _arrays: &Arrays, _dependencies: ()) -> Self {
-        Self(data.iter().map(|val| val.to_bits() as usize).collect())
+    fn process(data: XxsArray<'_>, _arrays: &Arrays, _dependencies: ()) -> Result<Self, PaceError> {
+        Ok(Self(data.uints(0, data.len())?))
     }
 }
 
@@ -0,0 +1,329 @@
+use thiserror::Error;
+
+use crate::unitf64::UnitF64;
+use crate::arrays::{Arrays, XxsArray, XxsWords};
+use crate::interpolation::InterpolationScheme;
+use crate::blocks::BlockType;
+use crate::blocks::block_traits::{get_block_start, get_xxs_uint, pull_block_range, PullFromXXS, XxsBlock, Process};
+use crate::pace_error::PaceError;
+
+// Each probability table has a CDF followed by total, elastic, fission, capture, and heating bands
+const NUM_TABLE_COLUMNS: usize = 6;
+
+//=====================================================================
+// LUND data block
+//
+// Contains the unresolved resonance range probability tables. For each
+// incident energy, the table gives a CDF over a set of bands along with
+// the cross sections in each band. These may be given either directly
+// or as factors which multiply the smooth cross sections. See the ACE
+// format spec for a description of the LUND block.
+//=====================================================================
+#[derive(Debug, Clone)]
+pub struct LUND {
+    pub interpolation_scheme: InterpolationScheme,  // INT, linear-linear or log-log between incident energies
+    pub inelastic_flag: isize,                      // ILF, <0 zero inelastic, >0 MT of the smooth inelastic cross section
+    pub other_absorption_flag: isize,               // IOA, <0 zero other absorption, >0 MT of the smooth other absorption cross section
+    pub factors: bool,                              // IFF, true if the tables are factors of the smooth cross sections
+    pub energy: Vec<f64>,
+    pub tables: Vec<ProbabilityTable>,
+}
+
+impl<'a> PullFromXXS<'a> for LUND {
+    const BLOCK_TYPE: BlockType = BlockType::LUND;
+
+    fn pull_from_xxs_array(arrays: &Arrays<'_, 'a>) -> Result<Option<XxsBlock<'a>>, PaceError> {
+        // We expect LUND if JXS(23) != 0
+        let has_probability_tables = arrays.jxs.get(&BlockType::LUND) != 0;
+
+        // Validate that the block is there and get the start index
+        let Some(block_start) = get_block_start(
+            &BlockType::LUND,
+            arrays,
+            has_probability_tables,
+            "LUND is expected if JXS(23) != 0, but LUND was not found.".to_string(),
+        )? else {
+            return Ok(None);
+        };
+
+        // Calculate the block length, see the LUND description in the ACE spec
+        // - The block starts with N, M, INT, ILF, IOA, and IFF, followed by the N incident energies.
+        // - Each of the N tables then has 6 columns of length M.
+        let num_energies = get_xxs_uint(&Self::BLOCK_TYPE, block_start, arrays)?;
+        let table_length = get_xxs_uint(&Self::BLOCK_TYPE, block_start + 1, arrays)?;
+        let block_length = 6 + num_energies + NUM_TABLE_COLUMNS * num_energies * table_length;
+
+        // Return the block's raw data as a slice
+        pull_block_range(&Self::BLOCK_TYPE, block_start, block_length, arrays)
+    }
+}
+
+impl<'a> Process<'a> for LUND {
+    type Dependencies = ();
+
+    fn process(data: XxsArray<'_>, _arrays: &Arrays, _dependencies: ()) -> Result<Self, PaceError> {
+        let num_energies = data.uint(0)?;
+        let table_length = data.uint(1)?;
+        // Sampling picks a band from every table, so the tables can not be empty
+        if num_energies > 0 && table_length == 0 {
+            return Err(PaceError::EmptyTable("LUND probability table"));
+        }
+        let energy = data.floats(6, num_energies)?.to_vec();
+
+        // Pull out the probability table at each incident energy
+        let tables_start = 6 + num_energies;
+        let tables = data.floats(tables_start, NUM_TABLE_COLUMNS * table_length * num_energies)?
+            .chunks(NUM_TABLE_COLUMNS * table_length.max(1))
+            .map(|table| {
+                let mut columns = table.chunks(table_length).map(|column| column.to_vec());
+                ProbabilityTable {
+                    cdf: columns.next().unwrap(),
+                    total: columns.next().unwrap(),
+                    elastic: columns.next().unwrap(),
+                    fission: columns.next().unwrap(),
+                    capture: columns.next().unwrap(),
+                    heating: columns.next().unwrap(),
+                }
+            })
+            .collect();
+
+        Ok(Self {
+            interpolation_scheme: InterpolationScheme::try_from(data.uint(2)?)?,
+            inelastic_flag: data.int(3)? as isize,
+            other_absorption_flag: data.int(4)? as isize,
+            factors: data.uint(5)? == 1,
+            energy,
+            tables,
+        })
+    }
+}
+
+impl LUND {
+    // Sample the cross sections in the unresolved resonance range at an energy (given in MeV).
+    // The same band is used in the tables on either side of the energy, and the band values
+    // are then interpolated between the two tables. If the tables are given as factors, they
+    // are multiplied by the provided smooth cross sections.
+    // The tables do not cover inelastic scattering or other absorption, so these are taken from
+    // the smooth cross sections of the MTs named by ILF and IOA (see inelastic_mt and
+    // other_absorption_mt), or are zero if there are none. The total is rebuilt from the sampled
+    // partial cross sections, as in MCNP and OpenMC, rather than taken from the tables.
+    // As with all sampling methods in the PACE library, the user is responsible for providing a random
+    // number in the range [0.0, 1.0]. This is checked in debug builds, but not in release builds.
+    pub fn sample(&self, energy: f64, unitf64: UnitF64, smooth: &ProbabilityTableCrossSections) -> Result<ProbabilityTableCrossSections, ProbabilityTableError> {
+        let (first, last) = match (self.energy.first(), self.energy.last()) {
+            (Some(&first), Some(&last)) => (first, last),
+            _ => return Err(ProbabilityTableError::EmptyTable),
+        };
+        if energy < first || energy > last {
+            return Err(ProbabilityTableError::EnergyOutOfRange(energy, first, last));
+        }
+
+        // Find the tables on either side of the energy
+        let lower = (self.energy.partition_point(|&e| e <= energy) - 1).min(self.energy.len().saturating_sub(2));
+        let upper = (lower + 1).min(self.energy.len() - 1);
+        let (lower_table, upper_table) = (&self.tables[lower], &self.tables[upper]);
+
+        // Sample the band from the lower table
+        let band = lower_table.cdf
+            .partition_point(|&cdf| cdf <= unitf64.0)
+            .min(lower_table.cdf.len() - 1);
+
+        // Interpolate the band values between the two tables
+        let (e_lower, e_upper) = (self.energy[lower], self.energy[upper]);
+        let interpolate = |lower_value: f64, upper_value: f64| -> f64 {
+            if upper == lower {
+                return lower_value;
+            }
+            match self.interpolation_scheme {
+                InterpolationScheme::LogLog if lower_value > 0.0 && upper_value > 0.0 => {
+                    let factor = (energy / e_lower).ln() / (e_upper / e_lower).ln();
+                    (lower_value.ln() + factor * (upper_value / lower_value).ln()).exp()
+                },
+                _ => {
+                    let factor = (energy - e_lower) / (e_upper - e_lower);
+                    lower_value + factor * (upper_value - lower_value)
+                },
+            }
+        };
+        let mut elastic = interpolate(lower_table.elastic[band], upper_table.elastic[band]);
+        let mut fission = interpolate(lower_table.fission[band], upper_table.fission[band]);
+        let mut capture = interpolate(lower_table.capture[band], upper_table.capture[band]);
+        let mut heating = interpolate(lower_table.heating[band], upper_table.heating[band]);
+        if self.factors {
+            elastic *= smooth.elastic;
+            fission *= smooth.fission;
+            capture *= smooth.capture;
+            heating *= smooth.heating;
+        }
+
+        let inelastic = if self.inelastic_mt().is_some() { smooth.inelastic } else { 0.0 };
+        let other_absorption = if self.other_absorption_mt().is_some() { smooth.other_absorption } else { 0.0 };
+        Ok(ProbabilityTableCrossSections {
+            total: elastic + fission + capture + inelastic + other_absorption,
+            elastic,
+            fission,
+            capture,
+            heating,
+            inelastic,
+            other_absorption,
+        })
+    }
+
+    // MT of the smooth cross section used for inelastic scattering in the unresolved resonance
+    // range, None if inelastic scattering is zero
+    pub fn inelastic_mt(&self) -> Option<usize> {
+        usize::try_from(self.inelastic_flag).ok().filter(|&mt| mt > 0)
+    }
+
+    // MT of the smooth cross section used for other absorption in the unresolved resonance range,
+    // None if other absorption is zero
+    pub fn other_absorption_mt(&self) -> Option<usize> {
+        usize::try_from(self.other_absorption_flag).ok().filter(|&mt| mt > 0)
+    }
+}
+
+impl std::fmt::Display for LUND {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "LUND({} energies)", self.energy.len())
+    }
+}
+
+//=====================================================================
+// Helper structs to represent a single probability table and a set of
+// cross sections sampled from the probability tables.
+//=====================================================================
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProbabilityTable {
+    pub cdf: Vec<f64>,
+    pub total: Vec<f64>,
+    pub elastic: Vec<f64>,
+    pub fission: Vec<f64>,
+    pub capture: Vec<f64>,
+    pub heating: Vec<f64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ProbabilityTableCrossSections {
+    pub total: f64,
+    pub elastic: f64,
+    pub fission: f64,
+    pub capture: f64,
+    pub heating: f64,
+    pub inelastic: f64,         // Smooth cross section of the MT named by ILF
+    pub other_absorption: f64,  // Smooth cross section of the MT named by IOA
+}
+
+#[derive(Debug, Error)]
+pub enum ProbabilityTableError {
+    #[error("Energy {0} is out of the unresolved resonance range [{1}, {2}]")]
+    EnergyOutOfRange(f64, f64, f64),
+
+    #[error("Probability tables contain no data")]
+    EmptyTable,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::blocks::Parse;
+    use crate::utils::get_parsed_test_file;
+
+    #[tokio::test]
+    async fn test_lund_parsing() {
+        let parsed_ace = get_parsed_test_file().await;
+
+        // Check contents
+        let lund = parsed_ace.data_blocks.LUND.unwrap();
+        assert_eq!(lund.interpolation_scheme, InterpolationScheme::LinLin);
+        assert_eq!(lund.inelastic_flag, -1);
+        assert_eq!(lund.other_absorption_flag, -1);
+        assert!(!lund.factors);
+        assert_eq!(lund.energy, vec![1.0, 3.0]);
+        assert_eq!(lund.tables.len(), 2);
+        assert_eq!(
+            lund.tables[0],
+            ProbabilityTable {
+                cdf: vec![0.5, 1.0],
+                total: vec![10.0, 20.0],
+                elastic: vec![4.0, 8.0],
+                fission: vec![2.0, 4.0],
+                capture: vec![4.0, 8.0],
+                heating: vec![1.0, 2.0],
+            }
+        );
+        assert_eq!(lund.tables[1].total, vec![30.0, 40.0]);
+    }
+
+    #[tokio::test]
+    async fn test_lund_sampling() {
+        let parsed_ace = get_parsed_test_file().await;
+        let mut lund = parsed_ace.data_blocks.LUND.unwrap();
+        let smooth = ProbabilityTableCrossSections {
+            total: 2.0, elastic: 2.0, fission: 2.0, capture: 2.0, heating: 2.0, inelastic: 3.0, other_absorption: 5.0,
+        };
+
+        // First band, halfway between the two tables. ILF and IOA are negative, so there is no
+        // inelastic scattering or other absorption.
+        let sampled = lund.sample(2.0, UnitF64(0.25), &smooth).unwrap();
+        assert_eq!(
+            sampled,
+            ProbabilityTableCrossSections {
+                total: 20.0, elastic: 8.0, fission: 4.0, capture: 8.0, heating: 2.0, inelastic: 0.0, other_absorption: 0.0,
+            }
+        );
+
+        // Second band at the top of the table
+        let sampled = lund.sample(3.0, UnitF64(0.75), &smooth).unwrap();
+        assert_eq!(sampled.total, 40.0);
+
+        // Factors multiply the smooth cross sections
+        lund.factors = true;
+        let sampled = lund.sample(1.0, UnitF64(0.25), &smooth).unwrap();
+        assert_eq!(sampled.total, 20.0);
+        assert_eq!(sampled.heating, 2.0);
+
+        // Energies outside of the unresolved resonance range can not be sampled
+        assert!(lund.sample(0.5, UnitF64(0.25), &smooth).is_err());
+        assert!(lund.sample(3.5, UnitF64(0.25), &smooth).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_lund_inelastic_and_other_absorption() {
+        let parsed_ace = get_parsed_test_file().await;
+        let mut lund = parsed_ace.data_blocks.LUND.unwrap();
+        lund.inelastic_flag = 51;
+        lund.other_absorption_flag = 107;
+        assert_eq!(lund.inelastic_mt(), Some(51));
+        assert_eq!(lund.other_absorption_mt(), Some(107));
+
+        // The smooth inelastic and other absorption cross sections are added to the total
+        let smooth = ProbabilityTableCrossSections {
+            total: 2.0, elastic: 2.0, fission: 2.0, capture: 2.0, heating: 2.0, inelastic: 3.0, other_absorption: 5.0,
+        };
+        let sampled = lund.sample(2.0, UnitF64(0.25), &smooth).unwrap();
+        assert_eq!((sampled.inelastic, sampled.other_absorption), (3.0, 5.0));
+        assert_eq!(sampled.total, 8.0 + 4.0 + 8.0 + 3.0 + 5.0);
+
+        // With factors, only the partial cross sections from the tables are scaled
+        lund.factors = true;
+        let sampled = lund.sample(1.0, UnitF64(0.25), &smooth).unwrap();
+        assert_eq!(sampled.total, 2.0 * (4.0 + 2.0 + 4.0) + 3.0 + 5.0);
+    }
+
+    #[tokio::test]
+    async fn test_empty_probability_tables() {
+        let parsed_ace = get_parsed_test_file().await;
+        let mut jxs = parsed_ace.jxs_array.clone();
+        jxs.insert(BlockType::LUND, 1);
+
+        // One incident energy with tables of length zero
+        let xxs = vec![1.0, 0.0, 2.0, -1.0, -1.0, 0.0, 1.0];
+        let arrays = Arrays { nxs: &parsed_ace.nxs_array, jxs: &jxs, xxs: XxsArray::untyped(&xxs) };
+        let error = LUND::parse(&arrays, ()).unwrap_err();
+        let PaceError::InBlock { block: BlockType::LUND, index: 0, source } = error else {
+            panic!("Expected the error to be tagged with the LUND block, got {error:?}");
+        };
+        assert!(matches!(*source, PaceError::EmptyTable(_)));
+    }
+}
@@ -1,6 +1,7 @@
 mod block_types;
 mod block_traits;
 mod block_processor;
+mod lazy_data_blocks;
 mod esz;
 mod mtr;
 mod lsig;
@@ -12,19 +13,61 @@ mod bdd;
 mod tyr;
 mod land;
 mod and;
+mod ldlw;
+mod dlw;
+mod gpd;
+mod mtrp;
+mod lsigp;
+mod sigp;
+mod landp;
+mod andp;
+mod ldlwp;
+mod dlwp;
+mod yp;
+mod fis;
+mod lund;
+mod dnedl;
+mod dned;
+mod ptype;
+mod ntro;
+mod next;
+mod particle_production;
+mod sigd;
 
 
 pub use block_types::BlockType;
+pub(crate) use block_traits::{Parse, PullFromXXS};
 pub use block_processor::DataBlocks;
+pub use lazy_data_blocks::LazyDataBlocks;
 
-pub use esz::ESZ;
+pub use esz::{ESZ, EszView};
 pub use mtr::MTR;
 pub use lsig::LSIG;
-pub use sig::SIG;
+pub use sig::{SIG, SigView, CrossSection, CrossSectionView};
 pub use lqr::LQR;
-pub use nu::NU;
+pub use nu::{NU, NuView, NuFormulationView};
 pub use dnu::DNU;
 pub use bdd::BDD;
-pub use tyr::TYR;
+pub use dnedl::DNEDL;
+pub use dned::{DNED, DelayedNeutron};
+pub use tyr::{TYR, NumberOfExitingNeutrons, ExitingNeutronFrameOfReference};
 pub use land::LAND;
 pub use and::AND;
+pub use ldlw::LDLW;
+pub use dlw::DLW;
+pub use gpd::GPD;
+pub use mtrp::MTRP;
+pub use lsigp::LSIGP;
+pub use sigp::{SIGP, PhotonProduction, PhotonYield};
+pub use landp::LANDP;
+pub use andp::ANDP;
+pub use ldlwp::LDLWP;
+pub use dlwp::DLWP;
+pub use yp::YP;
+pub use fis::FIS;
+pub use lund::{LUND, ProbabilityTable, ProbabilityTableCrossSections, ProbabilityTableError};
+pub use ptype::{PTYPE, ParticleType};
+pub use ntro::NTRO;
+pub use next::{NEXT, ParticleProductionLocators};
+pub use particle_production::{ParticleProduction, ParticleProductionData};
+pub use sigd::{SIGD, DosimetryError};
@@ -0,0 +1,79 @@
+use std::ops::Deref;
+
+use crate::arrays::{Arrays, XxsArray, XxsWords};
+use crate::blocks::BlockType;
+use crate::blocks::block_traits::{get_block_start, pull_block_range, PullFromXXS, XxsBlock, Process};
+use crate::pace_error::PaceError;
+
+//=====================================================================
+// MTRP data block
+//
+// This contains the MT numbers for the photon production reactions
+// available in the file. See the ACE format spec for a description of
+// the MTRP block.
+//=====================================================================
+#[derive(Debug, Clone, PartialEq)]
+pub struct MTRP( pub Vec<usize> );
+
+impl Deref for MTRP {
+    type Target = Vec<usize>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<'a> PullFromXXS<'a> for MTRP {
+    const BLOCK_TYPE: BlockType = BlockType::MTRP;
+
+    fn pull_from_xxs_array(arrays: &Arrays<'_, 'a>) -> Result<Option<XxsBlock<'a>>, PaceError> {
+        // We expect MTRP if NXS(6) (NTRP) != 0
+        let has_photon_production = arrays.nxs.ntrp != 0;
+
+        // Get the starting index of the block in the XXS array
+        let Some(block_start) = get_block_start(
+            &BlockType::MTRP,
+            arrays,
+            has_photon_production,
+            "MTRP is expected if NXS(6) (NTRP) != 0, but MTRP was not found.".to_string(),
+        )? else {
+            return Ok(None);
+        };
+
+        // Calculate the block length, see the MTRP description in the ACE spec
+        let num_reactions = arrays.nxs.ntrp;
+        let block_length = num_reactions;
+
+        // Return the block's raw data as a slice
+        pull_block_range(&Self::BLOCK_TYPE, block_start, block_length, arrays)
+    }
+}
+
+impl<'a> Process<'a> for MTRP {
+    type Dependencies = ();
+
+    fn process(data: XxsArray<'_>, _arrays: &Arrays, _dependencies: ()) -> Result<Self, PaceError> {
+        Ok(Self(data.uints(0, data.len())?))
+    }
+}
+
+impl std::fmt::Display for MTRP {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "MTRP({} reactions)", self.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::get_parsed_test_file;
+
+    #[tokio::test]
+    async fn test_mtrp_parsing() {
+        let parsed_ace = get_parsed_test_file().await;
+
+        // Check contents
+        let mtrp = parsed_ace.data_blocks.MTRP.unwrap();
+        assert_eq!(mtrp.len(), 2);
+        assert_eq!(mtrp.0, vec![18001, 18002]);
+    }
+}
@@ -0,0 +1,126 @@
+use std::ops::Deref;
+
+use crate::arrays::{Arrays, XxsArray, XxsWords};
+use crate::blocks::BlockType;
+use crate::blocks::block_traits::{get_block_start, pull_block_range, PullFromXXS, XxsBlock, Process};
+use crate::pace_error::PaceError;
+
+// Each particle type has 10 entries in the IXS array
+const NUM_IXS_ENTRIES_PER_PARTICLE: usize = 10;
+
+//=====================================================================
+// NEXT data block
+//
+// Contains the IXS array, which gives the locations of the production
+// data sub-blocks for each of the secondary particle types in the
+// PTYPE block. Unlike other locators, these are absolute locations in
+// the XXS array.
+//=====================================================================
+#[derive(Debug, Clone, PartialEq)]
+pub struct NEXT ( pub Vec<ParticleProductionLocators> );
+
+impl Deref for NEXT {
+    type Target = Vec<ParticleProductionLocators>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<'a> PullFromXXS<'a> for NEXT {
+    const BLOCK_TYPE: BlockType = BlockType::NEXT;
+
+    fn pull_from_xxs_array(arrays: &Arrays<'_, 'a>) -> Result<Option<XxsBlock<'a>>, PaceError> {
+        // We expect NEXT if NXS(7) (NTYPE) != 0
+        let has_particle_production = arrays.nxs.ntype != 0;
+
+        // Get the starting index of the block in the XXS array
+        let Some(block_start) = get_block_start(
+            &BlockType::NEXT,
+            arrays,
+            has_particle_production,
+            "NEXT is expected if NXS(7) (NTYPE) != 0, but NEXT was not found.".to_string(),
+        )? else {
+            return Ok(None);
+        };
+
+        // Calculate the block length, see the NEXT description in the ACE spec
+        let block_length = NUM_IXS_ENTRIES_PER_PARTICLE * arrays.nxs.ntype;
+
+        // Return the block's raw data as a slice
+        pull_block_range(&Self::BLOCK_TYPE, block_start, block_length, arrays)
+    }
+}
+
+impl<'a> Process<'a> for NEXT {
+    type Dependencies = ();
+
+    fn process(data: XxsArray<'_>, _arrays: &Arrays, _dependencies: ()) -> Result<Self, PaceError> {
+        Ok(Self(
+            data.uints(0, data.len())?
+                .chunks_exact(NUM_IXS_ENTRIES_PER_PARTICLE)
+                .map(|locators| {
+                    ParticleProductionLocators {
+                        hpd: locators[0],
+                        mtrh: locators[1],
+                        tyrh: locators[2],
+                        lsigh: locators[3],
+                        sigh: locators[4],
+                        landh: locators[5],
+                        andh: locators[6],
+                        ldlwh: locators[7],
+                        dlwh: locators[8],
+                        yh: locators[9],
+                    }
+                })
+                .collect()
+        ))
+    }
+}
+
+impl NEXT {
+    // The first location in the XXS array which holds particle production data
+    pub fn data_start(&self) -> Option<usize> {
+        self.iter().map(|locators| locators.hpd).min()
+    }
+}
+
+impl std::fmt::Display for NEXT {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "NEXT({} particle types)", self.len())
+    }
+}
+
+// Locations of the production data sub-blocks for a single particle type (one row of the
+// IXS array). All locations are one indexed and absolute in the XXS array.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ParticleProductionLocators {
+    pub hpd: usize,     // Total production cross section and heating numbers
+    pub mtrh: usize,    // Production MT array
+    pub tyrh: usize,    // Frame of reference array
+    pub lsigh: usize,   // Table of production cross section locators
+    pub sigh: usize,    // Production cross sections
+    pub landh: usize,   // Table of angular distribution locators
+    pub andh: usize,    // Angular distributions
+    pub ldlwh: usize,   // Table of energy distribution locators
+    pub dlwh: usize,    // Energy distributions
+    pub yh: usize,      // Table of yield multipliers
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::get_parsed_test_file;
+
+    #[tokio::test]
+    async fn test_next_parsing() {
+        let parsed_ace = get_parsed_test_file().await;
+
+        // Check contents
+        let next = parsed_ace.data_blocks.NEXT.unwrap();
+        assert_eq!(next.len(), 1);
+        assert_eq!(next[0].hpd, 449);
+        assert_eq!(next[0].dlwh, 483);
+        assert_eq!(next[0].yh, 499);
+        assert_eq!(next.data_start(), Some(449));
+    }
+}
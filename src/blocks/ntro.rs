@@ -0,0 +1,76 @@
+use std::ops::Deref;
+
+use crate::arrays::{Arrays, XxsArray, XxsWords};
+use crate::blocks::BlockType;
+use crate::blocks::block_traits::{get_block_start, pull_block_range, PullFromXXS, XxsBlock, Process};
+use crate::pace_error::PaceError;
+
+//=====================================================================
+// NTRO data block
+//
+// Contains the number of production reactions for each of the
+// secondary particle types in the PTYPE block.
+//=====================================================================
+#[derive(Debug, Clone, PartialEq)]
+pub struct NTRO ( pub Vec<usize> );
+
+impl Deref for NTRO {
+    type Target = Vec<usize>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<'a> PullFromXXS<'a> for NTRO {
+    const BLOCK_TYPE: BlockType = BlockType::NTRO;
+
+    fn pull_from_xxs_array(arrays: &Arrays<'_, 'a>) -> Result<Option<XxsBlock<'a>>, PaceError> {
+        // We expect NTRO if NXS(7) (NTYPE) != 0
+        let has_particle_production = arrays.nxs.ntype != 0;
+
+        // Get the starting index of the block in the XXS array
+        let Some(block_start) = get_block_start(
+            &BlockType::NTRO,
+            arrays,
+            has_particle_production,
+            "NTRO is expected if NXS(7) (NTYPE) != 0, but NTRO was not found.".to_string(),
+        )? else {
+            return Ok(None);
+        };
+
+        // Calculate the block length, see the NTRO description in the ACE spec
+        let block_length = arrays.nxs.ntype;
+
+        // Return the block's raw data as a slice
+        pull_block_range(&Self::BLOCK_TYPE, block_start, block_length, arrays)
+    }
+}
+
+impl<'a> Process<'a> for NTRO {
+    type Dependencies = ();
+
+    fn process(data: XxsArray<'_>, _arrays: &Arrays, _dependencies: ()) -> Result<Self, PaceError> {
+        Ok(Self(data.uints(0, data.len())?))
+    }
+}
+
+impl std::fmt::Display for NTRO {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "NTRO({} particle types)", self.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::get_parsed_test_file;
+
+    #[tokio::test]
+    async fn test_ntro_parsing() {
+        let parsed_ace = get_parsed_test_file().await;
+
+        // Check contents
+        let ntro = parsed_ace.data_blocks.NTRO.unwrap();
+        assert_eq!(ntro.0, vec![1]);
+    }
+}
@@ -1,9 +1,10 @@
 use anyhow::Result;
 
-use crate::arrays::Arrays;
-use crate::interpolation::{InterpolationTable, InterpolationError};
+use crate::arrays::{Arrays, XxsArray, XxsWords};
+use crate::interpolation::{InterpolationTable, InterpolationTableView, InterpolationError};
 use crate::blocks::BlockType;
-use crate::blocks::block_traits::{get_block_start, block_range_to_slice, PullFromXXS, Process};
+use crate::blocks::block_traits::{get_block_start, get_xxs_int, get_xxs_uint, pull_block_range, PullFromXXS, XxsBlock, Process};
+use crate::pace_error::PaceError;
 
 //=====================================================================
 // NU data block
@@ -18,90 +19,61 @@ pub struct NU {
 }
 
 impl<'a> PullFromXXS<'a> for NU {
-    fn pull_from_xxs_array(arrays: &'a Arrays) -> Option<&'a [f64]> {
+    const BLOCK_TYPE: BlockType = BlockType::NU;
+
+    fn pull_from_xxs_array(arrays: &Arrays<'_, 'a>) -> Result<Option<XxsBlock<'a>>, PaceError> {
         // We expect NU if JXS(2) != 0
         let is_fissile = arrays.jxs.get(&BlockType::NU) != 0;
 
         // Validate that the block is there and get the start index
-        let block_start = get_block_start(
+        let Some(block_start) = get_block_start(
             &BlockType::NU,
             arrays,
             is_fissile,
             "NU is expected if JXS(2) != 0, but NU was not found.".to_string(),
-        )?;
+        )? else {
+            return Ok(None);
+        };
 
         // Calculate the block length, see the NU description in the ACE spec
         // Check if we have prompt and total or just one of the two
-        let prompt_and_or_total_flag = arrays.xxs[block_start].to_bits() as isize;
+        let prompt_and_or_total_flag = get_xxs_int(&Self::BLOCK_TYPE, block_start, arrays)? as isize;
         let first_nu_length = prompt_and_or_total_flag.unsigned_abs() + 1;
         let mut block_length = first_nu_length;
         // We have both blocks, so we need to check the length of the second block
         if prompt_and_or_total_flag < 0 {
             // Jump to start of total nu and check if it is polynomial or tabulated
-            let total_nu_poly_or_tabulated =  arrays.xxs[block_start + block_length].to_bits() as usize;
+            let total_nu_poly_or_tabulated = get_xxs_uint(&Self::BLOCK_TYPE, block_start + block_length, arrays)?;
             let total_nu_start = block_start + block_length + 1;
             // We have a polynomial formulation for total nu
             if total_nu_poly_or_tabulated == 1 {
-                block_length += 2 + arrays.xxs[total_nu_start].to_bits() as usize;
+                block_length += 2 + get_xxs_uint(&Self::BLOCK_TYPE, total_nu_start, arrays)?;
             // We have a tabulated formulation for total nu
             } else if total_nu_poly_or_tabulated == 2 {
-                block_length += 1 + InterpolationTable::get_table_length(total_nu_start, arrays.xxs);
+                block_length += 1 + InterpolationTable::get_table_length(total_nu_start, arrays.xxs)?;
             } else {
-                panic!("Unknown total nu formulation, expected 1 or 2, got {}", total_nu_poly_or_tabulated);
+                return Err(PaceError::unknown_code("total nu formulation", total_nu_poly_or_tabulated));
             }
         }
 
         // Return the block's raw data as a slice
-        Some(block_range_to_slice(block_start, block_length, arrays))
+        pull_block_range(&Self::BLOCK_TYPE, block_start, block_length, arrays)
     }
 }
 
 impl<'a> Process<'a> for NU {
     type Dependencies = ();
 
-    fn process(data: &[f64], arrays: &Arrays, _dependencies: ()) -> Self {
-        // Grab first nu data
-        let prompt_and_or_total_flag = data[0].to_bits() as isize;
-        let first_nu_length = prompt_and_or_total_flag.unsigned_abs();
-        let first_nu_data = &data[1..first_nu_length + 1];
-
-        let prompt_or_total_nu = match first_nu_data[0].to_bits() as usize {
-            1 => NuFormulation::Polynomial(PolynomialNu {
-                coefficients: first_nu_data[2..].to_vec()
-            }),
-            2 => NuFormulation::Tabulated(TabulatedNu {
-                table: InterpolationTable::process(&first_nu_data[1..])
-            }),
-            _ => panic!("Unknown prompt/total nu formulation")
-        };
+    fn process(data: XxsArray<'_>, arrays: &Arrays, _dependencies: ()) -> Result<Self, PaceError> {
+        NuView::process(data, arrays).map(|view| Self::from(&view))
+    }
+}
 
-        // We have both blocks
-        if prompt_and_or_total_flag < 0 {
-            let second_nu_data = &data[first_nu_length + 1..];
-            let total_nu = match second_nu_data[0].to_bits() as usize {
-                1 => NuFormulation::Polynomial(PolynomialNu {
-                    coefficients: second_nu_data[2..].to_vec()
-                }),
-                2 => NuFormulation::Tabulated(TabulatedNu {
-                    table: InterpolationTable::process(&second_nu_data[1..])
-                }),
-                _ => panic!("Unknown total nu formulation")
-            };
-            NU {
-                prompt: Some(prompt_or_total_nu),
-                total: Some(total_nu)
-            }
-        // We do not have both blocks
-        } else if arrays.jxs.get(&BlockType::DNU) != 0 {
-            NU {
-                prompt: Some(prompt_or_total_nu),
-                total: None
-            }
-        } else {
-            NU {
-                prompt: None,
-                total: Some(prompt_or_total_nu)
-            }
+impl From<&NuView<'_>> for NU {
+    fn from(view: &NuView<'_>) -> Self {
+        Self {
+            prompt: view.prompt.as_ref().map(NuFormulation::from),
+            total: view.total.as_ref().map(NuFormulation::from),
         }
     }
 }
@@ -128,6 +100,19 @@ pub enum NuFormulation {
     Tabulated(TabulatedNu),
 }
 
+impl From<&NuFormulationView<'_>> for NuFormulation {
+    fn from(view: &NuFormulationView<'_>) -> Self {
+        match view {
+            NuFormulationView::Polynomial(coefficients) => NuFormulation::Polynomial(PolynomialNu {
+                coefficients: coefficients.to_vec()
+            }),
+            NuFormulationView::Tabulated(table) => NuFormulation::Tabulated(TabulatedNu {
+                table: InterpolationTable::from(table)
+            }),
+        }
+    }
+}
+
 impl NuFormulation {
     pub fn evaluate(&self, energy: f64) -> Result<f64> {
         match self {
@@ -146,12 +131,16 @@ pub struct PolynomialNu {
 impl PolynomialNu {
     // Evaluate the polynomial at an energy (given in MeV)
     pub fn evaluate(&self, energy: f64) -> Result<f64> {
-        let mut nu = 0.0;
-        for (i, coef) in self.coefficients.iter().enumerate() {
-            nu += coef * energy.powi(i as i32);
-        }
-        Ok(nu)
+        Ok(evaluate_polynomial(&self.coefficients, energy))
+    }
+}
+
+fn evaluate_polynomial(coefficients: &[f64], energy: f64) -> f64 {
+    let mut nu = 0.0;
+    for (i, coef) in coefficients.iter().enumerate() {
+        nu += coef * energy.powi(i as i32);
     }
+    nu
 }
 
 // Polynomial formulation for NU
@@ -167,6 +156,74 @@ impl TabulatedNu {
     }
 }
 
+//=====================================================================
+// Borrowed view of the NU block, which holds the polynomial
+// coefficients and tabulated values as slices of the XXS array rather
+// than copies of them. Convert to a NU to own the data.
+//=====================================================================
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct NuView<'a> {
+    pub prompt: Option<NuFormulationView<'a>>,
+    pub total: Option<NuFormulationView<'a>>,
+}
+
+impl<'a> NuView<'a> {
+    pub fn process(data: XxsArray<'a>, arrays: &Arrays) -> Result<Self, PaceError> {
+        // Grab first nu data
+        let prompt_and_or_total_flag = data.int(0)? as isize;
+        let first_nu_length = prompt_and_or_total_flag.unsigned_abs();
+        let first_nu_data = data.slice(1, first_nu_length)?;
+        let prompt_or_total_nu = NuFormulationView::process(first_nu_data)?;
+
+        // We have both blocks
+        if prompt_and_or_total_flag < 0 {
+            let second_nu_data = data.tail(first_nu_length + 1)?;
+            let total_nu = NuFormulationView::process(second_nu_data)?;
+            Ok(Self {
+                prompt: Some(prompt_or_total_nu),
+                total: Some(total_nu)
+            })
+        // We do not have both blocks
+        } else if arrays.jxs.get(&BlockType::DNU) != 0 {
+            Ok(Self {
+                prompt: Some(prompt_or_total_nu),
+                total: None
+            })
+        } else {
+            Ok(Self {
+                prompt: None,
+                total: Some(prompt_or_total_nu)
+            })
+        }
+    }
+}
+
+// Borrowed polynomial coefficients or interpolation table of a single nu array
+#[derive(Debug, Clone, PartialEq)]
+pub enum NuFormulationView<'a> {
+    Polynomial(&'a [f64]),
+    Tabulated(InterpolationTableView<'a>),
+}
+
+impl<'a> NuFormulationView<'a> {
+    // Process a single nu array, which starts with a flag for the formulation (1 = polynomial, 2 = tabulated)
+    fn process(data: XxsArray<'a>) -> Result<Self, PaceError> {
+        match data.uint(0)? {
+            1 => Ok(Self::Polynomial(data.tail(2)?.words())),
+            2 => Ok(Self::Tabulated(InterpolationTableView::process(data.tail(1)?)?)),
+            formulation => Err(PaceError::unknown_code("nu formulation", formulation)),
+        }
+    }
+
+    // Evaluate nu at an energy (given in MeV)
+    pub fn evaluate(&self, energy: f64) -> Result<f64, InterpolationError> {
+        match self {
+            Self::Polynomial(coefficients) => Ok(evaluate_polynomial(coefficients, energy)),
+            Self::Tabulated(table) => table.interpolate(energy),
+        }
+    }
+}
+
 
 
 #[cfg(test)]
@@ -0,0 +1,283 @@
+use std::collections::HashMap;
+use std::ops::Deref;
+
+use crate::arrays::{Arrays, XxsArray, XxsWords};
+use crate::angular_distributions::EnergyDependentAngularDistribution;
+use crate::energy_distributions::{SecondaryEnergyDistribution, ReactionKinematics};
+use crate::blocks::{
+    ESZ,
+    LQR,
+    PTYPE,
+    NTRO,
+    NEXT,
+    CrossSection,
+    PhotonProduction,
+    ParticleType,
+    BlockType,
+    ExitingNeutronFrameOfReference,
+    ParticleProductionLocators,
+};
+use crate::blocks::and::process_energy_dependent_distribution;
+use crate::blocks::block_traits::{get_block_length_from_next_block, pull_block_range, required_block, Parse, PullFromXXS, XxsBlock, Process};
+use crate::pace_error::{PaceError, get_slice};
+
+type ParticleProductionMap = HashMap<ParticleType, ParticleProductionData>;
+
+//=====================================================================
+// Particle production data
+//
+// Contains the production data for each of the secondary particle
+// types in the PTYPE block. Each particle type has its own set of
+// HPD, MTRH, TYRH, LSIGH, SIGH, LANDH, ANDH, LDLWH, DLWH and YH
+// sub-blocks, which are found through the IXS array in the NEXT block
+// and have the same layout as their neutron and photon counterparts.
+//=====================================================================
+#[derive(Debug, Clone)]
+pub struct ParticleProduction ( pub ParticleProductionMap );
+
+impl Deref for ParticleProduction {
+    type Target = ParticleProductionMap;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<'a> PullFromXXS<'a> for ParticleProduction {
+    const BLOCK_TYPE: BlockType = BlockType::NEXT;
+
+    fn pull_from_xxs_array(arrays: &Arrays<'_, 'a>) -> Result<Option<XxsBlock<'a>>, PaceError> {
+        // We expect particle production data if NXS(7) (NTYPE) != 0
+        if arrays.nxs.ntype == 0 {
+            return Ok(None);
+        }
+
+        // The sub-blocks are not listed in the JXS array, so we find the start of the data through the IXS array
+        let Some(data_start) = NEXT::parse(arrays, ())?.and_then(|next| next.data_start()) else {
+            return Ok(None);
+        };
+        let block_start = data_start - 1;
+
+        // Calculate the block length
+        // - The sub-blocks of each particle type are found through the IXS array, so we bound the
+        //   data by the start of the next block.
+        let block_length = get_block_length_from_next_block(block_start, arrays);
+
+        // Return the block's raw data as a slice
+        pull_block_range(&Self::BLOCK_TYPE, block_start, block_length, arrays)
+    }
+}
+
+impl<'a> Process<'a> for ParticleProduction {
+    // The atomic weight ratio from the header is needed for laws which use the reaction kinematics
+    type Dependencies = (&'a Option<PTYPE>, &'a Option<NTRO>, &'a Option<NEXT>, &'a Option<ESZ>, &'a Option<LQR>, f64);
+
+    fn process(data: XxsArray<'_>, _arrays: &Arrays, dependencies: (&Option<PTYPE>, &Option<NTRO>, &Option<NEXT>, &Option<ESZ>, &Option<LQR>, f64)) -> Result<Self, PaceError> {
+        let (ptype, ntro, next, esz, lqr, awr) = (
+            required_block(dependencies.0, BlockType::PTYPE)?,
+            required_block(dependencies.1, BlockType::NTRO)?,
+            required_block(dependencies.2, BlockType::NEXT)?,
+            required_block(dependencies.3, BlockType::ESZ)?,
+            dependencies.4,
+            dependencies.5,
+        );
+
+        // The IXS locators are absolute in the XXS array, convert them to indices into the block data.
+        // Locators before the start of the data are pushed past its end, so that they fail the bounds checks.
+        let data_start = next.data_start().unwrap_or(1);
+        let index = |locator: usize| locator.checked_sub(data_start).unwrap_or(usize::MAX);
+
+        let particle_production = ptype
+            .iter()
+            .zip(ntro.iter())
+            .zip(next.iter())
+            .map(|((&particle_type, &num_reactions), locators)| {
+                // HPD: IE and NE, followed by the total production cross section and heating numbers
+                let hpd_start = index(locators.hpd);
+                let energy_start_index = data.uint(hpd_start)?;
+                let num_energy_points = data.uint(hpd_start.saturating_add(1))?;
+                let xs_start = hpd_start + 2;
+                let total_xs = CrossSection {
+                    mt: particle_type.production_mt().unwrap_or(0),
+                    energy: get_slice(&esz.energy, energy_start_index.saturating_sub(1), num_energy_points)?.to_vec(),
+                    xs_val: data.floats(xs_start, num_energy_points)?.to_vec(),
+                };
+                let heating = data.floats(xs_start + num_energy_points, num_energy_points)?.to_vec();
+
+                Ok((
+                    particle_type,
+                    ParticleProductionData::process(data, index, locators, num_reactions, (total_xs, heating), (esz, lqr, awr))?
+                ))
+            })
+            .collect::<Result<_, PaceError>>()?;
+
+        Ok(Self(particle_production))
+    }
+}
+
+impl std::fmt::Display for ParticleProduction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ParticleProduction({} particle types)", self.len())
+    }
+}
+
+// Production data for a single secondary particle type, all maps are keyed by MT number
+#[derive(Debug, Clone)]
+pub struct ParticleProductionData {
+    pub total_xs: CrossSection,                                                     // HPD
+    pub heating: Vec<f64>,                                                          // HPD
+    pub mt_values: Vec<usize>,                                                      // MTRH
+    pub frames_of_reference: HashMap<usize, ExitingNeutronFrameOfReference>,        // TYRH
+    pub production: HashMap<usize, PhotonProduction>,                               // LSIGH and SIGH
+    pub angular_distributions: HashMap<usize, EnergyDependentAngularDistribution>,  // LANDH and ANDH
+    pub energy_distributions: HashMap<usize, SecondaryEnergyDistribution>,          // LDLWH and DLWH
+    pub yield_multipliers: Vec<usize>,                                              // YH
+}
+
+impl ParticleProductionData {
+    // Process the MTRH through YH sub-blocks for a single particle type, given its total production
+    // cross section and heating numbers. `index` converts the absolute IXS locators into indices into
+    // `data`. This layout is shared with the particle production data of photonuclear tables.
+    pub(crate) fn process<F: Fn(usize) -> usize>(
+        data: XxsArray<'_>,
+        index: F,
+        locators: &ParticleProductionLocators,
+        num_reactions: usize,
+        (total_xs, heating): (CrossSection, Vec<f64>),
+        (esz, lqr, awr): (&ESZ, &Option<LQR>, f64),
+    ) -> Result<Self, PaceError> {
+        // The MTRH, TYRH, LSIGH, LANDH and LDLWH sub-blocks each have an entry per reaction
+        let reaction_entries = |locator: usize| data.uints(index(locator), num_reactions);
+        let reaction_flags = |locator: usize| data.ints(index(locator), num_reactions);
+        let mt_values = reaction_entries(locators.mtrh)?;
+
+        // TYRH: Frame of reference for each reaction
+        let frames_of_reference = mt_values
+            .iter()
+            .zip(reaction_flags(locators.tyrh)?)
+            .map(|(&mt, flag)| (mt, ExitingNeutronFrameOfReference::from(flag as isize)))
+            .collect();
+
+        // SIGH: Production cross sections or yields, in the same form as SIGP
+        let production = mt_values
+            .iter()
+            .zip(reaction_entries(locators.lsigh)?)
+            .map(|(&mt, locator)| {
+                let sigh = data.tail(index(locators.sigh))?;
+                Ok((mt, PhotonProduction::process(sigh, mt, locator, esz)?))
+            })
+            .collect::<Result<_, PaceError>>()?;
+
+        // ANDH: Angular distributions, in the same form as AND. A locator of -1 means that
+        // the angular distribution is given with the energy distribution in DLWH. The ANDH
+        // sub-block may be absent entirely when all of the distributions are isotropic.
+        let angular_distributions = mt_values
+            .iter()
+            .zip(reaction_flags(locators.landh)?)
+            .filter_map(|(&mt, locator)| match locator as isize {
+                -1 => None,
+                0 => Some(Ok((mt, EnergyDependentAngularDistribution::new_fully_isotropic()))),
+                locator => Some(
+                    data.tail(index(locators.andh))
+                        .and_then(|andh| process_energy_dependent_distribution(andh, locator.unsigned_abs()))
+                        .map(|distribution| (mt, distribution))
+                ),
+            })
+            .collect::<Result<_, PaceError>>()?;
+
+        // DLWH: Energy distributions, in the same form as DLW
+        let energy_distributions = mt_values
+            .iter()
+            .zip(reaction_entries(locators.ldlwh)?)
+            .map(|(&mt, locator)| {
+                let dlwh = data.tail(index(locators.dlwh))?;
+                let q_value = lqr.as_ref().and_then(|lqr| lqr.get(&mt).copied()).unwrap_or(0.0);
+                let kinematics = ReactionKinematics { awr, q_value };
+                Ok((mt, SecondaryEnergyDistribution::process(dlwh, locator, kinematics)?))
+            })
+            .collect::<Result<_, PaceError>>()?;
+
+        // YH: Neutron MT numbers used as yield multipliers
+        let yield_multipliers = match locators.yh {
+            0 => Vec::new(),
+            yh => {
+                let yh_start = index(yh);
+                let num_multipliers = data.uint(yh_start)?;
+                data.uints(yh_start + 1, num_multipliers)?
+            },
+        };
+
+        Ok(Self {
+            total_xs,
+            heating,
+            mt_values,
+            frames_of_reference,
+            production,
+            angular_distributions,
+            energy_distributions,
+            yield_multipliers,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::utils::get_parsed_test_file;
+    use crate::helpers::MTNumber;
+    use crate::interpolation::InterpolationScheme;
+    use crate::angular_distributions::{AngularDistribution, IsotropicAngularDistribution, TabulatedAngularDistribution};
+
+    #[tokio::test]
+    async fn test_particle_production_parsing() {
+        let parsed_ace = get_parsed_test_file().await;
+        let particle_production = parsed_ace.data_blocks.ParticleProduction.unwrap();
+        let fission = MTNumber::Fission as usize;
+
+        // Only protons are produced, by fission
+        assert_eq!(particle_production.len(), 1);
+        let protons = particle_production.get(&ParticleType::Proton).unwrap();
+        assert_eq!(protons.total_xs.mt, 203);
+        assert_eq!(protons.total_xs.energy, vec![1.0, 2.0, 3.0]);
+        assert_eq!(protons.total_xs.xs_val, vec![0.1, 0.2, 0.3]);
+        assert_eq!(protons.heating, vec![1.0, 2.0, 3.0]);
+        assert_eq!(protons.mt_values, vec![fission]);
+        assert_eq!(protons.frames_of_reference.get(&fission), Some(&ExitingNeutronFrameOfReference::Laboratory));
+        assert_eq!(protons.yield_multipliers, vec![fission]);
+    }
+
+    #[tokio::test]
+    async fn test_particle_production_sub_block_parsing() {
+        let parsed_ace = get_parsed_test_file().await;
+        let particle_production = parsed_ace.data_blocks.ParticleProduction.unwrap();
+        let protons = particle_production.get(&ParticleType::Proton).unwrap();
+        let fission = MTNumber::Fission as usize;
+
+        // The proton yield multiplies the fission cross section
+        let proton_yield = match protons.production.get(&fission).unwrap() {
+            PhotonProduction::ParticleYield(proton_yield) => proton_yield,
+            _ => panic!("This should be a yield"),
+        };
+        assert_eq!(proton_yield.neutron_mt, fission);
+        assert_eq!(proton_yield.yield_table.interpolate(3.0E+01).unwrap(), 2.0);
+
+        // Protons are isotropic at low energies and tabulated at high energies
+        let angular_distribution = protons.angular_distributions.get(&fission).unwrap();
+        assert_eq!(angular_distribution.energy, vec![1.0E-11, 3.0E+01]);
+        assert_eq!(
+            angular_distribution.distributions,
+            vec![
+                AngularDistribution::Isotropic(IsotropicAngularDistribution {}),
+                AngularDistribution::Tabulated(
+                    TabulatedAngularDistribution::new(InterpolationScheme::LinLin, vec![-1.0, 1.0], vec![0.0, 1.0]).unwrap()
+                ),
+            ]
+        );
+
+        // Protons are emitted with an evaporation spectrum
+        let energy_distribution = protons.energy_distributions.get(&fission).unwrap();
+        assert_eq!(energy_distribution.laws.len(), 1);
+        assert_eq!(energy_distribution.laws[0].law(), 9);
+    }
+}
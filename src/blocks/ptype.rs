@@ -0,0 +1,138 @@
+use std::ops::Deref;
+
+use crate::arrays::{Arrays, XxsArray, XxsWords};
+use crate::blocks::BlockType;
+use crate::blocks::block_traits::{get_block_start, pull_block_range, PullFromXXS, XxsBlock, Process};
+use crate::pace_error::PaceError;
+
+//=====================================================================
+// PTYPE data block
+//
+// Contains the types of the secondary particles for which production
+// data is given. See the ACE format spec for a description of the
+// PTYPE block.
+//=====================================================================
+#[derive(Debug, Clone, PartialEq)]
+pub struct PTYPE ( pub Vec<ParticleType> );
+
+impl Deref for PTYPE {
+    type Target = Vec<ParticleType>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<'a> PullFromXXS<'a> for PTYPE {
+    const BLOCK_TYPE: BlockType = BlockType::PTYPE;
+
+    fn pull_from_xxs_array(arrays: &Arrays<'_, 'a>) -> Result<Option<XxsBlock<'a>>, PaceError> {
+        // We expect PTYPE if NXS(7) (NTYPE) != 0
+        let has_particle_production = arrays.nxs.ntype != 0;
+
+        // Get the starting index of the block in the XXS array
+        let Some(block_start) = get_block_start(
+            &BlockType::PTYPE,
+            arrays,
+            has_particle_production,
+            "PTYPE is expected if NXS(7) (NTYPE) != 0, but PTYPE was not found.".to_string(),
+        )? else {
+            return Ok(None);
+        };
+
+        // Calculate the block length, see the PTYPE description in the ACE spec
+        let block_length = arrays.nxs.ntype;
+
+        // Return the block's raw data as a slice
+        pull_block_range(&Self::BLOCK_TYPE, block_start, block_length, arrays)
+    }
+}
+
+impl<'a> Process<'a> for PTYPE {
+    type Dependencies = ();
+
+    fn process(data: XxsArray<'_>, _arrays: &Arrays, _dependencies: ()) -> Result<Self, PaceError> {
+        Ok(Self(data.uints(0, data.len())?.into_iter().map(ParticleType::from).collect()))
+    }
+}
+
+impl std::fmt::Display for PTYPE {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "PTYPE({} particle types)", self.len())
+    }
+}
+
+//=====================================================================
+// Particle types, identified by their MCNP particle number.
+//=====================================================================
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ParticleType {
+    Neutron,
+    Photon,
+    Electron,
+    Proton,
+    Deuteron,
+    Triton,
+    Helion,
+    Alpha,
+    Other(usize),
+}
+
+impl ParticleType {
+    // MT number of the total production cross section of the particle, if there is one
+    pub fn production_mt(&self) -> Option<usize> {
+        match self {
+            ParticleType::Neutron => Some(201),
+            ParticleType::Photon => Some(202),
+            ParticleType::Proton => Some(203),
+            ParticleType::Deuteron => Some(204),
+            ParticleType::Triton => Some(205),
+            ParticleType::Helion => Some(206),
+            ParticleType::Alpha => Some(207),
+            ParticleType::Electron | ParticleType::Other(_) => None,
+        }
+    }
+}
+
+// Produces a ParticleType from an MCNP particle number
+impl From<usize> for ParticleType {
+    fn from(value: usize) -> Self {
+        match value {
+            1 => ParticleType::Neutron,
+            2 => ParticleType::Photon,
+            3 => ParticleType::Electron,
+            9 => ParticleType::Proton,
+            31 => ParticleType::Deuteron,
+            32 => ParticleType::Triton,
+            33 => ParticleType::Helion,
+            34 => ParticleType::Alpha,
+            n => ParticleType::Other(n),
+        }
+    }
+}
+
+impl std::fmt::Display for ParticleType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParticleType::Other(n) => write!(f, "Particle({})", n),
+            particle => write!(f, "{:?}", particle),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::utils::get_parsed_test_file;
+
+    #[tokio::test]
+    async fn test_ptype_parsing() {
+        let parsed_ace = get_parsed_test_file().await;
+
+        // Check contents
+        let ptype = parsed_ace.data_blocks.PTYPE.unwrap();
+        assert_eq!(ptype.0, vec![ParticleType::Proton]);
+        assert_eq!(ptype[0].production_mt(), Some(203));
+    }
+}
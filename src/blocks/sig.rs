@@ -1,13 +1,13 @@
 use std::ops::Deref;
-use std::sync::Mutex;
 use std::collections::HashMap;
 
 use rayon::prelude::*;
 
 use crate::helpers::reaction_type_from_MT;
-use crate::arrays::Arrays;
+use crate::arrays::{Arrays, XxsArray, XxsWords};
 use crate::blocks::{BlockType, ESZ, MTR, LSIG};
-use crate::blocks::block_traits::{get_block_start, block_range_to_slice, PullFromXXS, Process};
+use crate::blocks::block_traits::{get_block_start, get_xxs_uint, required_block, pull_block_range, PullFromXXS, XxsBlock, Process};
+use crate::pace_error::{PaceError, get_slice};
 
 //=====================================================================
 // SIG data block
@@ -27,63 +27,61 @@ impl Deref for SIG {
 }
 
 impl<'a> PullFromXXS<'a> for SIG {
-    fn pull_from_xxs_array(arrays: &'a Arrays) -> Option<&'a [f64]> {
+    const BLOCK_TYPE: BlockType = BlockType::SIG;
+
+    fn pull_from_xxs_array(arrays: &Arrays<'_, 'a>) -> Result<Option<XxsBlock<'a>>, PaceError> {
         // We expect SIG if NXS(4) (NTR) != 0
         let has_xs_other_than_elastic = arrays.nxs.ntr != 0;
 
         // Get the starting index of the block in the XXS array
-        let block_start = get_block_start(
+        let Some(block_start) = get_block_start(
             &BlockType::SIG,
             arrays,
             has_xs_other_than_elastic,
             "SIG is expected if NXS(4) (NTR) != 0, but SIG was not found.".to_string(),
-        )?;
+        )? else {
+            return Ok(None);
+        };
 
         // Calculate the block length, see the SIG description in the ACE spec
         // Loop over the number of cross sections
         let mut block_length: usize = 1;
         for _ in 0..arrays.nxs.ntr {
             // Get the number of energy points in the cross section
-            let num_entries = arrays.xxs[block_start + block_length].to_bits() as usize;
+            let num_entries = get_xxs_uint(&Self::BLOCK_TYPE, block_start + block_length, arrays)?;
             // Jump forward to the next cross section
             block_length += num_entries + 2;
         }
 
         // Return the block's raw data as a slice
-        Some(block_range_to_slice(block_start, block_length, arrays))
+        pull_block_range(&Self::BLOCK_TYPE, block_start, block_length, arrays)
     }
 }
 
 impl<'a> Process<'a> for SIG {
     type Dependencies = (&'a Option<MTR>, &'a Option<LSIG>, &'a Option<ESZ>);
 
-    fn process(data: &[f64], _arrays: &Arrays, dependencies: (&Option<MTR>, &Option<LSIG>, &Option<ESZ>)) -> Self {
+    fn process(data: XxsArray<'_>, _arrays: &Arrays, dependencies: (&Option<MTR>, &Option<LSIG>, &Option<ESZ>)) -> Result<Self, PaceError> {
         let (mtr, lsig, esz) = (
-            dependencies.0.as_ref().unwrap(),
-            dependencies.1.as_ref().unwrap(),
-            dependencies.2.as_ref().unwrap(),
+            required_block(dependencies.0, BlockType::MTR)?,
+            required_block(dependencies.1, BlockType::LSIG)?,
+            required_block(dependencies.2, BlockType::ESZ)?,
         );
 
-        let xs = Mutex::new(CrossSectionMap::default()); // Use Mutex for thread-safe access
+        let xs = SigView::process(data, mtr, lsig, &esz.energy)?
+            .0
+            .into_par_iter()
+            .map(|(mt, view)| (mt, CrossSection::from(&view)))
+            .collect();
 
-        // Parallelize the loop over cross sections using par_iter()
-        mtr.par_iter().zip(lsig.par_iter()).for_each(|(mt, start_pos)| {
-            // Get the first position in the energy grid where we have a cross section value
-            let energy_start_index: usize = data[start_pos - 1].to_bits() as usize;
-            // Get the number of entries we have for the cross section
-            let num_xs_values: usize = data[*start_pos].to_bits() as usize;
+        Ok(Self(xs))
+    }
+}
 
-            // Get the cross section values
-            let xs_val = Vec::from(&data[start_pos + 1..start_pos + 1 + num_xs_values]);
-            // Get the corresponding energy values
-            let energy = Vec::from(&esz.energy[energy_start_index - 1..(energy_start_index - 1 + num_xs_values)]);
-        
-            // Lock the Mutex and insert into the CrossSectionMap
-            let mut xs_lock = xs.lock().unwrap();
-            xs_lock.insert(*mt, CrossSection { mt: *mt, energy, xs_val });
-        });
-
-        Self(xs.into_inner().unwrap())
+impl SIG {
+    // Borrow the block as a SigView
+    pub fn view(&self) -> SigView<'_> {
+        SigView(self.iter().map(|(&mt, xs)| (mt, xs.view())).collect())
     }
 }
 
@@ -99,6 +97,43 @@ impl std::fmt::Display for SIG {
     }
 }
 
+//=====================================================================
+// Borrowed view of the SIG block. Each cross section holds a slice of
+// the block data for its values and a slice of the ESZ energy grid for
+// its energies, rather than copies of them.
+//=====================================================================
+#[derive(Debug, Clone)]
+pub struct SigView<'a> ( pub HashMap<usize, CrossSectionView<'a>> );
+
+impl<'a> Deref for SigView<'a> {
+    type Target = HashMap<usize, CrossSectionView<'a>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<'a> SigView<'a> {
+    pub fn process(data: XxsArray<'a>, mtr: &MTR, lsig: &LSIG, energy: &'a [f64]) -> Result<Self, PaceError> {
+        // Parallelize the loop over cross sections using par_iter()
+        let xs = mtr.par_iter().zip(lsig.par_iter()).map(|(&mt, &start_pos)| {
+            // Get the first position in the energy grid where we have a cross section value
+            let energy_start_index = data.uint(start_pos.saturating_sub(1))?;
+            // Get the number of entries we have for the cross section
+            let num_xs_values = data.uint(start_pos)?;
+
+            // Get the cross section values
+            let xs_val = data.floats(start_pos + 1, num_xs_values)?;
+            // Get the corresponding energy values
+            let energy = get_slice(energy, energy_start_index.saturating_sub(1), num_xs_values)?;
+
+            Ok((mt, CrossSectionView { mt, energy, xs_val }))
+        }).collect::<Result<HashMap<_, _>, PaceError>>()?;
+
+        Ok(Self(xs))
+    }
+}
+
 //=====================================================================
 // Helper struct to represent a cross section.
 //=====================================================================
@@ -111,7 +146,55 @@ pub struct CrossSection {
     pub xs_val: Vec<f64>,
 }
 
-impl<'a> std::fmt::Display for CrossSection {
+impl CrossSection {
+    // Borrow the cross section as a CrossSectionView
+    pub fn view(&self) -> CrossSectionView<'_> {
+        CrossSectionView { mt: self.mt, energy: &self.energy, xs_val: &self.xs_val }
+    }
+
+    // Evaluate the cross section at an energy (given in MeV), see CrossSectionView::evaluate
+    pub fn evaluate(&self, energy: f64) -> f64 {
+        self.view().evaluate(energy)
+    }
+}
+
+impl From<&CrossSectionView<'_>> for CrossSection {
+    fn from(view: &CrossSectionView<'_>) -> Self {
+        Self { mt: view.mt, energy: view.energy.to_vec(), xs_val: view.xs_val.to_vec() }
+    }
+}
+
+// A cross section which borrows its energies and values rather than owning them
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CrossSectionView<'a> {
+    pub mt: usize,
+    pub energy: &'a [f64],
+    pub xs_val: &'a [f64],
+}
+
+impl CrossSectionView<'_> {
+    // Evaluate the cross section at an energy (given in MeV) with linear-linear interpolation.
+    // Below the first energy (e.g. the reaction threshold) the cross section is zero, and
+    // above the last energy it is held constant.
+    pub fn evaluate(&self, energy: f64) -> f64 {
+        match (self.energy.first(), self.energy.last()) {
+            (Some(&first), Some(&last)) => {
+                if energy < first {
+                    return 0.0;
+                }
+                if energy >= last {
+                    return self.xs_val[self.xs_val.len() - 1];
+                }
+                let i = self.energy.partition_point(|&e| e <= energy) - 1;
+                let factor = (energy - self.energy[i]) / (self.energy[i + 1] - self.energy[i]);
+                self.xs_val[i] + factor * (self.xs_val[i + 1] - self.xs_val[i])
+            },
+            _ => 0.0,
+        }
+    }
+}
+
+impl std::fmt::Display for CrossSection {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "CrossSection(MT={} {})", self.mt, reaction_type_from_MT(self.mt))
     }
@@ -136,4 +219,17 @@ mod tests {
         assert_eq!(fission_xs.energy, vec![1.0, 2.0, 3.0]);
         assert_eq!(fission_xs.xs_val, vec![17.0, 38.0, 100.0]);
     }
+
+    #[tokio::test]
+    async fn test_cross_section_evaluation() {
+        let parsed_ace = get_parsed_test_file().await;
+        let sig = parsed_ace.data_blocks.SIG.unwrap();
+        let fission_xs = sig.get(&18).unwrap();
+
+        assert_eq!(fission_xs.evaluate(0.5), 0.0);
+        assert_eq!(fission_xs.evaluate(1.0), 17.0);
+        assert_eq!(fission_xs.evaluate(1.5), 27.5);
+        assert_eq!(fission_xs.evaluate(3.0), 100.0);
+        assert_eq!(fission_xs.evaluate(4.0), 100.0);
+    }
 }
\ No newline at end of file
@@ -0,0 +1,220 @@
+use std::collections::HashMap;
+use std::ops::Deref;
+
+use thiserror::Error;
+
+use crate::arrays::{Arrays, XxsArray};
+use crate::blocks::{BlockType, MTR, LSIG};
+use crate::blocks::block_traits::{get_block_start, get_block_length_from_next_block, required_block, pull_block_range, PullFromXXS, XxsBlock, Process};
+use crate::pace_error::PaceError;
+use crate::interpolation::{InterpolationScheme, InterpolationTable, InterpolationError};
+
+//=====================================================================
+// SIGD data block
+//
+// Contains the reaction cross sections of a dosimetry ("y" class) ACE
+// table. Dosimetry tables have no ESZ energy grid, so each cross
+// section is given as its own interpolation table. The block occupies
+// the position of the SIG block in the JXS array, and is located
+// through the MTR and LSIG blocks in the same way.
+//=====================================================================
+#[derive(Debug, Clone, PartialEq)]
+pub struct SIGD ( pub HashMap<usize, InterpolationTable> );
+
+impl Deref for SIGD {
+    type Target = HashMap<usize, InterpolationTable>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<'a> PullFromXXS<'a> for SIGD {
+    const BLOCK_TYPE: BlockType = BlockType::SIG;
+
+    fn pull_from_xxs_array(arrays: &Arrays<'_, 'a>) -> Result<Option<XxsBlock<'a>>, PaceError> {
+        // We expect SIGD if NXS(4) (NTR) != 0
+        let has_reactions = arrays.nxs.ntr != 0;
+
+        // Get the starting index of the block in the XXS array
+        let Some(block_start) = get_block_start(
+            &BlockType::SIG,
+            arrays,
+            has_reactions,
+            "SIGD is expected if NXS(4) (NTR) != 0, but SIGD was not found.".to_string(),
+        )? else {
+            return Ok(None);
+        };
+
+        // Calculate the block length
+        // - Each cross section is a full interpolation table, we bound the data by the start of
+        //   the next block.
+        let block_length = get_block_length_from_next_block(block_start, arrays);
+
+        // Return the block's raw data as a slice
+        pull_block_range(&Self::BLOCK_TYPE, block_start, block_length, arrays)
+    }
+}
+
+impl<'a> Process<'a> for SIGD {
+    type Dependencies = (&'a Option<MTR>, &'a Option<LSIG>);
+
+    fn process(data: XxsArray<'_>, _arrays: &Arrays, dependencies: (&Option<MTR>, &Option<LSIG>)) -> Result<Self, PaceError> {
+        let (mtr, lsig) = (
+            required_block(dependencies.0, BlockType::MTR)?,
+            required_block(dependencies.1, BlockType::LSIG)?,
+        );
+
+        Ok(Self(
+            mtr.iter()
+                .zip(lsig.iter())
+                .map(|(&mt, &locator)| Ok((mt, InterpolationTable::process(data.tail(locator.saturating_sub(1))?)?)))
+                .collect::<Result<_, PaceError>>()?
+        ))
+    }
+}
+
+impl SIGD {
+    // Cross section of reaction `mt` at an energy (given in MeV), zero outside of the tabulated
+    // range. None if the reaction is not present in the table.
+    pub fn evaluate(&self, mt: usize, energy: f64) -> Option<f64> {
+        self.get(&mt).map(|table| table.interpolate(energy).unwrap_or(0.0))
+    }
+
+    // Fold the cross section of reaction `mt` against a group flux, returning the reaction rate
+    // Σ φ_g σ_g. The group boundaries are given in ascending energy (in MeV), with one more
+    // boundary than there are groups, and σ_g is the average cross section over each group.
+    // The cross section is integrated exactly between tabulated energies for each interpolation
+    // scheme, apart from Gamow interpolation which is not supported.
+    pub fn fold(&self, mt: usize, group_boundaries: &[f64], group_flux: &[f64]) -> Result<f64, DosimetryError> {
+        let table = self.get(&mt).ok_or(DosimetryError::ReactionNotFound(mt))?;
+        if group_boundaries.len() != group_flux.len() + 1 {
+            return Err(DosimetryError::GroupStructureMismatch(group_boundaries.len(), group_flux.len()));
+        }
+        if group_boundaries.windows(2).any(|bounds| bounds[1] <= bounds[0]) {
+            return Err(DosimetryError::UnsortedGroupBoundaries);
+        }
+        if let Some(region) = table.iter().find(|region| region.interpolation_scheme == InterpolationScheme::Gamow) {
+            return Err(DosimetryError::UnsupportedInterpolation(region.interpolation_scheme));
+        }
+
+        // Every pair of neighbouring tabulated points, along with the scheme used between them
+        let intervals: Vec<_> = table
+            .iter()
+            .flat_map(|region| region.data
+                .windows(2)
+                .map(|pair| (region.interpolation_scheme, (pair[0].x, pair[0].y), (pair[1].x, pair[1].y)))
+            )
+            .collect();
+
+        let reaction_rate = group_boundaries
+            .windows(2)
+            .zip(group_flux)
+            .map(|(bounds, &flux)| {
+                let (low, high) = (bounds[0], bounds[1]);
+
+                // Integrate the part of each interval which lies inside of the group
+                let integral = intervals
+                    .iter()
+                    .filter(|(_, start, end)| start.0 < high && low < end.0)
+                    .map(|&(scheme, start, end)| {
+                        let (a, b) = (low.max(start.0), high.min(end.0));
+                        scheme.integrate((a, scheme.interpolate(start, end, a)?), (b, scheme.interpolate(start, end, b)?))
+                    })
+                    .sum::<Result<f64, InterpolationError>>()?;
+                Ok(flux * integral / (high - low))
+            })
+            .sum::<Result<f64, DosimetryError>>()?;
+
+        Ok(reaction_rate)
+    }
+}
+
+impl std::fmt::Display for SIGD {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SIGD({} reactions)", self.len())
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum DosimetryError {
+    #[error("Reaction MT={0} is not present in the dosimetry table")]
+    ReactionNotFound(usize),
+
+    #[error("Expected one more group boundary than group fluxes, got {0} boundaries and {1} fluxes")]
+    GroupStructureMismatch(usize, usize),
+
+    #[error("Group boundaries must be given in strictly ascending energy")]
+    UnsortedGroupBoundaries,
+
+    #[error("{0} interpolation of dosimetry cross sections is not supported")]
+    UnsupportedInterpolation(InterpolationScheme),
+
+    #[error("Interpolation error: {0}")]
+    InterpolationError(#[from] InterpolationError),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::utils::get_parsed_dosimetry_test_file;
+
+    #[tokio::test]
+    async fn test_sigd_parsing() {
+        let parsed_ace = get_parsed_dosimetry_test_file().await;
+        let sigd = parsed_ace.sigd.unwrap();
+
+        assert_eq!(sigd.len(), 2);
+        assert_eq!(sigd.evaluate(102, 1.5), Some(3.0));
+        assert_eq!(sigd.evaluate(102, 5.0), Some(0.0));
+        assert_eq!(sigd.evaluate(107, 7.5), Some(0.1));
+        assert_eq!(sigd.evaluate(16, 7.5), None);
+    }
+
+    #[tokio::test]
+    async fn test_sigd_folding() {
+        let parsed_ace = get_parsed_dosimetry_test_file().await;
+        let sigd = parsed_ace.sigd.unwrap();
+
+        // Linear cross section, the groups outside of the tabulated range do not contribute
+        let group_boundaries = [0.0, 1.0, 2.0, 3.0, 4.0];
+        assert!((sigd.fold(102, &group_boundaries, &[1.0, 1.0, 1.0, 1.0]).unwrap() - 6.0).abs() < 1e-12);
+        assert!((sigd.fold(102, &group_boundaries, &[1.0, 2.0, 3.0, 4.0]).unwrap() - 15.0).abs() < 1e-12);
+
+        // Histogram cross section, starting partway through the group
+        let reaction_rate = sigd.fold(107, &[4.0, 10.0], &[6.0]).unwrap();
+        assert!((reaction_rate - 0.5).abs() < 1e-12);
+
+        // Invalid inputs
+        assert!(matches!(sigd.fold(16, &group_boundaries, &[1.0; 4]), Err(DosimetryError::ReactionNotFound(16))));
+        assert!(matches!(sigd.fold(102, &group_boundaries, &[1.0; 3]), Err(DosimetryError::GroupStructureMismatch(5, 3))));
+        assert!(matches!(sigd.fold(102, &[2.0, 1.0], &[1.0]), Err(DosimetryError::UnsortedGroupBoundaries)));
+    }
+
+    #[test]
+    fn test_sigd_folding_schemes() {
+        let table = |y: Vec<f64>, scheme| InterpolationTable::from_x_and_y(vec![1.0, 2.0, 4.0], y, scheme);
+        let sigd = SIGD(HashMap::from([
+            (1, table(vec![1.0, 4.0, 16.0], InterpolationScheme::LogLog)),
+            (2, table(vec![1.0, 0.5, 0.25], InterpolationScheme::LogLog)),
+            (3, table(vec![1.0, std::f64::consts::E, std::f64::consts::E.powi(3)], InterpolationScheme::LogLin)),
+            (4, table(vec![0.0, 2.0_f64.ln(), 4.0_f64.ln()], InterpolationScheme::LinLog)),
+            (5, table(vec![1.0, 1.0, 1.0], InterpolationScheme::Gamow)),
+        ]));
+
+        // σ = E², the integral from 1 to 3 is 26 / 3
+        assert!((sigd.fold(1, &[1.0, 3.0], &[2.0]).unwrap() - 26.0 / 3.0).abs() < 1e-12);
+        // σ = 1 / E, the integral from 1 to 3 is ln(3)
+        assert!((sigd.fold(2, &[1.0, 3.0], &[2.0]).unwrap() - 3.0_f64.ln()).abs() < 1e-12);
+        // σ = exp(E - 1), the integral from 1 to 3 is e² - 1
+        assert!((sigd.fold(3, &[1.0, 3.0], &[2.0]).unwrap() - (std::f64::consts::E.powi(2) - 1.0)).abs() < 1e-12);
+        // σ = ln(E), the integral from 1 to 3 is 3 ln(3) - 2
+        assert!((sigd.fold(4, &[1.0, 3.0], &[2.0]).unwrap() - (3.0 * 3.0_f64.ln() - 2.0)).abs() < 1e-12);
+
+        assert!(matches!(
+            sigd.fold(5, &[1.0, 3.0], &[2.0]),
+            Err(DosimetryError::UnsupportedInterpolation(InterpolationScheme::Gamow))
+        ));
+    }
+}
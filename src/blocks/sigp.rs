@@ -0,0 +1,207 @@
+use std::collections::HashMap;
+use std::ops::Deref;
+
+use crate::arrays::{Arrays, XxsArray, XxsWords};
+use crate::interpolation::InterpolationTable;
+use crate::blocks::{BlockType, ESZ, MTRP, LSIGP, CrossSection};
+use crate::blocks::block_traits::{get_block_start, get_xxs_uint, required_block, pull_block_range, PullFromXXS, XxsBlock, Process};
+use crate::pace_error::{PaceError, get_slice};
+
+type PhotonProductionMap = HashMap<usize, PhotonProduction>;
+
+//=====================================================================
+// SIGP data block
+//
+// Contains photon production data for each photon production reaction.
+// This is either given as a yield which multiplies a neutron cross
+// section (MFTYPE 12 or 16), or directly as a cross section (MFTYPE
+// 13). See the ACE format spec for a description of the SIGP block.
+//=====================================================================
+#[derive(Debug, Clone)]
+pub struct SIGP ( pub PhotonProductionMap );
+
+impl Deref for SIGP {
+    type Target = PhotonProductionMap;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<'a> PullFromXXS<'a> for SIGP {
+    const BLOCK_TYPE: BlockType = BlockType::SIGP;
+
+    fn pull_from_xxs_array(arrays: &Arrays<'_, 'a>) -> Result<Option<XxsBlock<'a>>, PaceError> {
+        // We expect SIGP if NXS(6) (NTRP) != 0
+        let has_photon_production = arrays.nxs.ntrp != 0;
+
+        // Get the starting index of the block in the XXS array
+        let Some(block_start) = get_block_start(
+            &BlockType::SIGP,
+            arrays,
+            has_photon_production,
+            "SIGP is expected if NXS(6) (NTRP) != 0, but SIGP was not found.".to_string(),
+        )? else {
+            return Ok(None);
+        };
+
+        // Calculate the block length, see the SIGP description in the ACE spec
+        // - Each reaction's data is found through the LSIGP locators, so we find the last reaction
+        //   in the block and add on its length.
+        let Some(lsigp) = LSIGP::pull_from_xxs_array(arrays)? else {
+            return Ok(None);
+        };
+        let last_entry_locator = lsigp.data.uints(0, lsigp.data.len())?
+            .into_iter()
+            .max()
+            .unwrap_or(1);
+        let last_entry_start = block_start + last_entry_locator - 1;
+        let last_entry_length = match get_xxs_uint(&Self::BLOCK_TYPE, last_entry_start, arrays)? {
+            // MFTYPE, IE, NE, and the cross section values
+            13 => 3 + get_xxs_uint(&Self::BLOCK_TYPE, last_entry_start + 2, arrays)?,
+            // MFTYPE, MTMULT, and the yield table
+            6 | 12 | 16 => 2 + InterpolationTable::get_table_length(last_entry_start + 2, arrays.xxs)?,
+            // Expected 6, 12, 13, or 16
+            mftype => return Err(PaceError::unknown_code("photon production MFTYPE", mftype)),
+        };
+        let block_length = last_entry_locator - 1 + last_entry_length;
+
+        // Return the block's raw data as a slice
+        pull_block_range(&Self::BLOCK_TYPE, block_start, block_length, arrays)
+    }
+}
+
+impl<'a> Process<'a> for SIGP {
+    type Dependencies = (&'a Option<MTRP>, &'a Option<LSIGP>, &'a Option<ESZ>);
+
+    fn process(data: XxsArray<'_>, _arrays: &Arrays, dependencies: (&Option<MTRP>, &Option<LSIGP>, &Option<ESZ>)) -> Result<Self, PaceError> {
+        let (mtrp, lsigp, esz) = (
+            required_block(dependencies.0, BlockType::MTRP)?,
+            required_block(dependencies.1, BlockType::LSIGP)?,
+            required_block(dependencies.2, BlockType::ESZ)?,
+        );
+
+        let photon_production = mtrp
+            .iter()
+            .zip(lsigp.iter())
+            .map(|(&mt, &locator)| Ok((mt, PhotonProduction::process(data, mt, locator, esz)?)))
+            .collect::<Result<_, PaceError>>()?;
+
+        Ok(Self(photon_production))
+    }
+}
+
+impl std::fmt::Display for SIGP {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SIGP({} reactions)", self.len())
+    }
+}
+
+//=====================================================================
+// Photon production data may be given in one of three forms, which
+// correspond to the ENDF file that the data originated from.
+//=====================================================================
+#[derive(Debug, Clone)]
+pub enum PhotonProduction {
+    MultiplicityYield(PhotonYield),     // MFTYPE 12, photon multiplicities from ENDF File 12
+    CrossSection(CrossSection),         // MFTYPE 13, photon production cross sections from ENDF File 13
+    ParticleYield(PhotonYield),         // MFTYPE 16, photon yields from ENDF File 6
+}
+
+impl PhotonProduction {
+    // Process the production data for reaction `mt` which starts at `locator` (relative to the start
+    // of the block data). This layout is shared by the SIGP block and the SIGH particle production sub-blocks.
+    pub(crate) fn process(data: XxsArray<'_>, mt: usize, locator: usize, esz: &ESZ) -> Result<Self, PaceError> {
+        let start = locator.saturating_sub(1);
+        match data.uint(start)? {
+            13 => {
+                // Get the first position in the energy grid where we have a cross section value
+                let energy_start_index = data.uint(start + 1)?;
+                // Get the number of entries we have for the cross section
+                let num_xs_values = data.uint(start + 2)?;
+                Ok(PhotonProduction::CrossSection(CrossSection {
+                    mt,
+                    energy: get_slice(&esz.energy, energy_start_index.saturating_sub(1), num_xs_values)?.to_vec(),
+                    xs_val: data.floats(start + 3, num_xs_values)?.to_vec(),
+                }))
+            },
+            // Photonuclear tables flag yields from ENDF File 6 with an MFTYPE of 6 rather than 16
+            mftype @ (6 | 12 | 16) => {
+                let photon_yield = PhotonYield {
+                    neutron_mt: data.uint(start + 1)?,
+                    yield_table: InterpolationTable::process(data.tail(start + 2)?)?,
+                };
+                if mftype == 12 {
+                    Ok(PhotonProduction::MultiplicityYield(photon_yield))
+                } else {
+                    Ok(PhotonProduction::ParticleYield(photon_yield))
+                }
+            },
+            // Expected 6, 12, 13, or 16
+            mftype => Err(PaceError::unknown_code("photon production MFTYPE", mftype)),
+        }
+    }
+
+    // The MFTYPE flag of the data as given in the ACE file
+    pub fn mftype(&self) -> usize {
+        match self {
+            PhotonProduction::MultiplicityYield(_) => 12,
+            PhotonProduction::CrossSection(_) => 13,
+            PhotonProduction::ParticleYield(_) => 16,
+        }
+    }
+}
+
+// Photon yield as a function of incident energy. The photon production cross section
+// is this yield multiplied by the cross section of the neutron reaction `neutron_mt`.
+#[derive(Debug, Clone)]
+pub struct PhotonYield {
+    pub neutron_mt: usize,
+    pub yield_table: InterpolationTable,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::utils::get_parsed_test_file;
+
+    #[tokio::test]
+    async fn test_sigp_parsing() {
+        let parsed_ace = get_parsed_test_file().await;
+
+        // Check contents
+        let sigp = parsed_ace.data_blocks.SIGP.unwrap();
+        assert_eq!(sigp.len(), 2);
+        assert_eq!(sigp.get(&18001).unwrap().mftype(), 13);
+        assert_eq!(sigp.get(&18002).unwrap().mftype(), 12);
+    }
+
+    #[tokio::test]
+    async fn test_sigp_cross_section_parsing() {
+        let parsed_ace = get_parsed_test_file().await;
+        let sigp = parsed_ace.data_blocks.SIGP.unwrap();
+
+        let xs = match sigp.get(&18001).unwrap() {
+            PhotonProduction::CrossSection(xs) => xs,
+            _ => panic!("This should be a cross section"),
+        };
+        assert_eq!(xs.mt, 18001);
+        assert_eq!(xs.energy, vec![1.0, 2.0, 3.0]);
+        assert_eq!(xs.xs_val, vec![1.0, 2.0, 3.0]);
+    }
+
+    #[tokio::test]
+    async fn test_sigp_yield_parsing() {
+        let parsed_ace = get_parsed_test_file().await;
+        let sigp = parsed_ace.data_blocks.SIGP.unwrap();
+
+        let photon_yield = match sigp.get(&18002).unwrap() {
+            PhotonProduction::MultiplicityYield(photon_yield) => photon_yield,
+            _ => panic!("This should be a yield"),
+        };
+        assert_eq!(photon_yield.neutron_mt, 18);
+        assert_eq!(photon_yield.yield_table.interpolate(1.0E-11).unwrap(), 1.0);
+        assert_eq!(photon_yield.yield_table.interpolate(3.0E+01).unwrap(), 2.0);
+    }
+}
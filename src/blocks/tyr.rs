@@ -1,9 +1,10 @@
 use std::collections::HashMap;
 use std::ops::Deref;
 
-use crate::arrays::Arrays;
+use crate::arrays::{Arrays, XxsArray, XxsWords};
 use crate::blocks::{BlockType, MTR};
-use crate::blocks::block_traits::{get_block_start, block_range_to_slice, PullFromXXS, Process};
+use crate::blocks::block_traits::{get_block_start, required_block, pull_block_range, PullFromXXS, XxsBlock, Process};
+use crate::pace_error::PaceError;
 
 //=====================================================================
 // TYR data block
@@ -23,48 +24,46 @@ impl Deref for TYR {
 }
 
 impl<'a> PullFromXXS<'a> for TYR {
-    fn pull_from_xxs_array(arrays: &'a Arrays) -> Option<&'a [f64]> {
+    const BLOCK_TYPE: BlockType = BlockType::TYR;
+
+    fn pull_from_xxs_array(arrays: &Arrays<'_, 'a>) -> Result<Option<XxsBlock<'a>>, PaceError> {
         // We expect TYR if NXS(4) (NTR) != 0
         let has_xs_other_than_elastic = arrays.nxs.ntr != 0;
 
         // Get the starting index of the block in the XXS array
-        let block_start = get_block_start(
+        let Some(block_start) = get_block_start(
             &BlockType::TYR,
             arrays,
             has_xs_other_than_elastic,
             "TYR is expected if NXS(4) (NTR) != 0, but TYR was not found.".to_string(),
-        )?;
+        )? else {
+            return Ok(None);
+        };
 
         // Calculate the block end index, see the TYR description in the ACE spec
         let num_reactions = arrays.nxs.ntr;
         let block_length = num_reactions;
 
         // Return the block's raw data as a slice
-        Some(block_range_to_slice(block_start, block_length, arrays))
+        pull_block_range(&Self::BLOCK_TYPE, block_start, block_length, arrays)
     }
 }
 
 impl<'a> Process<'a> for TYR {
     type Dependencies = &'a Option<MTR>;
 
-    fn process(data: &[f64], _arrays: &Arrays, mtr: &Option<MTR>) -> Self {
-        let neutron_release: HashMap<usize, ExitingNeutronData> = data
+    fn process(data: XxsArray<'_>, _arrays: &Arrays, mtr: &Option<MTR>) -> Result<Self, PaceError> {
+        let neutron_release = required_block(mtr, BlockType::MTR)?
             .iter()
-            .enumerate()
-            .map(|(i, &val)| (
-                mtr.as_ref().unwrap()[i],
-                ExitingNeutronData {
-                    neutron_release: NumberOfExitingNeutrons::from(val.to_bits() as isize),
-                    frame_of_reference: ExitingNeutronFrameOfReference::from(val.to_bits() as isize),
-                }
-            ))
-            .collect();
-
-        Self(neutron_release)
+            .zip(data.ints(0, data.len())?)
+            .map(|(&mt, value)| Ok((mt, ExitingNeutronData::try_from(value as isize)?)))
+            .collect::<Result<HashMap<usize, ExitingNeutronData>, PaceError>>()?;
+
+        Ok(Self(neutron_release))
     }
 }
 
-impl<'a> TYR {
+impl TYR {
     pub fn mt_values_with_neutron_release(&self) -> Vec<usize> {
         self.iter()
             .filter(|(_, exit_neutron_data)| exit_neutron_data.neutron_release != NumberOfExitingNeutrons::Absorption)
@@ -88,22 +87,26 @@ impl std::fmt::Display for TYR {
 pub enum NumberOfExitingNeutrons {
     Discrete(usize),
     EnergyDependent,
+    TabulatedYield(usize),
     Absorption
 }
 // Produces a NumberOfExitingNeutrons from an isize value
-// 0 = Absorption, +/- (1-4 = Discrete, 19 = EnergyDependent, > 100 = EnergyDependent)
-impl From<isize> for NumberOfExitingNeutrons {
-    fn from(value: isize) -> Self {
+// 0 = Absorption, +/- (1-4 = Discrete, 19 = EnergyDependent, > 100 = TabulatedYield)
+// For TabulatedYield, we keep the location of the yield table relative to the start of the DLW block.
+impl TryFrom<isize> for NumberOfExitingNeutrons {
+    type Error = PaceError;
+
+    fn try_from(value: isize) -> Result<Self, Self::Error> {
         match value.abs() {
-            0 => NumberOfExitingNeutrons::Absorption,
-            1 => NumberOfExitingNeutrons::Discrete(1),
-            2 => NumberOfExitingNeutrons::Discrete(2),
-            3 => NumberOfExitingNeutrons::Discrete(3),
-            4 => NumberOfExitingNeutrons::Discrete(4),
-            n if n > 100 || n == 19 => NumberOfExitingNeutrons::EnergyDependent,
-            _ => {
-                panic!("Invalid value in TYR describing neutron release, allowable values are 0, +/- 1-4, 19, and > 100, found: {}", value)
-            }
+            0 => Ok(NumberOfExitingNeutrons::Absorption),
+            1 => Ok(NumberOfExitingNeutrons::Discrete(1)),
+            2 => Ok(NumberOfExitingNeutrons::Discrete(2)),
+            3 => Ok(NumberOfExitingNeutrons::Discrete(3)),
+            4 => Ok(NumberOfExitingNeutrons::Discrete(4)),
+            19 => Ok(NumberOfExitingNeutrons::EnergyDependent),
+            n if n > 100 => Ok(NumberOfExitingNeutrons::TabulatedYield(n as usize - 100)),
+            // Allowable values are 0, +/- 1-4, 19, and > 100
+            _ => Err(PaceError::unknown_code("TYR neutron release", value)),
         }
     }
 }
@@ -121,8 +124,7 @@ impl From<isize> for ExitingNeutronFrameOfReference {
         match value {
             0 => ExitingNeutronFrameOfReference::NoRelease,
             n if n > 0 => ExitingNeutronFrameOfReference::Laboratory,
-            n if n < 0 => ExitingNeutronFrameOfReference::CenterOfMass,
-            _ => panic!("Unexpected value for ExitingNeutronFrameOfReference: {}", value),
+            _ => ExitingNeutronFrameOfReference::CenterOfMass,
         }
     }
 }
@@ -134,12 +136,14 @@ pub struct ExitingNeutronData {
     pub frame_of_reference: ExitingNeutronFrameOfReference,
 }
 // Produces a ExitingNeutronData from an isize value
-impl From<isize> for ExitingNeutronData {
-    fn from(value: isize) -> Self {
-        Self {
-            neutron_release: NumberOfExitingNeutrons::from(value),
+impl TryFrom<isize> for ExitingNeutronData {
+    type Error = PaceError;
+
+    fn try_from(value: isize) -> Result<Self, Self::Error> {
+        Ok(Self {
+            neutron_release: NumberOfExitingNeutrons::try_from(value)?,
             frame_of_reference: ExitingNeutronFrameOfReference::from(value),
-        }
+        })
     }
 }
 
@@ -148,6 +152,7 @@ impl From<isize> for ExitingNeutronData {
 mod tests {
     use super::*;
     use crate::{utils::get_parsed_test_file, helpers::MTNumber};
+    use crate::blocks::block_traits::Parse;
 
     #[tokio::test]
     async fn test_tyr_parsing() {
@@ -163,4 +168,37 @@ mod tests {
             })
         );
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_unknown_neutron_release() {
+        assert_eq!(NumberOfExitingNeutrons::try_from(-19).unwrap(), NumberOfExitingNeutrons::EnergyDependent);
+        assert!(matches!(
+            NumberOfExitingNeutrons::try_from(5),
+            Err(PaceError::UnknownCode { code: 5, .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_tyr_parsing_errors() {
+        let parsed_ace = get_parsed_test_file().await;
+        let nxs = &parsed_ace.nxs_array;
+        let mtr = &parsed_ace.data_blocks.MTR;
+        let mut jxs = parsed_ace.jxs_array.clone();
+
+        // TYR is expected, but JXS does not give it
+        jxs.insert(BlockType::TYR, 0);
+        let arrays = Arrays { nxs, jxs: &jxs, xxs: XxsArray::untyped(&[]) };
+        assert!(matches!(TYR::parse(&arrays, mtr), Err(PaceError::MissingBlock { block: BlockType::TYR, .. })));
+
+        // TYR starts past the end of the XXS array
+        jxs.insert(BlockType::TYR, 1);
+        let arrays = Arrays { nxs, jxs: &jxs, xxs: XxsArray::untyped(&[]) };
+        assert!(matches!(TYR::parse(&arrays, mtr), Err(PaceError::BlockOutOfBounds { block: BlockType::TYR, .. })));
+
+        // TYR holds an unknown neutron release, which is reported along with the block
+        let xxs = vec![5.0; nxs.ntr];
+        let arrays = Arrays { nxs, jxs: &jxs, xxs: XxsArray::untyped(&xxs) };
+        let error = TYR::parse(&arrays, mtr).unwrap_err();
+        assert!(matches!(error, PaceError::InBlock { block: BlockType::TYR, index: 0, .. }));
+    }
+}
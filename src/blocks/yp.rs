@@ -0,0 +1,80 @@
+use std::ops::Deref;
+
+use crate::arrays::{Arrays, XxsArray, XxsWords};
+use crate::blocks::BlockType;
+use crate::blocks::block_traits::{get_block_start, get_xxs_uint, pull_block_range, PullFromXXS, XxsBlock, Process};
+use crate::pace_error::PaceError;
+
+//=====================================================================
+// YP data block
+//
+// Contains the list of neutron MT numbers which are used as yield
+// multipliers by photon production reactions given as yields (MFTYPE
+// 12 or 16 in SIGP). See the ACE format spec for a description of the
+// YP block.
+//=====================================================================
+#[derive(Debug, Clone, PartialEq)]
+pub struct YP ( pub Vec<usize> );
+
+impl Deref for YP {
+    type Target = Vec<usize>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<'a> PullFromXXS<'a> for YP {
+    const BLOCK_TYPE: BlockType = BlockType::YP;
+
+    fn pull_from_xxs_array(arrays: &Arrays<'_, 'a>) -> Result<Option<XxsBlock<'a>>, PaceError> {
+        // We expect YP if JXS(20) != 0, it is only present when some photon production is given as a yield
+        let has_yield_multipliers = arrays.jxs.get(&BlockType::YP) != 0;
+
+        // Get the starting index of the block in the XXS array
+        let Some(block_start) = get_block_start(
+            &BlockType::YP,
+            arrays,
+            has_yield_multipliers,
+            "YP is expected if JXS(20) != 0, but YP was not found.".to_string(),
+        )? else {
+            return Ok(None);
+        };
+
+        // Calculate the block length, see the YP description in the ACE spec
+        // The first entry is the number of yield multipliers (NYP)
+        let num_multipliers = get_xxs_uint(&Self::BLOCK_TYPE, block_start, arrays)?;
+        let block_length = num_multipliers + 1;
+
+        // Return the block's raw data as a slice
+        pull_block_range(&Self::BLOCK_TYPE, block_start, block_length, arrays)
+    }
+}
+
+impl<'a> Process<'a> for YP {
+    type Dependencies = ();
+
+    fn process(data: XxsArray<'_>, _arrays: &Arrays, _dependencies: ()) -> Result<Self, PaceError> {
+        Ok(Self(data.uints(1, data.len().saturating_sub(1))?))
+    }
+}
+
+impl std::fmt::Display for YP {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "YP({} multipliers)", self.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::get_parsed_test_file;
+
+    #[tokio::test]
+    async fn test_yp_parsing() {
+        let parsed_ace = get_parsed_test_file().await;
+
+        // Check contents
+        let yp = parsed_ace.data_blocks.YP.unwrap();
+        assert_eq!(yp.0, vec![18]);
+    }
+}
@@ -0,0 +1,115 @@
+use std::path::Path;
+use std::error::Error;
+
+use crate::utils::{ensure_not_ascii, PaceMmap};
+use crate::header::Header;
+use crate::arrays::{Arrays, IzawArray, NxsArray, JxsArray};
+use crate::blocks::{Parse, MTR, LQR, LSIG, SIGD, DosimetryError};
+
+//=====================================================================
+// Dosimetry data from a "y" class ACE table. These tables share the
+// NXS and JXS layout of continuous neutron tables, but only contain
+// the MTR, LQR, LSIG and SIGD blocks.
+//=====================================================================
+#[derive(Clone, Debug)]
+pub struct DosimetryData {
+    pub header: Header,
+    pub izaw_array: IzawArray,
+    pub nxs_array: NxsArray,
+    pub jxs_array: JxsArray,
+    pub mtr: Option<MTR>,
+    pub lqr: Option<LQR>,
+    pub sigd: Option<SIGD>,
+}
+
+impl DosimetryData {
+    pub fn from_file<P: AsRef<Path>>(file_path: P) -> Result<Self, Box<dyn Error + Send + Sync>> {
+        let path = file_path.as_ref();
+
+        // If we have an ASCII file, request that it first be converted to our own binary format
+        ensure_not_ascii(path)?;
+
+        // Create a memory map of the binary file and parse the table from it
+        Self::from_PACE(&PaceMmap::from_file(path)?)
+    }
+
+    // Parse the table from a memory map of a PACE file
+    pub fn from_PACE(mmap: &PaceMmap) -> Result<Self, Box<dyn Error + Send + Sync>> {
+        // Process the header and arrays
+        let header = Header::from_PACE(mmap)?;
+        let izaw_array = IzawArray::from_PACE(mmap)?;
+        let nxs_array = NxsArray::from_PACE(mmap)?;
+        let jxs_array = JxsArray::from_PACE(mmap)?;
+
+        // Process the blocks out of the XXS array
+        let arrays = Arrays { nxs: &nxs_array, jxs: &jxs_array, xxs: mmap.xxs_array() };
+        let mtr = MTR::parse(&arrays, ())?;
+        let lqr = LQR::parse(&arrays, &mtr)?;
+        let lsig = LSIG::parse(&arrays, ())?;
+        let sigd = SIGD::parse(&arrays, (&mtr, &lsig))?;
+
+        Ok(Self { header, izaw_array, nxs_array, jxs_array, mtr, lqr, sigd })
+    }
+
+    // ZAID of the table
+    #[inline]
+    pub fn zaid(&self) -> String {
+        self.header.zaid.clone()
+    }
+
+    // SZAID of the table (version 2.0.0 and later)
+    #[inline]
+    pub fn szaid(&self) -> Option<String> {
+        self.header.szaid.clone()
+    }
+
+    // ZA of the target
+    #[inline]
+    pub fn za(&self) -> usize {
+        self.nxs_array.za
+    }
+
+    // Cross section of reaction `mt` at an energy (given in MeV), None if the reaction is not
+    // present in the table
+    pub fn reaction_xs(&self, mt: usize, energy: f64) -> Option<f64> {
+        self.sigd.as_ref()?.evaluate(mt, energy)
+    }
+
+    // Reaction rate of reaction `mt` in a group flux, see `SIGD::fold`
+    pub fn fold(&self, mt: usize, group_boundaries: &[f64], group_flux: &[f64]) -> Result<f64, DosimetryError> {
+        self.sigd
+            .as_ref()
+            .ok_or(DosimetryError::ReactionNotFound(mt))?
+            .fold(mt, group_boundaries, group_flux)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::utils::get_parsed_dosimetry_test_file;
+
+    #[tokio::test]
+    async fn test_parse_dosimetry_test_file() {
+        let parsed_ace = get_parsed_dosimetry_test_file().await;
+        assert_eq!(parsed_ace.szaid(), Some(String::from("13027.800y")));
+        assert_eq!(parsed_ace.zaid(), String::from("13027.00y"));
+        assert_eq!(parsed_ace.za(), 13027);
+        assert_eq!(*parsed_ace.mtr.as_ref().unwrap(), MTR(vec![102, 107]));
+        assert_eq!(parsed_ace.lqr.as_ref().unwrap()[&107], -3.132);
+    }
+
+    #[test]
+    fn test_reject_ascii() {
+        let result = DosimetryData::from_file("LICENSE");
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_dosimetry_response() {
+        let parsed_ace = get_parsed_dosimetry_test_file().await;
+        assert_eq!(parsed_ace.reaction_xs(102, 2.0), Some(4.0));
+        assert!((parsed_ace.fold(102, &[1.0, 3.0], &[2.0]).unwrap() - 6.0).abs() < 1e-12);
+    }
+}
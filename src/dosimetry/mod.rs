@@ -0,0 +1,4 @@
+mod dosimetry_data;
+
+// Structs
+pub use dosimetry_data::DosimetryData;
@@ -0,0 +1,83 @@
+use anyhow::Result;
+
+use crate::utils::PaceMmap;
+
+//=====================================================================
+// Represents the NXS array of an eprdata table, as used for electron
+// transport. The electron data is stored in the same table as the
+// photoatomic data, and is described by NXS(7) through NXS(11). The
+// older el03 ("e" class) format is not supported.
+//=====================================================================
+#[derive(Clone, Debug, PartialEq)]
+pub struct ElectronNxsArray {
+    pub xxs_len: usize, // Number of entries in XXS array
+    pub z: usize,       // Atomic number
+    pub nssh: usize,    // Number of electroionization subshells
+    pub ne: usize,      // Number of electron energies in the ESZE block
+    pub na: usize,      // Number of incident energies with elastic angular distributions
+    pub nb: usize,      // Number of incident energies with bremsstrahlung photon spectra
+    pub nxl: usize,     // Number of incident energies with excitation energy losses
+}
+
+impl ElectronNxsArray {
+    pub fn from_PACE(mmap: &PaceMmap) -> Result<Self> {
+        let nxs_array: &[usize] = mmap.nxs_array();
+
+        Ok(Self {
+            xxs_len: nxs_array[0],
+            z: nxs_array[1],
+            nssh: nxs_array[6],
+            ne: nxs_array[7],
+            na: nxs_array[8],
+            nb: nxs_array[9],
+            nxl: nxs_array[10],
+        })
+    }
+}
+
+//=====================================================================
+// Represents the electron entries, JXS(11) through JXS(16), of the JXS
+// array of an eprdata table. Each entry is the (one indexed) start of
+// a block in the XXS array, or 0 if the block is not present.
+//=====================================================================
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ElectronJxsArray {
+    pub esze: usize,    // Electron energy grid and cross sections
+    pub subsh: usize,   // Electroionization subshell data
+    pub excit: usize,   // Excitation energy losses
+    pub elas: usize,    // Elastic angular distributions
+    pub breme: usize,   // Bremsstrahlung photon energy spectra
+    pub eion: usize,    // Electroionization knock-on electron energy spectra
+}
+
+impl ElectronJxsArray {
+    pub fn from_PACE(mmap: &PaceMmap) -> Result<Self> {
+        let jxs_array: &[usize] = mmap.jxs_array();
+
+        Ok(Self {
+            esze: jxs_array[10],
+            subsh: jxs_array[11],
+            excit: jxs_array[12],
+            elas: jxs_array[13],
+            breme: jxs_array[14],
+            eion: jxs_array[15],
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::get_parsed_electron_test_file;
+
+    #[tokio::test]
+    async fn test_electron_array_parsing() {
+        let parsed_ace = get_parsed_electron_test_file().await;
+
+        assert_eq!(parsed_ace.nxs_array.xxs_len, 101);
+        assert_eq!(parsed_ace.nxs_array.nssh, 1);
+        assert_eq!(parsed_ace.nxs_array.ne, 2);
+        assert_eq!(parsed_ace.nxs_array.nxl, 2);
+        assert_eq!(parsed_ace.jxs_array.esze, 53);
+        assert_eq!(parsed_ace.jxs_array.eion, 93);
+    }
+}
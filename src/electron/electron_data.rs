@@ -0,0 +1,233 @@
+use std::path::Path;
+use std::error::Error;
+
+use crate::unitf64::UnitF64;
+use crate::utils::{ensure_not_ascii, PaceMmap};
+use crate::header::Header;
+use crate::arrays::IzawArray;
+use crate::energy_distributions::{SampledEnergy, EnergyDistributionError};
+use crate::electron::{
+    ElectronNxsArray,
+    ElectronJxsArray,
+    ElectronCrossSections,
+    ElectronDistributions,
+    Excitation,
+    Subshell,
+    process_elastic,
+    process_bremsstrahlung,
+};
+
+//=====================================================================
+// Electron interaction data from an eprdata table. The electron data
+// shares its table with the photoatomic data (see PhotoatomicData),
+// and is described by NXS(7) through NXS(11) and JXS(11) through
+// JXS(16). The older el03 ("e" class) tables are not supported, and
+// are rejected by from_file.
+//=====================================================================
+#[derive(Clone, Debug)]
+pub struct ElectronData {
+    pub header: Header,
+    pub izaw_array: IzawArray,
+    pub nxs_array: ElectronNxsArray,
+    pub jxs_array: ElectronJxsArray,
+    pub cross_sections: Option<ElectronCrossSections>,  // ESZE
+    pub subshells: Vec<Subshell>,                       // SUBSH and EION
+    pub excitation: Option<Excitation>,                 // EXCIT
+    pub elastic: Option<ElectronDistributions>,         // ELAS
+    pub bremsstrahlung: Option<ElectronDistributions>,  // BREME
+}
+
+impl ElectronData {
+    pub fn from_file<P: AsRef<Path>>(file_path: P) -> Result<Self, Box<dyn Error + Send + Sync>> {
+        let path = file_path.as_ref();
+
+        // If we have an ASCII file, request that it first be converted to our own binary format
+        ensure_not_ascii(path)?;
+
+        // Create a memory map of the binary file and parse the table from it
+        Self::from_PACE(&PaceMmap::from_file(path)?)
+    }
+
+    // Parse the table from a memory map of a PACE file
+    pub fn from_PACE(mmap: &PaceMmap) -> Result<Self, Box<dyn Error + Send + Sync>> {
+        // Process the header and arrays. The older el03 ("e" class) tables have a different
+        // layout, so they are rejected rather than read as eprdata tables.
+        let header = Header::from_PACE(mmap)?;
+        if header.zaid.ends_with('e') {
+            return Err(format!(
+                "Table {} is an el03 (\"e\" class) electron table, which is not supported. \
+                Electron data is read from eprdata (\"p\" class) tables.", header.zaid).into()
+            )
+        }
+        let izaw_array = IzawArray::from_PACE(mmap)?;
+        let nxs_array = ElectronNxsArray::from_PACE(mmap)?;
+        let jxs_array = ElectronJxsArray::from_PACE(mmap)?;
+
+        // Process the blocks out of the XXS array
+        let xxs_array = mmap.xxs_array();
+        let cross_sections = ElectronCrossSections::process(xxs_array, &nxs_array, &jxs_array)?;
+        let subshells = Subshell::process(xxs_array, &nxs_array, &jxs_array)?;
+        let excitation = Excitation::process(xxs_array, &nxs_array, &jxs_array)?;
+        let elastic = process_elastic(xxs_array, &nxs_array, &jxs_array)?;
+        let bremsstrahlung = process_bremsstrahlung(xxs_array, &nxs_array, &jxs_array)?;
+
+        Ok(Self {
+            header,
+            izaw_array,
+            nxs_array,
+            jxs_array,
+            cross_sections,
+            subshells,
+            excitation,
+            elastic,
+            bremsstrahlung,
+        })
+    }
+
+    // ZAID of the table
+    #[inline]
+    pub fn zaid(&self) -> String {
+        self.header.zaid.clone()
+    }
+
+    // SZAID of the table (version 2.0.0 and later)
+    #[inline]
+    pub fn szaid(&self) -> Option<String> {
+        self.header.szaid.clone()
+    }
+
+    // Atomic number of the element
+    #[inline]
+    pub fn z(&self) -> usize {
+        self.nxs_array.z
+    }
+
+    // Whether the table contains any electron data
+    #[inline]
+    pub fn has_electron_data(&self) -> bool {
+        self.cross_sections.is_some()
+    }
+
+    // Elastic scattering cross section at an energy (given in MeV)
+    pub fn elastic_xs(&self, energy: f64) -> f64 {
+        self.cross_sections.as_ref().map_or(0.0, |xs| xs.elastic_xs(energy))
+    }
+
+    // Bremsstrahlung cross section at an energy (given in MeV)
+    pub fn bremsstrahlung_xs(&self, energy: f64) -> f64 {
+        self.cross_sections.as_ref().map_or(0.0, |xs| xs.bremsstrahlung_xs(energy))
+    }
+
+    // Excitation cross section at an energy (given in MeV)
+    pub fn excitation_xs(&self, energy: f64) -> f64 {
+        self.cross_sections.as_ref().map_or(0.0, |xs| xs.excitation_xs(energy))
+    }
+
+    // Electroionization cross section, summed over all subshells, at an energy (given in MeV)
+    pub fn ionization_xs(&self, energy: f64) -> f64 {
+        self.cross_sections.as_ref().map_or(0.0, |xs| xs.ionization_xs(energy))
+    }
+
+    // Total electron interaction cross section at an energy (given in MeV)
+    pub fn total_xs(&self, energy: f64) -> f64 {
+        self.cross_sections.as_ref().map_or(0.0, |xs| xs.total_xs(energy))
+    }
+
+    // Average energy lost to excitation at an energy (given in MeV)
+    pub fn excitation_energy_loss(&self, energy: f64) -> f64 {
+        self.excitation.as_ref().map_or(0.0, |excitation| excitation.evaluate(energy))
+    }
+
+    // Sample an elastic scattering event, which changes the direction but not the energy of the electron
+    pub fn sample_elastic<R: FnMut() -> UnitF64>(&self, energy: f64, rng: &mut R) -> Result<SampledEnergy, EnergyDistributionError> {
+        let elastic = self.elastic.as_ref().ok_or(EnergyDistributionError::EmptyDistribution)?;
+        let cos_theta = elastic.sample(energy, rng)?.clamp(-1.0, 1.0);
+        Ok(SampledEnergy { energy, cos_theta: Some(cos_theta) })
+    }
+
+    // Sample the energy of a bremsstrahlung photon emitted by an electron at an energy
+    pub fn sample_bremsstrahlung<R: FnMut() -> UnitF64>(&self, energy: f64, rng: &mut R) -> Result<SampledEnergy, EnergyDistributionError> {
+        let bremsstrahlung = self.bremsstrahlung.as_ref().ok_or(EnergyDistributionError::EmptyDistribution)?;
+        let energy_ratio = bremsstrahlung.sample(energy, rng)?;
+        Ok(SampledEnergy { energy: energy_ratio * energy, cos_theta: None })
+    }
+
+    // Sample the energy of the knock-on electron from an electroionization of a subshell
+    pub fn sample_knock_on<R: FnMut() -> UnitF64>(&self, subshell: usize, energy: f64, rng: &mut R) -> Result<SampledEnergy, EnergyDistributionError> {
+        let spectra = self.subshells
+            .get(subshell)
+            .and_then(|subshell| subshell.knock_on_spectra.as_ref())
+            .ok_or(EnergyDistributionError::EmptyDistribution)?;
+        Ok(SampledEnergy { energy: spectra.sample(energy, rng)?, cos_theta: None })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::utils::{convert_ACE_to_PACE, get_parsed_electron_test_file};
+    use crate::pace_error::PaceError;
+
+    #[tokio::test]
+    async fn test_parse_electron_test_file() {
+        let parsed_ace = get_parsed_electron_test_file().await;
+        assert_eq!(parsed_ace.szaid(), Some(String::from("1000.14p")));
+        assert_eq!(parsed_ace.z(), 1);
+        assert!(parsed_ace.has_electron_data());
+        assert_eq!(parsed_ace.elastic_xs(1.0E-03), 1.0E+06);
+        assert_eq!(parsed_ace.excitation_energy_loss(1.0), 2.0E-05);
+    }
+
+    #[test]
+    fn test_reject_ascii() {
+        let result = ElectronData::from_file("LICENSE");
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_reject_el03() {
+        // The photoatomic test table relabelled as an el03 table
+        get_parsed_electron_test_file().await;
+        let ascii = std::fs::read_to_string("test_nuclear_data_files/test_photoatomic_ace.no_comment").unwrap().replace("1000.14p", "1000.03e");
+        let directory = tempfile::tempdir().unwrap();
+        let ascii_path = directory.path().join("el03_ace");
+        std::fs::write(&ascii_path, ascii).unwrap();
+
+        let result = ElectronData::from_file(convert_ACE_to_PACE(&ascii_path).unwrap());
+        assert!(result.unwrap_err().to_string().contains("el03"));
+    }
+
+    #[tokio::test]
+    async fn test_electron_sampling() {
+        let parsed_ace = get_parsed_electron_test_file().await;
+
+        // At the lowest energy the first table is used, at the highest the forward peaked one
+        let mut rng = || UnitF64(0.25);
+        let sample = parsed_ace.sample_elastic(1.0E-03, &mut rng).unwrap();
+        assert_eq!(sample.energy, 1.0E-03);
+        assert_eq!(sample.cos_theta, Some(-0.5));
+        let sample = parsed_ace.sample_elastic(1.0, &mut rng).unwrap();
+        assert!((sample.cos_theta.unwrap() - 0.925).abs() < 1e-12);
+
+        let sample = parsed_ace.sample_bremsstrahlung(2.0, &mut rng).unwrap();
+        assert_eq!(sample.energy, 0.5);
+
+        let sample = parsed_ace.sample_knock_on(0, 1.0, &mut rng).unwrap();
+        assert_eq!(sample.energy, 0.125);
+        assert!(parsed_ace.sample_knock_on(1, 1.0, &mut rng).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_truncated_table() {
+        let parsed_ace = get_parsed_electron_test_file().await;
+        let mmap = PaceMmap::from_file("test_nuclear_data_files/1000.14p.pace").unwrap();
+
+        // Cut the XXS array off part way through the ESZE block
+        let (nxs, jxs) = (&parsed_ace.nxs_array, &parsed_ace.jxs_array);
+        let xxs = mmap.xxs_array().slice(0, jxs.esze + nxs.ne).unwrap();
+        let error = ElectronCrossSections::process(xxs, nxs, jxs).unwrap_err();
+        assert!(matches!(error, PaceError::InTableBlock { block: "ESZE", .. }), "{}", error);
+    }
+
+}
@@ -0,0 +1,204 @@
+use crate::unitf64::UnitF64;
+use crate::energy_distributions::EnergyDistributionError;
+use crate::electron::{ElectronNxsArray, ElectronJxsArray};
+use crate::arrays::{XxsArray, XxsWords};
+use crate::pace_error::PaceError;
+
+// A single tabulated distribution, given by NP followed by NP values and their CDF. The CDF is
+// taken to vary linearly between the tabulated values.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ElectronTabulatedDistribution {
+    pub value: Vec<f64>,
+    pub cdf: Vec<f64>,
+}
+
+impl ElectronTabulatedDistribution {
+    pub fn process(data: XxsArray<'_>, start: usize) -> Result<Self, PaceError> {
+        let num_points = data.uint(start)?;
+        let value_start = start + 1;
+        let cdf_start = value_start + num_points;
+
+        Ok(Self {
+            value: data.floats(value_start, num_points)?.to_vec(),
+            cdf: data.floats(cdf_start, num_points)?.to_vec(),
+        })
+    }
+
+    // Sample a value by inverting the CDF
+    pub fn sample(&self, xi: UnitF64) -> Result<f64, EnergyDistributionError> {
+        if self.value.is_empty() {
+            return Err(EnergyDistributionError::EmptyDistribution);
+        }
+        if self.value.len() == 1 {
+            return Ok(self.value[0]);
+        }
+
+        let bin = self.cdf
+            .partition_point(|&c| c <= xi.0)
+            .clamp(1, self.cdf.len() - 1);
+        let (lower, upper) = (bin - 1, bin);
+        if self.cdf[upper] <= self.cdf[lower] {
+            return Ok(self.value[lower]);
+        }
+
+        let fraction = (xi.0 - self.cdf[lower]) / (self.cdf[upper] - self.cdf[lower]);
+        Ok(self.value[lower] + fraction * (self.value[upper] - self.value[lower]))
+    }
+}
+
+// A set of tabulated distributions given at a number of incident energies. The layout is the
+// incident energies E(N), followed by the locators L(N) of each distribution relative to the
+// start of the block that contains them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ElectronDistributions {
+    pub energy: Vec<f64>,
+    pub distributions: Vec<ElectronTabulatedDistribution>,
+}
+
+impl ElectronDistributions {
+    // Process the distributions with incident energies starting at `start`, with locators relative to `block_start`
+    pub fn process(data: XxsArray<'_>, block_start: usize, start: usize, num_energies: usize) -> Result<Self, PaceError> {
+        let energy = data.floats(start, num_energies)?.to_vec();
+        let distributions = data.uints(start + num_energies, num_energies)?
+            .into_iter()
+            .map(|locator| ElectronTabulatedDistribution::process(data, (block_start + locator).saturating_sub(1)))
+            .collect::<Result<Vec<_>, PaceError>>()?;
+
+        Ok(Self { energy, distributions })
+    }
+
+    // Sample from the distributions at an incident energy. The distribution at one of the two
+    // neighbouring incident energies is chosen with a probability given by the interpolation
+    // fraction, and incident energies outside of the tabulated range use the nearest distribution.
+    pub fn sample<R: FnMut() -> UnitF64>(&self, incident_energy: f64, rng: &mut R) -> Result<f64, EnergyDistributionError> {
+        let num_energies = self.energy.len();
+        if num_energies == 0 {
+            return Err(EnergyDistributionError::EmptyDistribution);
+        }
+
+        let index = match self.energy.partition_point(|&e| e <= incident_energy) {
+            0 => 0,
+            i if i == num_energies => num_energies - 1,
+            i => {
+                let fraction = (incident_energy - self.energy[i - 1]) / (self.energy[i] - self.energy[i - 1]);
+                if rng().0 < fraction { i } else { i - 1 }
+            }
+        };
+
+        self.distributions[index].sample(rng())
+    }
+}
+
+//=====================================================================
+// ELAS data block
+//
+// Contains the elastic scattering angular distributions, tabulated as
+// CDFs in the scattering cosine at NXS(9) incident energies.
+//=====================================================================
+pub(crate) fn process_elastic(xxs: XxsArray<'_>, nxs: &ElectronNxsArray, jxs: &ElectronJxsArray) -> Result<Option<ElectronDistributions>, PaceError> {
+    if jxs.elas == 0 || nxs.na == 0 {
+        return Ok(None);
+    }
+
+    let elas = jxs.elas - 1;
+    ElectronDistributions::process(xxs, elas, elas, nxs.na)
+        .map(Some)
+        .map_err(|error| error.in_table_block("ELAS", elas))
+}
+
+//=====================================================================
+// BREME data block
+//
+// Contains the bremsstrahlung photon energy spectra, tabulated as CDFs
+// in the ratio of the photon energy to the incident electron energy at
+// NXS(10) incident energies.
+//=====================================================================
+pub(crate) fn process_bremsstrahlung(xxs: XxsArray<'_>, nxs: &ElectronNxsArray, jxs: &ElectronJxsArray) -> Result<Option<ElectronDistributions>, PaceError> {
+    if jxs.breme == 0 || nxs.nb == 0 {
+        return Ok(None);
+    }
+
+    let breme = jxs.breme - 1;
+    ElectronDistributions::process(xxs, breme, breme, nxs.nb)
+        .map(Some)
+        .map_err(|error| error.in_table_block("BREME", breme))
+}
+
+//=====================================================================
+// EXCIT data block
+//
+// Contains the average energy lost by an electron to atomic
+// excitation, tabulated at NXS(11) incident energies.
+//=====================================================================
+#[derive(Debug, Clone, PartialEq)]
+pub struct Excitation {
+    pub energy: Vec<f64>,
+    pub energy_loss: Vec<f64>,
+}
+
+impl Excitation {
+    // Process the excitation energy losses from the XXS array, None if they are not given
+    pub fn process(xxs: XxsArray<'_>, nxs: &ElectronNxsArray, jxs: &ElectronJxsArray) -> Result<Option<Self>, PaceError> {
+        if jxs.excit == 0 || nxs.nxl == 0 {
+            return Ok(None);
+        }
+
+        let excit = jxs.excit - 1;
+        let num_energies = nxs.nxl;
+        let data = xxs.floats(excit, num_energies.saturating_mul(2))
+            .map_err(|error| error.in_table_block("EXCIT", excit))?;
+        Ok(Some(Self {
+            energy: data[..num_energies].to_vec(),
+            energy_loss: data[num_energies..].to_vec(),
+        }))
+    }
+
+    // Average energy loss at an incident energy, interpolated linearly and clamped to the tabulated range
+    pub fn evaluate(&self, energy: f64) -> f64 {
+        let grid = &self.energy;
+        match grid.partition_point(|&e| e <= energy) {
+            0 => self.energy_loss[0],
+            i if i == grid.len() => self.energy_loss[i - 1],
+            i => {
+                let fraction = (energy - grid[i - 1]) / (grid[i] - grid[i - 1]);
+                self.energy_loss[i - 1] + fraction * (self.energy_loss[i] - self.energy_loss[i - 1])
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::utils::get_parsed_electron_test_file;
+
+    #[tokio::test]
+    async fn test_elastic_parsing() {
+        let parsed_ace = get_parsed_electron_test_file().await;
+        let elastic = parsed_ace.elastic.unwrap();
+
+        assert_eq!(elastic.energy, vec![1.0E-03, 1.0]);
+        assert_eq!(elastic.distributions[0].value, vec![-1.0, 0.0, 1.0]);
+        assert_eq!(elastic.distributions[0].cdf, vec![0.0, 0.5, 1.0]);
+        assert_eq!(elastic.distributions[1].value, vec![0.9, 1.0]);
+    }
+
+    #[tokio::test]
+    async fn test_excitation() {
+        let parsed_ace = get_parsed_electron_test_file().await;
+        let excitation = parsed_ace.excitation.unwrap();
+
+        assert_eq!(excitation.evaluate(1.0E-04), 1.5E-05);
+        assert_eq!(excitation.evaluate(1.0), 2.0E-05);
+        assert!((excitation.evaluate(0.5005) - 1.75E-05).abs() < 1e-15);
+    }
+
+    #[test]
+    fn test_tabulated_sampling() {
+        let distribution = ElectronTabulatedDistribution { value: vec![-1.0, 0.0, 1.0], cdf: vec![0.0, 0.5, 1.0] };
+        assert_eq!(distribution.sample(UnitF64(0.25)).unwrap(), -0.5);
+        assert_eq!(distribution.sample(UnitF64(0.75)).unwrap(), 0.5);
+        assert_eq!(distribution.sample(UnitF64(1.0)).unwrap(), 1.0);
+    }
+}
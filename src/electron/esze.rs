@@ -0,0 +1,113 @@
+use crate::electron::{ElectronNxsArray, ElectronJxsArray};
+use crate::arrays::{XxsArray, XxsWords};
+use crate::pace_error::PaceError;
+
+//=====================================================================
+// ESZE data block
+//
+// Contains the electron energy grid followed by the elastic,
+// bremsstrahlung and excitation cross sections and the
+// electroionization cross section of each of the NXS(7) subshells.
+// Each column has NXS(8) entries. Energies are given in MeV and cross
+// sections in barns.
+//=====================================================================
+#[derive(Debug, Clone, PartialEq)]
+pub struct ElectronCrossSections {
+    pub energy: Vec<f64>,
+    pub elastic: Vec<f64>,
+    pub bremsstrahlung: Vec<f64>,
+    pub excitation: Vec<f64>,
+    pub ionization: Vec<Vec<f64>>,
+}
+
+impl ElectronCrossSections {
+    // Process the electron cross sections from the XXS array, None if the table has no electron data
+    pub fn process(xxs: XxsArray<'_>, nxs: &ElectronNxsArray, jxs: &ElectronJxsArray) -> Result<Option<Self>, PaceError> {
+        if jxs.esze == 0 || nxs.ne == 0 {
+            return Ok(None);
+        }
+
+        let num_energies = nxs.ne;
+        let esze = jxs.esze - 1;
+        let data = xxs.floats(esze, num_energies.saturating_mul(4 + nxs.nssh))
+            .map_err(|error| error.in_table_block("ESZE", esze))?;
+        let column = |i: usize| data[i * num_energies..(i + 1) * num_energies].to_vec();
+        Ok(Some(Self {
+            energy: column(0),
+            elastic: column(1),
+            bremsstrahlung: column(2),
+            excitation: column(3),
+            ionization: (0..nxs.nssh).map(|subshell| column(4 + subshell)).collect(),
+        }))
+    }
+
+    // Interpolate a cross section linearly on the energy grid, zero outside of the tabulated range
+    fn interpolate(&self, xs: &[f64], energy: f64) -> f64 {
+        let grid = &self.energy;
+        if grid.is_empty() || energy < grid[0] || energy > grid[grid.len() - 1] {
+            return 0.0;
+        }
+        match grid.partition_point(|&e| e <= energy) {
+            i if i == grid.len() => xs[i - 1],
+            i => xs[i - 1] + (energy - grid[i - 1]) / (grid[i] - grid[i - 1]) * (xs[i] - xs[i - 1]),
+        }
+    }
+
+    // Elastic scattering cross section at an energy
+    pub fn elastic_xs(&self, energy: f64) -> f64 {
+        self.interpolate(&self.elastic, energy)
+    }
+
+    // Bremsstrahlung cross section at an energy
+    pub fn bremsstrahlung_xs(&self, energy: f64) -> f64 {
+        self.interpolate(&self.bremsstrahlung, energy)
+    }
+
+    // Excitation cross section at an energy
+    pub fn excitation_xs(&self, energy: f64) -> f64 {
+        self.interpolate(&self.excitation, energy)
+    }
+
+    // Electroionization cross section of a single subshell at an energy
+    pub fn subshell_ionization_xs(&self, subshell: usize, energy: f64) -> f64 {
+        self.ionization.get(subshell).map_or(0.0, |xs| self.interpolate(xs, energy))
+    }
+
+    // Electroionization cross section summed over all subshells at an energy
+    pub fn ionization_xs(&self, energy: f64) -> f64 {
+        self.ionization.iter().map(|xs| self.interpolate(xs, energy)).sum()
+    }
+
+    // Total electron interaction cross section at an energy
+    pub fn total_xs(&self, energy: f64) -> f64 {
+        self.elastic_xs(energy) + self.bremsstrahlung_xs(energy) + self.excitation_xs(energy) + self.ionization_xs(energy)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::get_parsed_electron_test_file;
+
+    #[tokio::test]
+    async fn test_esze_parsing() {
+        let parsed_ace = get_parsed_electron_test_file().await;
+        let cross_sections = parsed_ace.cross_sections.unwrap();
+
+        assert_eq!(cross_sections.energy, vec![1.0E-03, 1.0]);
+        assert_eq!(cross_sections.bremsstrahlung, vec![10.0, 20.0]);
+        assert_eq!(cross_sections.ionization, vec![vec![5.0E+04, 5.0E+03]]);
+    }
+
+    #[tokio::test]
+    async fn test_esze_evaluation() {
+        let parsed_ace = get_parsed_electron_test_file().await;
+        let cross_sections = parsed_ace.cross_sections.unwrap();
+
+        assert_eq!(cross_sections.bremsstrahlung_xs(1.0), 20.0);
+        assert!((cross_sections.bremsstrahlung_xs(0.5005) - 15.0).abs() < 1e-9);
+        assert_eq!(cross_sections.subshell_ionization_xs(0, 1.0E-03), 5.0E+04);
+        assert_eq!(cross_sections.subshell_ionization_xs(1, 1.0E-03), 0.0);
+        assert_eq!(cross_sections.total_xs(1.0), 1.0E+05 + 20.0 + 1.0E+04 + 5.0E+03);
+        assert_eq!(cross_sections.total_xs(2.0), 0.0);
+    }
+}
@@ -0,0 +1,13 @@
+mod electron_arrays;
+mod esze;
+mod electron_distributions;
+mod subshells;
+mod electron_data;
+
+// Structs
+pub use electron_arrays::{ElectronNxsArray, ElectronJxsArray};
+pub use esze::ElectronCrossSections;
+pub use electron_distributions::{ElectronTabulatedDistribution, ElectronDistributions, Excitation};
+pub(crate) use electron_distributions::{process_elastic, process_bremsstrahlung};
+pub use subshells::Subshell;
+pub use electron_data::ElectronData;
@@ -0,0 +1,78 @@
+use crate::electron::{ElectronNxsArray, ElectronJxsArray, ElectronDistributions};
+use crate::arrays::{XxsArray, XxsWords};
+use crate::pace_error::PaceError;
+
+//=====================================================================
+// SUBSH and EION data blocks
+//
+// SUBSH gives the designator, the number of electrons and the binding
+// energy of each of the NXS(7) electroionization subshells, each as a
+// separate array of NXS(7) entries. EION starts with a locator for
+// each subshell, followed by the knock-on electron energy spectra of
+// that subshell at its own set of incident energies. All locators in
+// EION are relative to the start of the EION block.
+//=====================================================================
+#[derive(Debug, Clone, PartialEq)]
+pub struct Subshell {
+    pub designator: usize,
+    pub electrons: f64,
+    pub binding_energy: f64,
+    pub knock_on_spectra: Option<ElectronDistributions>,
+}
+
+impl Subshell {
+    // Process all of the electroionization subshells from the XXS array
+    pub fn process(xxs: XxsArray<'_>, nxs: &ElectronNxsArray, jxs: &ElectronJxsArray) -> Result<Vec<Self>, PaceError> {
+        if jxs.subsh == 0 || nxs.nssh == 0 {
+            return Ok(Vec::new());
+        }
+
+        let num_subshells = nxs.nssh;
+        let subsh = jxs.subsh - 1;
+        (0..num_subshells)
+            .map(|i| {
+                let knock_on_spectra = match jxs.eion {
+                    0 => None,
+                    eion => {
+                        let block_start = eion - 1;
+                        let spectra = xxs.uint(block_start + i).and_then(|locator| {
+                            let start = (block_start + locator).saturating_sub(1);
+                            let num_energies = xxs.uint(start)?;
+                            ElectronDistributions::process(xxs, block_start, start + 1, num_energies)
+                        });
+                        Some(spectra.map_err(|error| error.in_table_block("EION", block_start))?)
+                    }
+                };
+
+                let shell = || Ok::<_, PaceError>(Self {
+                    designator: xxs.uint(subsh + i)?,
+                    electrons: xxs.float(subsh + num_subshells + i)?,
+                    binding_energy: xxs.float(subsh + 2 * num_subshells + i)?,
+                    knock_on_spectra,
+                });
+                shell().map_err(|error| error.in_table_block("SUBSH", subsh))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::get_parsed_electron_test_file;
+
+    #[tokio::test]
+    async fn test_subshell_parsing() {
+        let parsed_ace = get_parsed_electron_test_file().await;
+        assert_eq!(parsed_ace.subshells.len(), 1);
+
+        let subshell = &parsed_ace.subshells[0];
+        assert_eq!(subshell.designator, 1);
+        assert_eq!(subshell.electrons, 1.0);
+        assert_eq!(subshell.binding_energy, 1.36E-05);
+
+        let spectra = subshell.knock_on_spectra.as_ref().unwrap();
+        assert_eq!(spectra.energy, vec![1.0]);
+        assert_eq!(spectra.distributions[0].value, vec![0.0, 0.5]);
+        assert_eq!(spectra.distributions[0].cdf, vec![0.0, 1.0]);
+    }
+}
@@ -0,0 +1,1439 @@
+use std::f64::consts::PI;
+
+use thiserror::Error;
+
+use crate::unitf64::UnitF64;
+use crate::interpolation::{InterpolationScheme, InterpolationTable, InterpolationError};
+use crate::angular_distributions::{
+    AngularDistribution,
+    IsotropicAngularDistribution,
+    TabulatedAngularDistribution,
+    SampleAngle,
+};
+use crate::arrays::{XxsArray, XxsWords};
+use crate::pace_error::{PaceError, locator_to_index};
+
+//=====================================================================
+// Trait to sample the outgoing energy (and, for correlated laws, the
+// cosine of the scattering angle) from an energy distribution at a
+// given incident energy.
+// Many laws need more than one random number, so the caller provides
+// a source of random numbers rather than a single value. As with all
+// sampling methods in the PACE library, the user is responsible for
+// providing random numbers in the range [0.0, 1.0]. This is checked
+// in debug builds, but not in release builds.
+//=====================================================================
+pub trait SampleEnergy {
+    fn sample_energy<R: FnMut() -> UnitF64>(&self, incident_energy: f64, rng: &mut R) -> Result<SampledEnergy, EnergyDistributionError>;
+}
+
+// Result of sampling an energy distribution. The cosine of the scattering angle is only
+// provided by laws which correlate the outgoing energy and angle (laws 44, 61, 66 and 67).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SampledEnergy {
+    pub energy: f64,
+    pub cos_theta: Option<f64>,
+}
+
+impl SampledEnergy {
+    fn uncorrelated(energy: f64) -> Self {
+        Self { energy, cos_theta: None }
+    }
+
+    fn correlated(energy: f64, cos_theta: f64) -> Self {
+        Self { energy, cos_theta: Some(cos_theta) }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum EnergyDistributionError {
+    #[error("Interpolation error: {0}")]
+    InterpolationError(#[from] InterpolationError),
+
+    #[error("Incident energy {0} does not exceed the restriction energy {1}, no outgoing energy is possible")]
+    BelowRestrictionEnergy(f64, f64),
+
+    #[error("Energy distribution contains no data")]
+    EmptyDistribution,
+
+    #[error("BDD gives {bdd_groups} precursor groups, but DNED gives spectra for {dned_groups}")]
+    PrecursorGroupMismatch { bdd_groups: usize, dned_groups: usize },
+}
+
+// Kinematic data for the target and reaction. These are needed by the laws which compute the
+// outgoing energy from the reaction kinematics rather than from tabulated data (laws 2 and 66).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ReactionKinematics {
+    pub awr: f64,       // Atomic weight ratio of the target
+    pub q_value: f64,   // Q value of the reaction
+}
+
+
+//=====================================================================
+// Secondary energy distributions are given in the ACE format as one
+// of a number of "laws". Each law has its own data layout (LDAT), see
+// the ACE format spec for a description of each.
+//
+// All locators found inside of the law data are relative to the start
+// of the block which contains the law (e.g. JXS(11) for DLW), so every
+// law is processed from the full block data and the (zero-indexed)
+// start of its LDAT array.
+//=====================================================================
+#[derive(Debug, Clone, PartialEq)]
+pub enum EnergyDistribution {
+    EquiprobableBins(EquiprobableBinsEnergyDistribution),                   // Law 1
+    DiscretePhoton(DiscretePhotonEnergyDistribution),                       // Law 2
+    LevelScattering(LevelScatteringEnergyDistribution),                     // Law 3
+    ContinuousTabular(ContinuousTabularEnergyDistribution),                 // Law 4
+    GeneralEvaporation(GeneralEvaporationEnergyDistribution),               // Law 5
+    MaxwellFission(MaxwellFissionEnergyDistribution),                       // Law 7
+    Evaporation(EvaporationEnergyDistribution),                             // Law 9
+    Watt(WattEnergyDistribution),                                           // Law 11
+    TabularLinearFunctions(TabularLinearFunctionsEnergyDistribution),       // Law 22
+    TabularEnergyMultipliers(TabularEnergyMultipliersEnergyDistribution),   // Law 24
+    Kalbach(KalbachEnergyDistribution),                                     // Law 44
+    CorrelatedEnergyAngle(CorrelatedEnergyAngleDistribution),               // Law 61
+    NBodyPhaseSpace(NBodyPhaseSpaceDistribution),                           // Law 66
+    LabAngleEnergy(LabAngleEnergyDistribution),                             // Law 67
+}
+
+impl EnergyDistribution {
+    // Process the law data for a given law number, starting at `ldat_start` in the block data
+    pub fn process(law: usize, data: XxsArray<'_>, ldat_start: usize, kinematics: ReactionKinematics) -> Result<Self, PaceError> {
+        let distribution = match law {
+            1 => EnergyDistribution::EquiprobableBins(EquiprobableBinsEnergyDistribution::process(data, ldat_start)?),
+            2 => EnergyDistribution::DiscretePhoton(DiscretePhotonEnergyDistribution::process(data, ldat_start, kinematics)?),
+            3 => EnergyDistribution::LevelScattering(LevelScatteringEnergyDistribution::process(data, ldat_start)?),
+            4 => EnergyDistribution::ContinuousTabular(ContinuousTabularEnergyDistribution::process(data, ldat_start)?),
+            5 => EnergyDistribution::GeneralEvaporation(GeneralEvaporationEnergyDistribution::process(data, ldat_start)?),
+            7 => EnergyDistribution::MaxwellFission(MaxwellFissionEnergyDistribution::process(data, ldat_start)?),
+            9 => EnergyDistribution::Evaporation(EvaporationEnergyDistribution::process(data, ldat_start)?),
+            11 => EnergyDistribution::Watt(WattEnergyDistribution::process(data, ldat_start)?),
+            22 => EnergyDistribution::TabularLinearFunctions(TabularLinearFunctionsEnergyDistribution::process(data, ldat_start)?),
+            24 => EnergyDistribution::TabularEnergyMultipliers(TabularEnergyMultipliersEnergyDistribution::process(data, ldat_start)?),
+            44 => EnergyDistribution::Kalbach(KalbachEnergyDistribution::process(data, ldat_start)?),
+            61 => EnergyDistribution::CorrelatedEnergyAngle(CorrelatedEnergyAngleDistribution::process(data, ldat_start)?),
+            66 => EnergyDistribution::NBodyPhaseSpace(NBodyPhaseSpaceDistribution::process(data, ldat_start, kinematics)?),
+            67 => EnergyDistribution::LabAngleEnergy(LabAngleEnergyDistribution::process(data, ldat_start)?),
+            // Expected one of 1-5, 7, 9, 11, 22, 24, 44, 61, 66, 67
+            _ => return Err(PaceError::unknown_code("energy distribution law", law)),
+        };
+        Ok(distribution)
+    }
+
+    // The ACE law number of the distribution
+    pub fn law(&self) -> usize {
+        match self {
+            EnergyDistribution::EquiprobableBins(_) => 1,
+            EnergyDistribution::DiscretePhoton(_) => 2,
+            EnergyDistribution::LevelScattering(_) => 3,
+            EnergyDistribution::ContinuousTabular(_) => 4,
+            EnergyDistribution::GeneralEvaporation(_) => 5,
+            EnergyDistribution::MaxwellFission(_) => 7,
+            EnergyDistribution::Evaporation(_) => 9,
+            EnergyDistribution::Watt(_) => 11,
+            EnergyDistribution::TabularLinearFunctions(_) => 22,
+            EnergyDistribution::TabularEnergyMultipliers(_) => 24,
+            EnergyDistribution::Kalbach(_) => 44,
+            EnergyDistribution::CorrelatedEnergyAngle(_) => 61,
+            EnergyDistribution::NBodyPhaseSpace(_) => 66,
+            EnergyDistribution::LabAngleEnergy(_) => 67,
+        }
+    }
+}
+
+impl SampleEnergy for EnergyDistribution {
+    fn sample_energy<R: FnMut() -> UnitF64>(&self, incident_energy: f64, rng: &mut R) -> Result<SampledEnergy, EnergyDistributionError> {
+        match self {
+            EnergyDistribution::EquiprobableBins(distribution) => distribution.sample_energy(incident_energy, rng),
+            EnergyDistribution::DiscretePhoton(distribution) => distribution.sample_energy(incident_energy, rng),
+            EnergyDistribution::LevelScattering(distribution) => distribution.sample_energy(incident_energy, rng),
+            EnergyDistribution::ContinuousTabular(distribution) => distribution.sample_energy(incident_energy, rng),
+            EnergyDistribution::GeneralEvaporation(distribution) => distribution.sample_energy(incident_energy, rng),
+            EnergyDistribution::MaxwellFission(distribution) => distribution.sample_energy(incident_energy, rng),
+            EnergyDistribution::Evaporation(distribution) => distribution.sample_energy(incident_energy, rng),
+            EnergyDistribution::Watt(distribution) => distribution.sample_energy(incident_energy, rng),
+            EnergyDistribution::TabularLinearFunctions(distribution) => distribution.sample_energy(incident_energy, rng),
+            EnergyDistribution::TabularEnergyMultipliers(distribution) => distribution.sample_energy(incident_energy, rng),
+            EnergyDistribution::Kalbach(distribution) => distribution.sample_energy(incident_energy, rng),
+            EnergyDistribution::CorrelatedEnergyAngle(distribution) => distribution.sample_energy(incident_energy, rng),
+            EnergyDistribution::NBodyPhaseSpace(distribution) => distribution.sample_energy(incident_energy, rng),
+            EnergyDistribution::LabAngleEnergy(distribution) => distribution.sample_energy(incident_energy, rng),
+        }
+    }
+}
+
+
+//=====================================================================
+// Helper structs shared between several laws.
+//=====================================================================
+// Incident energy grid along with its interpolation parameters (NR, NBT(NR), INT(NR), NE, E(NE))
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct IncidentEnergyGrid {
+    pub breakpoints: Vec<usize>,
+    pub schemes: Vec<InterpolationScheme>,
+    pub energy: Vec<f64>,
+}
+
+impl IncidentEnergyGrid {
+    // Process the grid starting at `start`, returns the grid and the index of the first word past it
+    pub fn process(data: XxsArray<'_>, start: usize) -> Result<(Self, usize), PaceError> {
+        let num_regions = data.uint(start)?;
+        let breakpoints_start = start + 1;
+        let schemes_start = breakpoints_start + num_regions;
+        let num_energies_index = schemes_start + num_regions;
+
+        let breakpoints = data.uints(breakpoints_start, num_regions)?;
+        let schemes = data.uints(schemes_start, num_regions)?
+            .into_iter()
+            .map(InterpolationScheme::try_from)
+            .collect::<Result<_, _>>()?;
+
+        let num_energies = data.uint(num_energies_index)?;
+        let energy_start = num_energies_index + 1;
+        let energy = data.floats(energy_start, num_energies)?.to_vec();
+
+        Ok((Self { breakpoints, schemes, energy }, energy_start + num_energies))
+    }
+
+    pub fn len(&self) -> usize {
+        self.energy.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.energy.is_empty()
+    }
+
+    // Find the bin which contains an incident energy, along with the interpolation fraction
+    // within that bin. Energies outside of the grid are clamped to its ends.
+    pub fn interpolation_factor(&self, energy: f64) -> (usize, f64) {
+        let num_energies = self.energy.len();
+        if num_energies < 2 || energy <= self.energy[0] {
+            return (0, 0.0);
+        }
+        if energy >= self.energy[num_energies - 1] {
+            return (num_energies - 2, 1.0);
+        }
+
+        let bin = self.energy.partition_point(|&e| e <= energy) - 1;
+        let (e0, e1) = (self.energy[bin], self.energy[bin + 1]);
+        let factor = match self.scheme_for_bin(bin) {
+            InterpolationScheme::Histogram => 0.0,
+            InterpolationScheme::LinLog | InterpolationScheme::LogLog => (energy / e0).ln() / (e1 / e0).ln(),
+            _ => (energy - e0) / (e1 - e0),
+        };
+        (bin, factor)
+    }
+
+    // Interpolation scheme for a bin. The breakpoints are the (one-indexed) last points of each region,
+    // and if no interpolation regions are given we use linear-linear interpolation.
+    fn scheme_for_bin(&self, bin: usize) -> InterpolationScheme {
+        self.breakpoints
+            .iter()
+            .zip(self.schemes.iter())
+            .find(|&(&breakpoint, _)| bin + 1 < breakpoint)
+            .map(|(_, &scheme)| scheme)
+            .unwrap_or(InterpolationScheme::LinLin)
+    }
+
+    // Stochastically pick the table to sample from for an incident energy, either the lower
+    // or upper table of its bin. Returns (lower bin, interpolation fraction, chosen table).
+    fn sample_table<R: FnMut() -> UnitF64>(&self, energy: f64, rng: &mut R) -> (usize, f64, usize) {
+        let (bin, factor) = self.interpolation_factor(energy);
+        let table = if factor > 0.0 && rng().0 < factor { bin + 1 } else { bin };
+        (bin, factor, table)
+    }
+}
+
+// Tabulated outgoing energy distribution (INTT, NP, E_out(NP), PDF(NP), CDF(NP)).
+// INTT encodes both the number of discrete lines (ND) and the interpolation scheme as ND * 10 + INTT.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TabulatedEnergyDistribution {
+    pub interpolation_scheme: InterpolationScheme,
+    pub num_discrete_lines: usize,
+    pub energy_out: Vec<f64>,
+    pub pdf: Vec<f64>,
+    pub cdf: Vec<f64>,
+}
+
+impl TabulatedEnergyDistribution {
+    // Process the distribution starting at `start`, returns the distribution and the index of
+    // the first word past it
+    pub fn process(data: XxsArray<'_>, start: usize) -> Result<(Self, usize), PaceError> {
+        let intt = data.uint(start)?;
+        let num_points = data.uint(start + 1)?;
+        let energy_out_start = start + 2;
+        let pdf_start = energy_out_start + num_points;
+        let cdf_start = pdf_start + num_points;
+
+        let distribution = Self {
+            interpolation_scheme: InterpolationScheme::try_from(intt % 10)?,
+            num_discrete_lines: intt / 10,
+            energy_out: data.floats(energy_out_start, num_points)?.to_vec(),
+            pdf: data.floats(pdf_start, num_points)?.to_vec(),
+            cdf: data.floats(cdf_start, num_points)?.to_vec(),
+        };
+        Ok((distribution, cdf_start + num_points))
+    }
+
+    // Sample an outgoing energy from the distribution using its CDF. Returns the outgoing energy
+    // and the index of the outgoing energy bin that it was sampled from. The CDF does not need to
+    // be normalized (law 67 tables are not), the random number is scaled to its final value.
+    pub fn sample(&self, unitf64: UnitF64) -> Result<(f64, usize), EnergyDistributionError> {
+        let num_points = self.energy_out.len();
+        if num_points == 0 {
+            return Err(EnergyDistributionError::EmptyDistribution);
+        }
+        let xi = unitf64.0 * self.cdf[num_points - 1];
+
+        // Discrete lines come first, and are sampled directly
+        for k in 0..self.num_discrete_lines.min(num_points) {
+            if xi < self.cdf[k] {
+                return Ok((self.energy_out[k], k));
+            }
+        }
+        if num_points == 1 {
+            return Ok((self.energy_out[0], 0));
+        }
+
+        // Find the continuous bin which contains the random number
+        let first_continuous = self.num_discrete_lines.min(num_points - 2);
+        let k = (first_continuous + self.cdf[first_continuous..].partition_point(|&c| c <= xi))
+            .saturating_sub(1)
+            .clamp(first_continuous, num_points - 2);
+        let (e_k, p_k, c_k) = (self.energy_out[k], self.pdf[k], self.cdf[k]);
+
+        let energy = match self.interpolation_scheme {
+            InterpolationScheme::LinLin => {
+                let slope = (self.pdf[k + 1] - p_k) / (self.energy_out[k + 1] - e_k);
+                // Bins of zero width or with a zero PDF carry no probability, and are only reached
+                // at the end of the CDF, so their lower energy is returned
+                if !slope.is_finite() || (slope == 0.0 && p_k <= 0.0) {
+                    e_k
+                } else if slope == 0.0 {
+                    e_k + (xi - c_k) / p_k
+                } else {
+                    e_k + ((p_k * p_k + 2.0 * slope * (xi - c_k)).max(0.0).sqrt() - p_k) / slope
+                }
+            },
+            // All other schemes are treated as histograms
+            _ => {
+                if p_k > 0.0 {
+                    e_k + (xi - c_k) / p_k
+                } else {
+                    e_k
+                }
+            },
+        };
+        Ok((energy, k))
+    }
+
+    // First and last continuous outgoing energies, used for unit-base interpolation
+    fn continuous_bounds(&self) -> (f64, f64) {
+        let first = self.num_discrete_lines.min(self.energy_out.len() - 1);
+        (self.energy_out[first], self.energy_out[self.energy_out.len() - 1])
+    }
+}
+
+// Sample an outgoing energy from one of two neighbouring tabulated distributions and scale the
+// result with unit-base interpolation between the two tables. This is shared between laws 4, 44
+// and 61. Returns the scaled energy, the unscaled energy, the chosen table and the outgoing bin.
+fn sample_unit_base_interpolated<R: FnMut() -> UnitF64>(
+    tables: &[&TabulatedEnergyDistribution],
+    incident_energy: &IncidentEnergyGrid,
+    energy: f64,
+    rng: &mut R,
+) -> Result<(f64, f64, usize, usize, UnitF64), EnergyDistributionError> {
+    if tables.is_empty() {
+        return Err(EnergyDistributionError::EmptyDistribution);
+    }
+    let (bin, factor, table) = incident_energy.sample_table(energy, rng);
+    let xi = rng();
+    let (energy_out, k) = tables[table].sample(xi)?;
+
+    // Discrete lines and single tables are not scaled
+    if k < tables[table].num_discrete_lines || tables.len() < 2 {
+        return Ok((energy_out, energy_out, table, k, xi));
+    }
+
+    // Interpolate the outgoing energy bounds between the tables, then scale the sampled energy
+    let (lower_first, lower_last) = tables[bin].continuous_bounds();
+    let (upper_first, upper_last) = tables[bin + 1].continuous_bounds();
+    let first = lower_first + factor * (upper_first - lower_first);
+    let last = lower_last + factor * (upper_last - lower_last);
+    let (table_first, table_last) = tables[table].continuous_bounds();
+    let scaled = if table_last > table_first {
+        first + (energy_out - table_first) * (last - first) / (table_last - table_first)
+    } else {
+        energy_out
+    };
+    Ok((scaled, energy_out, table, k, xi))
+}
+
+// Pull a list of locators (relative to the start of the block) and convert them to zero-indexed
+// positions in the block data
+fn locators_to_indices(locators: XxsArray<'_>) -> Result<Vec<usize>, PaceError> {
+    locators.uints(0, locators.len())?.into_iter().map(locator_to_index).collect()
+}
+
+// Sample from a Maxwellian spectrum with temperature T
+fn sample_maxwell<R: FnMut() -> UnitF64>(temperature: f64, rng: &mut R) -> f64 {
+    let (xi1, xi2, xi3) = (rng().0, rng().0, rng().0);
+    let c = (PI / 2.0 * xi3).cos();
+    -temperature * (xi1.ln() + xi2.ln() * c * c)
+}
+
+// Evaluate a table at an energy, holding it constant outside of the tabulated range
+pub(crate) fn interpolate_clamped(table: &InterpolationTable, energy: f64) -> Result<f64, InterpolationError> {
+    let first = table.first().and_then(|region| region.data.first()).ok_or(InterpolationError::InvalidTable())?;
+    let last = table.last().and_then(|region| region.data.last()).ok_or(InterpolationError::InvalidTable())?;
+    table.interpolate(energy.clamp(first.x, last.x))
+}
+
+
+//=====================================================================
+// Law 1 - Tabular equiprobable energy bins
+//=====================================================================
+#[derive(Debug, Clone, PartialEq)]
+pub struct EquiprobableBinsEnergyDistribution {
+    pub incident_energy: IncidentEnergyGrid,
+    pub energy_out: Vec<Vec<f64>>,
+}
+
+impl EquiprobableBinsEnergyDistribution {
+    pub fn process(data: XxsArray<'_>, ldat_start: usize) -> Result<Self, PaceError> {
+        let (incident_energy, offset) = IncidentEnergyGrid::process(data, ldat_start)?;
+        // Number of outgoing energies for each incident energy
+        let num_energy_out = data.uint(offset)?;
+        let energy_out = (0..incident_energy.len())
+            .map(|i| data.floats(offset + 1 + i * num_energy_out, num_energy_out).map(<[f64]>::to_vec))
+            .collect::<Result<_, _>>()?;
+        Ok(Self { incident_energy, energy_out })
+    }
+}
+
+impl SampleEnergy for EquiprobableBinsEnergyDistribution {
+    fn sample_energy<R: FnMut() -> UnitF64>(&self, incident_energy: f64, rng: &mut R) -> Result<SampledEnergy, EnergyDistributionError> {
+        let (bin, factor) = self.incident_energy.interpolation_factor(incident_energy);
+        let lower = self.energy_out.get(bin).ok_or(EnergyDistributionError::EmptyDistribution)?;
+        let upper = self.energy_out.get(bin + 1).unwrap_or(lower);
+        if lower.len() < 2 {
+            return Err(EnergyDistributionError::EmptyDistribution);
+        }
+
+        // Pick an equiprobable bin, then interpolate its bounds between the incident energies
+        let num_bins = lower.len() - 1;
+        let k = ((rng().0 * num_bins as f64) as usize).min(num_bins - 1);
+        let e_k = lower[k] + factor * (upper[k] - lower[k]);
+        let e_k1 = lower[k + 1] + factor * (upper[k + 1] - lower[k + 1]);
+        Ok(SampledEnergy::uncorrelated(e_k + rng().0 * (e_k1 - e_k)))
+    }
+}
+
+
+//=====================================================================
+// Law 2 - Discrete photon energy
+//=====================================================================
+#[derive(Debug, Clone, PartialEq)]
+pub struct DiscretePhotonEnergyDistribution {
+    pub primary_flag: usize,    // LP, 0/1 = non-primary photon, 2 = primary photon
+    pub photon_energy: f64,     // EG
+    pub awr: f64,               // Atomic weight ratio of the target, needed for primary photons
+}
+
+impl DiscretePhotonEnergyDistribution {
+    pub fn process(data: XxsArray<'_>, ldat_start: usize, kinematics: ReactionKinematics) -> Result<Self, PaceError> {
+        Ok(Self {
+            primary_flag: data.uint(ldat_start)?,
+            photon_energy: data.float(ldat_start + 1)?,
+            awr: kinematics.awr,
+        })
+    }
+}
+
+impl SampleEnergy for DiscretePhotonEnergyDistribution {
+    fn sample_energy<R: FnMut() -> UnitF64>(&self, incident_energy: f64, _rng: &mut R) -> Result<SampledEnergy, EnergyDistributionError> {
+        // Primary photons carry part of the incident energy
+        let energy = match self.primary_flag {
+            2 => self.photon_energy + self.awr / (self.awr + 1.0) * incident_energy,
+            _ => self.photon_energy,
+        };
+        Ok(SampledEnergy::uncorrelated(energy))
+    }
+}
+
+
+//=====================================================================
+// Law 3 - Level scattering
+//=====================================================================
+#[derive(Debug, Clone, PartialEq)]
+pub struct LevelScatteringEnergyDistribution {
+    pub threshold: f64,     // (A + 1) / A * |Q|
+    pub mass_ratio: f64,    // (A / (A + 1))^2
+}
+
+impl LevelScatteringEnergyDistribution {
+    pub fn process(data: XxsArray<'_>, ldat_start: usize) -> Result<Self, PaceError> {
+        Ok(Self {
+            threshold: data.float(ldat_start)?,
+            mass_ratio: data.float(ldat_start + 1)?,
+        })
+    }
+}
+
+impl SampleEnergy for LevelScatteringEnergyDistribution {
+    fn sample_energy<R: FnMut() -> UnitF64>(&self, incident_energy: f64, _rng: &mut R) -> Result<SampledEnergy, EnergyDistributionError> {
+        // This is the outgoing energy in the center of mass system
+        Ok(SampledEnergy::uncorrelated(self.mass_ratio * (incident_energy - self.threshold)))
+    }
+}
+
+
+//=====================================================================
+// Law 4 - Continuous tabular distribution
+//=====================================================================
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContinuousTabularEnergyDistribution {
+    pub incident_energy: IncidentEnergyGrid,
+    pub distributions: Vec<TabulatedEnergyDistribution>,
+}
+
+impl ContinuousTabularEnergyDistribution {
+    pub fn process(data: XxsArray<'_>, ldat_start: usize) -> Result<Self, PaceError> {
+        let (incident_energy, offset) = IncidentEnergyGrid::process(data, ldat_start)?;
+        let locators = locators_to_indices(data.slice(offset, incident_energy.len())?)?;
+        let distributions = locators
+            .into_iter()
+            .map(|start| Ok(TabulatedEnergyDistribution::process(data, start)?.0))
+            .collect::<Result<_, PaceError>>()?;
+        Ok(Self { incident_energy, distributions })
+    }
+}
+
+impl SampleEnergy for ContinuousTabularEnergyDistribution {
+    fn sample_energy<R: FnMut() -> UnitF64>(&self, incident_energy: f64, rng: &mut R) -> Result<SampledEnergy, EnergyDistributionError> {
+        let tables: Vec<&TabulatedEnergyDistribution> = self.distributions.iter().collect();
+        let (energy, _, _, _, _) = sample_unit_base_interpolated(&tables, &self.incident_energy, incident_energy, rng)?;
+        Ok(SampledEnergy::uncorrelated(energy))
+    }
+}
+
+
+//=====================================================================
+// Law 5 - General evaporation spectrum
+//=====================================================================
+#[derive(Debug, Clone, PartialEq)]
+pub struct GeneralEvaporationEnergyDistribution {
+    pub temperature: InterpolationTable,    // Effective temperature as a function of incident energy
+    pub x: Vec<f64>,                        // Equiprobable bins of X(E_out / T)
+}
+
+impl GeneralEvaporationEnergyDistribution {
+    pub fn process(data: XxsArray<'_>, ldat_start: usize) -> Result<Self, PaceError> {
+        let temperature = InterpolationTable::process(data.tail(ldat_start)?)?;
+        let offset = ldat_start + InterpolationTable::get_table_length(ldat_start, data)?;
+        let num_x = data.uint(offset)?;
+        let x = data.floats(offset + 1, num_x)?.to_vec();
+        Ok(Self { temperature, x })
+    }
+}
+
+impl SampleEnergy for GeneralEvaporationEnergyDistribution {
+    fn sample_energy<R: FnMut() -> UnitF64>(&self, incident_energy: f64, rng: &mut R) -> Result<SampledEnergy, EnergyDistributionError> {
+        if self.x.len() < 2 {
+            return Err(EnergyDistributionError::EmptyDistribution);
+        }
+        let temperature = interpolate_clamped(&self.temperature, incident_energy)?;
+        // Pick an equiprobable bin of X and sample uniformly within it
+        let num_bins = self.x.len() - 1;
+        let k = ((rng().0 * num_bins as f64) as usize).min(num_bins - 1);
+        let x = self.x[k] + rng().0 * (self.x[k + 1] - self.x[k]);
+        Ok(SampledEnergy::uncorrelated(x * temperature))
+    }
+}
+
+
+//=====================================================================
+// Law 7 - Simple Maxwell fission spectrum
+//=====================================================================
+#[derive(Debug, Clone, PartialEq)]
+pub struct MaxwellFissionEnergyDistribution {
+    pub temperature: InterpolationTable,    // Nuclear temperature as a function of incident energy
+    pub restriction_energy: f64,            // U
+}
+
+impl MaxwellFissionEnergyDistribution {
+    pub fn process(data: XxsArray<'_>, ldat_start: usize) -> Result<Self, PaceError> {
+        let temperature = InterpolationTable::process(data.tail(ldat_start)?)?;
+        let offset = ldat_start + InterpolationTable::get_table_length(ldat_start, data)?;
+        Ok(Self { temperature, restriction_energy: data.float(offset)? })
+    }
+}
+
+impl SampleEnergy for MaxwellFissionEnergyDistribution {
+    fn sample_energy<R: FnMut() -> UnitF64>(&self, incident_energy: f64, rng: &mut R) -> Result<SampledEnergy, EnergyDistributionError> {
+        let max_energy = incident_energy - self.restriction_energy;
+        if max_energy <= 0.0 {
+            return Err(EnergyDistributionError::BelowRestrictionEnergy(incident_energy, self.restriction_energy));
+        }
+        let temperature = interpolate_clamped(&self.temperature, incident_energy)?;
+        // Rejection sample until the outgoing energy is below E - U
+        loop {
+            let energy = sample_maxwell(temperature, rng);
+            if energy <= max_energy {
+                return Ok(SampledEnergy::uncorrelated(energy));
+            }
+        }
+    }
+}
+
+
+//=====================================================================
+// Law 9 - Evaporation spectrum
+//=====================================================================
+#[derive(Debug, Clone, PartialEq)]
+pub struct EvaporationEnergyDistribution {
+    pub temperature: InterpolationTable,    // Nuclear temperature as a function of incident energy
+    pub restriction_energy: f64,            // U
+}
+
+impl EvaporationEnergyDistribution {
+    pub fn process(data: XxsArray<'_>, ldat_start: usize) -> Result<Self, PaceError> {
+        let temperature = InterpolationTable::process(data.tail(ldat_start)?)?;
+        let offset = ldat_start + InterpolationTable::get_table_length(ldat_start, data)?;
+        Ok(Self { temperature, restriction_energy: data.float(offset)? })
+    }
+}
+
+impl SampleEnergy for EvaporationEnergyDistribution {
+    fn sample_energy<R: FnMut() -> UnitF64>(&self, incident_energy: f64, rng: &mut R) -> Result<SampledEnergy, EnergyDistributionError> {
+        let max_energy = incident_energy - self.restriction_energy;
+        if max_energy <= 0.0 {
+            return Err(EnergyDistributionError::BelowRestrictionEnergy(incident_energy, self.restriction_energy));
+        }
+        let temperature = interpolate_clamped(&self.temperature, incident_energy)?;
+        // Sample from the spectrum truncated at E - U, rejecting the rare samples past the cutoff
+        let g = 1.0 - (-max_energy / temperature).exp();
+        loop {
+            let energy = -temperature * ((1.0 - g * rng().0) * (1.0 - g * rng().0)).ln();
+            if energy <= max_energy {
+                return Ok(SampledEnergy::uncorrelated(energy));
+            }
+        }
+    }
+}
+
+
+//=====================================================================
+// Law 11 - Energy-dependent Watt spectrum
+//=====================================================================
+#[derive(Debug, Clone, PartialEq)]
+pub struct WattEnergyDistribution {
+    pub a: InterpolationTable,
+    pub b: InterpolationTable,
+    pub restriction_energy: f64,    // U
+}
+
+impl WattEnergyDistribution {
+    pub fn process(data: XxsArray<'_>, ldat_start: usize) -> Result<Self, PaceError> {
+        let a = InterpolationTable::process(data.tail(ldat_start)?)?;
+        let b_start = ldat_start + InterpolationTable::get_table_length(ldat_start, data)?;
+        let b = InterpolationTable::process(data.tail(b_start)?)?;
+        let offset = b_start + InterpolationTable::get_table_length(b_start, data)?;
+        Ok(Self { a, b, restriction_energy: data.float(offset)? })
+    }
+}
+
+impl SampleEnergy for WattEnergyDistribution {
+    fn sample_energy<R: FnMut() -> UnitF64>(&self, incident_energy: f64, rng: &mut R) -> Result<SampledEnergy, EnergyDistributionError> {
+        let max_energy = incident_energy - self.restriction_energy;
+        if max_energy <= 0.0 {
+            return Err(EnergyDistributionError::BelowRestrictionEnergy(incident_energy, self.restriction_energy));
+        }
+        let a = interpolate_clamped(&self.a, incident_energy)?;
+        let b = interpolate_clamped(&self.b, incident_energy)?;
+        // Sample a Maxwellian with temperature a, then shift it (see the ENDF manual)
+        loop {
+            let w = sample_maxwell(a, rng);
+            let energy = w + a * a * b / 4.0 + (2.0 * rng().0 - 1.0) * (a * a * b * w).sqrt();
+            if (0.0..=max_energy).contains(&energy) {
+                return Ok(SampledEnergy::uncorrelated(energy));
+            }
+        }
+    }
+}
+
+
+//=====================================================================
+// Law 22 - Tabular linear functions of incident energy out (UK law 2)
+//=====================================================================
+#[derive(Debug, Clone, PartialEq)]
+pub struct LinearFunctions {
+    pub probability: Vec<f64>,  // P
+    pub threshold: Vec<f64>,    // T
+    pub slope: Vec<f64>,        // C
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TabularLinearFunctionsEnergyDistribution {
+    pub incident_energy: IncidentEnergyGrid,
+    pub functions: Vec<LinearFunctions>,
+}
+
+impl TabularLinearFunctionsEnergyDistribution {
+    pub fn process(data: XxsArray<'_>, ldat_start: usize) -> Result<Self, PaceError> {
+        let (incident_energy, offset) = IncidentEnergyGrid::process(data, ldat_start)?;
+        let locators = locators_to_indices(data.slice(offset, incident_energy.len())?)?;
+        let functions = locators
+            .into_iter()
+            .map(|start| {
+                let num_functions = data.uint(start)?;
+                let probability_start = start + 1;
+                let threshold_start = probability_start + num_functions;
+                let slope_start = threshold_start + num_functions;
+                Ok(LinearFunctions {
+                    probability: data.floats(probability_start, num_functions)?.to_vec(),
+                    threshold: data.floats(threshold_start, num_functions)?.to_vec(),
+                    slope: data.floats(slope_start, num_functions)?.to_vec(),
+                })
+            })
+            .collect::<Result<_, PaceError>>()?;
+        Ok(Self { incident_energy, functions })
+    }
+}
+
+impl SampleEnergy for TabularLinearFunctionsEnergyDistribution {
+    fn sample_energy<R: FnMut() -> UnitF64>(&self, incident_energy: f64, rng: &mut R) -> Result<SampledEnergy, EnergyDistributionError> {
+        // The functions are not interpolated in incident energy, use the bin the energy falls in
+        let (bin, factor) = self.incident_energy.interpolation_factor(incident_energy);
+        let bin = if factor >= 1.0 { bin + 1 } else { bin };
+        let functions = self.functions.get(bin).ok_or(EnergyDistributionError::EmptyDistribution)?;
+        if functions.probability.is_empty() {
+            return Err(EnergyDistributionError::EmptyDistribution);
+        }
+
+        // Pick a function from the cumulative probabilities
+        let xi = rng().0;
+        let mut cumulative = 0.0;
+        let mut k = functions.probability.len() - 1;
+        for (i, &p) in functions.probability.iter().enumerate() {
+            cumulative += p;
+            if xi <= cumulative {
+                k = i;
+                break;
+            }
+        }
+        Ok(SampledEnergy::uncorrelated(functions.slope[k] * (incident_energy - functions.threshold[k])))
+    }
+}
+
+
+//=====================================================================
+// Law 24 - Tabular energy multipliers (UK law 6)
+//=====================================================================
+#[derive(Debug, Clone, PartialEq)]
+pub struct TabularEnergyMultipliersEnergyDistribution {
+    pub incident_energy: IncidentEnergyGrid,
+    pub multipliers: Vec<Vec<f64>>,     // Equiprobable E_out / E_in multipliers for each incident energy
+}
+
+impl TabularEnergyMultipliersEnergyDistribution {
+    pub fn process(data: XxsArray<'_>, ldat_start: usize) -> Result<Self, PaceError> {
+        let (incident_energy, offset) = IncidentEnergyGrid::process(data, ldat_start)?;
+        let num_multipliers = data.uint(offset)?;
+        let multipliers = (0..incident_energy.len())
+            .map(|i| data.floats(offset + 1 + i * num_multipliers, num_multipliers).map(<[f64]>::to_vec))
+            .collect::<Result<_, _>>()?;
+        Ok(Self { incident_energy, multipliers })
+    }
+}
+
+impl SampleEnergy for TabularEnergyMultipliersEnergyDistribution {
+    fn sample_energy<R: FnMut() -> UnitF64>(&self, incident_energy: f64, rng: &mut R) -> Result<SampledEnergy, EnergyDistributionError> {
+        let (_, _, table) = self.incident_energy.sample_table(incident_energy, rng);
+        let multipliers = self.multipliers.get(table).ok_or(EnergyDistributionError::EmptyDistribution)?;
+        if multipliers.len() < 2 {
+            return Err(EnergyDistributionError::EmptyDistribution);
+        }
+        // Pick an equiprobable bin and sample uniformly within it
+        let num_bins = multipliers.len() - 1;
+        let k = ((rng().0 * num_bins as f64) as usize).min(num_bins - 1);
+        let multiplier = multipliers[k] + rng().0 * (multipliers[k + 1] - multipliers[k]);
+        Ok(SampledEnergy::uncorrelated(multiplier * incident_energy))
+    }
+}
+
+
+//=====================================================================
+// Law 44 - Kalbach-87 correlated energy-angle distribution
+//=====================================================================
+#[derive(Debug, Clone, PartialEq)]
+pub struct KalbachTabulatedDistribution {
+    pub distribution: TabulatedEnergyDistribution,
+    pub precompound_fraction: Vec<f64>,     // R
+    pub angular_slope: Vec<f64>,            // A
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct KalbachEnergyDistribution {
+    pub incident_energy: IncidentEnergyGrid,
+    pub distributions: Vec<KalbachTabulatedDistribution>,
+}
+
+impl KalbachEnergyDistribution {
+    pub fn process(data: XxsArray<'_>, ldat_start: usize) -> Result<Self, PaceError> {
+        let (incident_energy, offset) = IncidentEnergyGrid::process(data, ldat_start)?;
+        let locators = locators_to_indices(data.slice(offset, incident_energy.len())?)?;
+        let distributions = locators
+            .into_iter()
+            .map(|start| {
+                let (distribution, r_start) = TabulatedEnergyDistribution::process(data, start)?;
+                let num_points = distribution.energy_out.len();
+                let a_start = r_start + num_points;
+                Ok(KalbachTabulatedDistribution {
+                    precompound_fraction: data.floats(r_start, num_points)?.to_vec(),
+                    angular_slope: data.floats(a_start, num_points)?.to_vec(),
+                    distribution,
+                })
+            })
+            .collect::<Result<_, PaceError>>()?;
+        Ok(Self { incident_energy, distributions })
+    }
+}
+
+impl SampleEnergy for KalbachEnergyDistribution {
+    fn sample_energy<R: FnMut() -> UnitF64>(&self, incident_energy: f64, rng: &mut R) -> Result<SampledEnergy, EnergyDistributionError> {
+        let tables: Vec<&TabulatedEnergyDistribution> = self.distributions.iter().map(|d| &d.distribution).collect();
+        let (energy, unscaled_energy, table, k, _) = sample_unit_base_interpolated(&tables, &self.incident_energy, incident_energy, rng)?;
+
+        // Get the Kalbach parameters at the (unscaled) outgoing energy
+        let kalbach = &self.distributions[table];
+        let (r, a) = match (kalbach.distribution.interpolation_scheme, kalbach.distribution.energy_out.get(k + 1)) {
+            (InterpolationScheme::LinLin, Some(&e_k1)) => {
+                let e_k = kalbach.distribution.energy_out[k];
+                let fraction = (unscaled_energy - e_k) / (e_k1 - e_k);
+                (
+                    kalbach.precompound_fraction[k] + fraction * (kalbach.precompound_fraction[k + 1] - kalbach.precompound_fraction[k]),
+                    kalbach.angular_slope[k] + fraction * (kalbach.angular_slope[k + 1] - kalbach.angular_slope[k]),
+                )
+            },
+            _ => (kalbach.precompound_fraction[k], kalbach.angular_slope[k]),
+        };
+
+        // Sample the cosine of the scattering angle from the Kalbach-87 systematics
+        let cos_theta = if a == 0.0 {
+            2.0 * rng().0 - 1.0
+        } else if rng().0 > r {
+            let t = (2.0 * rng().0 - 1.0) * a.sinh();
+            (t + (t * t + 1.0).sqrt()).ln() / a
+        } else {
+            let xi = rng().0;
+            (xi * a.exp() + (1.0 - xi) * (-a).exp()).ln() / a
+        };
+        Ok(SampledEnergy::correlated(energy, cos_theta.clamp(-1.0, 1.0)))
+    }
+}
+
+
+//=====================================================================
+// Law 61 - Correlated energy-angle distribution with tabulated angles
+//=====================================================================
+#[derive(Debug, Clone, PartialEq)]
+pub struct CorrelatedTabulatedDistribution {
+    pub distribution: TabulatedEnergyDistribution,
+    pub angular_distributions: Vec<AngularDistribution>,    // One per outgoing energy
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CorrelatedEnergyAngleDistribution {
+    pub incident_energy: IncidentEnergyGrid,
+    pub distributions: Vec<CorrelatedTabulatedDistribution>,
+}
+
+impl CorrelatedEnergyAngleDistribution {
+    pub fn process(data: XxsArray<'_>, ldat_start: usize) -> Result<Self, PaceError> {
+        let (incident_energy, offset) = IncidentEnergyGrid::process(data, ldat_start)?;
+        let locators = locators_to_indices(data.slice(offset, incident_energy.len())?)?;
+        let distributions = locators
+            .into_iter()
+            .map(|start| {
+                let (distribution, lc_start) = TabulatedEnergyDistribution::process(data, start)?;
+                let num_points = distribution.energy_out.len();
+                // Angular distribution locators, zero means isotropic
+                let angular_distributions = data.ints(lc_start, num_points)?
+                    .into_iter()
+                    .map(|locator| match locator as isize {
+                        0 => Ok(AngularDistribution::Isotropic(IsotropicAngularDistribution {})),
+                        locator => Ok(AngularDistribution::Tabulated(
+                            process_tabulated_angular_distribution(data, locator.unsigned_abs() - 1)?
+                        )),
+                    })
+                    .collect::<Result<_, PaceError>>()?;
+                Ok(CorrelatedTabulatedDistribution { distribution, angular_distributions })
+            })
+            .collect::<Result<_, PaceError>>()?;
+        Ok(Self { incident_energy, distributions })
+    }
+}
+
+impl SampleEnergy for CorrelatedEnergyAngleDistribution {
+    fn sample_energy<R: FnMut() -> UnitF64>(&self, incident_energy: f64, rng: &mut R) -> Result<SampledEnergy, EnergyDistributionError> {
+        let tables: Vec<&TabulatedEnergyDistribution> = self.distributions.iter().map(|d| &d.distribution).collect();
+        let (energy, _, table, k, xi) = sample_unit_base_interpolated(&tables, &self.incident_energy, incident_energy, rng)?;
+
+        // Use the angular distribution of the closest outgoing energy point
+        let correlated = &self.distributions[table];
+        let cdf = &correlated.distribution.cdf;
+        let use_lower = correlated.distribution.interpolation_scheme == InterpolationScheme::Histogram
+            || k + 1 >= cdf.len()
+            || xi.0 - cdf[k] < cdf[k + 1] - xi.0;
+        let angular_distribution = if use_lower {
+            &correlated.angular_distributions[k]
+        } else {
+            &correlated.angular_distributions[k + 1]
+        };
+        let cos_theta = angular_distribution.sample_cos_theta(rng())?;
+        Ok(SampledEnergy::correlated(energy, cos_theta))
+    }
+}
+
+// Tabulated angular distribution (JJ, NP, CosOut(NP), PDF(NP), CDF(NP)) as used by laws 61 and 67
+fn process_tabulated_angular_distribution(data: XxsArray<'_>, start: usize) -> Result<TabulatedAngularDistribution, PaceError> {
+    let interpolation_scheme = InterpolationScheme::try_from(data.uint(start)?)?;
+    let num_points = data.uint(start + 1)?;
+    let cos_theta_start = start + 2;
+    let cdf_start = cos_theta_start + 2 * num_points;
+    Ok(TabulatedAngularDistribution::new(
+        interpolation_scheme,
+        data.floats(cos_theta_start, num_points)?.to_vec(),
+        data.floats(cdf_start, num_points)?.to_vec(),
+    )?)
+}
+
+
+//=====================================================================
+// Law 66 - N-body phase space distribution
+//=====================================================================
+#[derive(Debug, Clone, PartialEq)]
+pub struct NBodyPhaseSpaceDistribution {
+    pub num_bodies: usize,          // NPSX
+    pub total_mass_ratio: f64,      // Ap
+    pub awr: f64,                   // Atomic weight ratio of the target
+    pub q_value: f64,               // Q value of the reaction
+}
+
+impl NBodyPhaseSpaceDistribution {
+    pub fn process(data: XxsArray<'_>, ldat_start: usize, kinematics: ReactionKinematics) -> Result<Self, PaceError> {
+        Ok(Self {
+            num_bodies: data.uint(ldat_start)?,
+            total_mass_ratio: data.float(ldat_start + 1)?,
+            awr: kinematics.awr,
+            q_value: kinematics.q_value,
+        })
+    }
+}
+
+impl SampleEnergy for NBodyPhaseSpaceDistribution {
+    fn sample_energy<R: FnMut() -> UnitF64>(&self, incident_energy: f64, rng: &mut R) -> Result<SampledEnergy, EnergyDistributionError> {
+        // Maximum center of mass energy available to the particle
+        let max_energy = (self.total_mass_ratio - 1.0) / self.total_mass_ratio
+            * (self.awr / (self.awr + 1.0) * incident_energy + self.q_value);
+
+        // Sample the fraction of the maximum energy, see the MCNP manual for the N-body phase space sampling scheme
+        let x = sample_maxwell(1.0, rng);
+        let y = match self.num_bodies {
+            3 => sample_maxwell(1.0, rng),
+            4 => -(rng().0 * rng().0 * rng().0).ln(),
+            _ => {
+                let (xi1, xi2, xi3, xi4, xi5, xi6) = (rng().0, rng().0, rng().0, rng().0, rng().0, rng().0);
+                let c = (PI / 2.0 * xi6).cos();
+                -(xi1 * xi2 * xi3 * xi4).ln() - xi5.ln() * c * c
+            },
+        };
+        let energy = max_energy * x / (x + y);
+
+        // The outgoing direction is isotropic in the center of mass system
+        Ok(SampledEnergy::correlated(energy, 2.0 * rng().0 - 1.0))
+    }
+}
+
+
+//=====================================================================
+// Law 67 - Laboratory angle-energy distribution
+//=====================================================================
+#[derive(Debug, Clone, PartialEq)]
+pub struct LabAngleEnergyTable {
+    pub interpolation_scheme: InterpolationScheme,              // INTMU
+    pub cos_theta: Vec<f64>,                                    // XMU
+    pub energy_distributions: Vec<TabulatedEnergyDistribution>, // One per cosine
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LabAngleEnergyDistribution {
+    pub incident_energy: IncidentEnergyGrid,
+    pub tables: Vec<LabAngleEnergyTable>,
+}
+
+impl LabAngleEnergyDistribution {
+    pub fn process(data: XxsArray<'_>, ldat_start: usize) -> Result<Self, PaceError> {
+        let (incident_energy, offset) = IncidentEnergyGrid::process(data, ldat_start)?;
+        let locators = locators_to_indices(data.slice(offset, incident_energy.len())?)?;
+        let tables = locators
+            .into_iter()
+            .map(|start| {
+                let interpolation_scheme = InterpolationScheme::try_from(data.uint(start)?)?;
+                let num_cosines = data.uint(start + 1)?;
+                let cos_theta_start = start + 2;
+                let locators_start = cos_theta_start + num_cosines;
+                let energy_distributions = locators_to_indices(data.slice(locators_start, num_cosines)?)?
+                    .into_iter()
+                    .map(|energy_start| Ok(TabulatedEnergyDistribution::process(data, energy_start)?.0))
+                    .collect::<Result<_, PaceError>>()?;
+                Ok(LabAngleEnergyTable {
+                    interpolation_scheme,
+                    cos_theta: data.floats(cos_theta_start, num_cosines)?.to_vec(),
+                    energy_distributions,
+                })
+            })
+            .collect::<Result<_, PaceError>>()?;
+        Ok(Self { incident_energy, tables })
+    }
+}
+
+impl SampleEnergy for LabAngleEnergyDistribution {
+    fn sample_energy<R: FnMut() -> UnitF64>(&self, incident_energy: f64, rng: &mut R) -> Result<SampledEnergy, EnergyDistributionError> {
+        let (_, _, table) = self.incident_energy.sample_table(incident_energy, rng);
+        let table = self.tables.get(table).ok_or(EnergyDistributionError::EmptyDistribution)?;
+        let num_cosines = table.cos_theta.len();
+        if num_cosines == 0 {
+            return Err(EnergyDistributionError::EmptyDistribution);
+        }
+        if num_cosines == 1 {
+            let (energy, _) = table.energy_distributions[0].sample(rng())?;
+            return Ok(SampledEnergy::correlated(energy, table.cos_theta[0]));
+        }
+
+        // The angular PDF at each cosine is the integral of its (unnormalized) energy distribution,
+        // which is the final value of its CDF. Build the angular CDF with the trapezoid rule.
+        let angular_pdf: Vec<f64> = table.energy_distributions
+            .iter()
+            .map(|distribution| distribution.cdf.last().copied().unwrap_or(0.0))
+            .collect();
+        let mut angular_cdf = vec![0.0; num_cosines];
+        for j in 1..num_cosines {
+            angular_cdf[j] = angular_cdf[j - 1]
+                + 0.5 * (angular_pdf[j] + angular_pdf[j - 1]) * (table.cos_theta[j] - table.cos_theta[j - 1]);
+        }
+        let total = angular_cdf[num_cosines - 1];
+        if total <= 0.0 {
+            return Err(EnergyDistributionError::EmptyDistribution);
+        }
+
+        // Sample the cosine within its bin, then the energy from the closest cosine's distribution
+        let xi = rng().0 * total;
+        let j = angular_cdf.partition_point(|&c| c <= xi).saturating_sub(1).min(num_cosines - 2);
+        let fraction = (xi - angular_cdf[j]) / (angular_cdf[j + 1] - angular_cdf[j]).max(f64::MIN_POSITIVE);
+        let cos_theta = match table.interpolation_scheme {
+            InterpolationScheme::Histogram => table.cos_theta[j],
+            _ => table.cos_theta[j] + fraction.clamp(0.0, 1.0) * (table.cos_theta[j + 1] - table.cos_theta[j]),
+        };
+        let closest = if fraction < 0.5 { j } else { j + 1 };
+        let (energy, _) = table.energy_distributions[closest].sample(rng())?;
+        Ok(SampledEnergy::correlated(energy, cos_theta))
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Source of random numbers which cycles through a fixed list of values
+    fn fixed_rng(values: Vec<f64>) -> impl FnMut() -> UnitF64 {
+        let mut index = 0;
+        move || {
+            let value = values[index % values.len()];
+            index += 1;
+            UnitF64(value)
+        }
+    }
+
+    // Simple linear congruential generator, used for checking the statistics of the samplers
+    fn lcg_rng(seed: u64) -> impl FnMut() -> UnitF64 {
+        let mut state = seed;
+        move || {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            UnitF64(((state >> 11) as f64 + 0.5) / (1u64 << 53) as f64)
+        }
+    }
+
+    fn single_point_grid(energy: Vec<f64>) -> IncidentEnergyGrid {
+        IncidentEnergyGrid { breakpoints: vec![], schemes: vec![], energy }
+    }
+
+    fn constant_table(value: f64) -> InterpolationTable {
+        InterpolationTable::from_x_and_y(vec![1.0e-11, 30.0], vec![value, value], InterpolationScheme::LinLin)
+    }
+
+    #[test]
+    fn test_level_scattering_processing() {
+        let data = vec![3.0, 0.25];
+        let distribution = EnergyDistribution::process(3, XxsArray::untyped(&data), 0, ReactionKinematics::default()).unwrap();
+        assert_eq!(distribution.law(), 3);
+        assert_eq!(
+            distribution,
+            EnergyDistribution::LevelScattering(LevelScatteringEnergyDistribution { threshold: 3.0, mass_ratio: 0.25 })
+        );
+    }
+
+    #[test]
+    fn test_watt_processing() {
+        // a(E) and b(E) tables followed by the restriction energy
+        let data = vec![
+            0.0, 2.0, 1.0, 2.0, 0.9, 1.0,
+            0.0, 2.0, 1.0, 2.0, 2.0, 3.0,
+            -1.0,
+        ];
+        let watt = match EnergyDistribution::process(11, XxsArray::untyped(&data), 0, ReactionKinematics::default()).unwrap() {
+            EnergyDistribution::Watt(watt) => watt,
+            _ => panic!("This should be a Watt spectrum"),
+        };
+        assert_eq!(watt.a.interpolate(1.5).unwrap(), 0.95);
+        assert_eq!(watt.b.interpolate(1.5).unwrap(), 2.5);
+        assert_eq!(watt.restriction_energy, -1.0);
+    }
+
+    #[test]
+    fn test_kalbach_processing() {
+        // Incident energy grid without interpolation regions and a single distribution located at word 6
+        let data = vec![
+            0.0, 1.0, 1.0, 6.0,
+            0.0,
+            2.0, 2.0, 0.0, 1.0, 1.0, 1.0, 0.0, 1.0, 0.1, 0.2, 0.3, 0.4,
+        ];
+        let kalbach = match EnergyDistribution::process(44, XxsArray::untyped(&data), 0, ReactionKinematics::default()).unwrap() {
+            EnergyDistribution::Kalbach(kalbach) => kalbach,
+            _ => panic!("This should be a Kalbach distribution"),
+        };
+        assert_eq!(kalbach.incident_energy.energy, vec![1.0]);
+        assert_eq!(kalbach.distributions.len(), 1);
+        let distribution = &kalbach.distributions[0];
+        assert_eq!(distribution.distribution.interpolation_scheme, InterpolationScheme::LinLin);
+        assert_eq!(distribution.distribution.energy_out, vec![0.0, 1.0]);
+        assert_eq!(distribution.distribution.cdf, vec![0.0, 1.0]);
+        assert_eq!(distribution.precompound_fraction, vec![0.1, 0.2]);
+        assert_eq!(distribution.angular_slope, vec![0.3, 0.4]);
+    }
+
+    #[test]
+    fn test_unknown_law() {
+        let result = EnergyDistribution::process(8, XxsArray::untyped(&[0.0]), 0, ReactionKinematics::default());
+        assert!(matches!(result, Err(PaceError::UnknownCode { code: 8, .. })));
+    }
+
+    #[test]
+    fn test_truncated_distribution() {
+        // The Kalbach data from above, missing its last word
+        let data = vec![
+            0.0, 1.0, 1.0, 6.0,
+            0.0,
+            2.0, 2.0, 0.0, 1.0, 1.0, 1.0, 0.0, 1.0, 0.1, 0.2, 0.3,
+        ];
+        let result = EnergyDistribution::process(44, XxsArray::untyped(&data), 0, ReactionKinematics::default());
+        assert!(matches!(result, Err(PaceError::LocatorOutOfBounds { index: 16, length: 16 })));
+    }
+
+    #[test]
+    fn test_interpolation_factor() {
+        let grid = single_point_grid(vec![1.0, 2.0, 4.0]);
+        assert_eq!(grid.interpolation_factor(0.5), (0, 0.0));
+        assert_eq!(grid.interpolation_factor(1.5), (0, 0.5));
+        assert_eq!(grid.interpolation_factor(3.0), (1, 0.5));
+        assert_eq!(grid.interpolation_factor(5.0), (1, 1.0));
+
+        // Histogram interpolation in the first region, log-log in the second
+        let grid = IncidentEnergyGrid {
+            breakpoints: vec![2, 3],
+            schemes: vec![InterpolationScheme::Histogram, InterpolationScheme::LogLog],
+            energy: vec![1.0, 2.0, 4.0],
+        };
+        assert_eq!(grid.interpolation_factor(1.5), (0, 0.0));
+        assert!((grid.interpolation_factor(2.0 * 2.0_f64.sqrt()).1 - 0.5).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_tabulated_energy_distribution_sampling() {
+        // Linear-linear PDF rising from 0 to 2 over [0, 1]
+        let linlin = TabulatedEnergyDistribution {
+            interpolation_scheme: InterpolationScheme::LinLin,
+            num_discrete_lines: 0,
+            energy_out: vec![0.0, 1.0],
+            pdf: vec![0.0, 2.0],
+            cdf: vec![0.0, 1.0],
+        };
+        assert_eq!(linlin.sample(UnitF64(0.0)).unwrap(), (0.0, 0));
+        assert!((linlin.sample(UnitF64(0.25)).unwrap().0 - 0.5).abs() < 1e-12);
+        assert!((linlin.sample(UnitF64(1.0)).unwrap().0 - 1.0).abs() < 1e-12);
+
+        // Histogram PDF with two bins
+        let histogram = TabulatedEnergyDistribution {
+            interpolation_scheme: InterpolationScheme::Histogram,
+            num_discrete_lines: 0,
+            energy_out: vec![0.0, 1.0, 3.0],
+            pdf: vec![0.5, 0.25, 0.0],
+            cdf: vec![0.0, 0.5, 1.0],
+        };
+        assert_eq!(histogram.sample(UnitF64(0.25)).unwrap(), (0.5, 0));
+        assert_eq!(histogram.sample(UnitF64(0.75)).unwrap(), (2.0, 1));
+
+        // A discrete line carrying half of the probability
+        let discrete = TabulatedEnergyDistribution {
+            interpolation_scheme: InterpolationScheme::Histogram,
+            num_discrete_lines: 1,
+            energy_out: vec![5.0, 0.0, 1.0],
+            pdf: vec![0.5, 0.5, 0.0],
+            cdf: vec![0.5, 0.5, 1.0],
+        };
+        assert_eq!(discrete.sample(UnitF64(0.25)).unwrap(), (5.0, 0));
+        assert_eq!(discrete.sample(UnitF64(0.75)).unwrap(), (0.5, 1));
+
+        // The same histogram without normalization samples the same energies
+        let unnormalized = TabulatedEnergyDistribution {
+            pdf: vec![1.0, 0.5, 0.0],
+            cdf: vec![0.0, 1.0, 2.0],
+            ..histogram
+        };
+        assert_eq!(unnormalized.sample(UnitF64(0.25)).unwrap(), (0.5, 0));
+        assert_eq!(unnormalized.sample(UnitF64(0.75)).unwrap(), (2.0, 1));
+
+        // Linear-linear PDF whose last bin is empty, which is chosen at the end of the CDF
+        let empty_bin = TabulatedEnergyDistribution {
+            interpolation_scheme: InterpolationScheme::LinLin,
+            num_discrete_lines: 0,
+            energy_out: vec![0.0, 1.0, 2.0],
+            pdf: vec![1.0, 1.0, 0.0],
+            cdf: vec![0.0, 1.0, 1.0],
+        };
+        assert_eq!(empty_bin.sample(UnitF64(0.5)).unwrap(), (0.5, 0));
+        let empty_bin = TabulatedEnergyDistribution { pdf: vec![1.0, 0.0, 0.0], cdf: vec![0.0, 0.5, 0.5], ..empty_bin };
+        assert_eq!(empty_bin.sample(UnitF64(1.0)).unwrap(), (1.0, 1));
+
+        // Repeated outgoing energy, giving a bin of zero width
+        let zero_width = TabulatedEnergyDistribution {
+            interpolation_scheme: InterpolationScheme::LinLin,
+            num_discrete_lines: 0,
+            energy_out: vec![0.0, 1.0, 1.0],
+            pdf: vec![1.0, 1.0, 0.0],
+            cdf: vec![0.0, 1.0, 1.0],
+        };
+        assert_eq!(zero_width.sample(UnitF64(1.0)).unwrap(), (1.0, 1));
+    }
+
+    #[test]
+    fn test_equiprobable_bins_sampling() {
+        let distribution = EquiprobableBinsEnergyDistribution {
+            incident_energy: single_point_grid(vec![1.0, 3.0]),
+            energy_out: vec![vec![0.0, 1.0, 2.0], vec![0.0, 2.0, 4.0]],
+        };
+        // Second bin at the midpoint of the incident energy grid, halfway through the bin
+        let mut rng = fixed_rng(vec![0.75, 0.5]);
+        let sample = distribution.sample_energy(2.0, &mut rng).unwrap();
+        assert!((sample.energy - 2.25).abs() < 1e-12);
+        assert_eq!(sample.cos_theta, None);
+    }
+
+    #[test]
+    fn test_discrete_photon_sampling() {
+        let mut rng = fixed_rng(vec![0.5]);
+        let mut photon = DiscretePhotonEnergyDistribution { primary_flag: 0, photon_energy: 2.0, awr: 1.0 };
+        assert_eq!(photon.sample_energy(4.0, &mut rng).unwrap().energy, 2.0);
+        photon.primary_flag = 2;
+        assert_eq!(photon.sample_energy(4.0, &mut rng).unwrap().energy, 4.0);
+    }
+
+    #[test]
+    fn test_level_scattering_sampling() {
+        let distribution = LevelScatteringEnergyDistribution { threshold: 1.0, mass_ratio: 0.25 };
+        let mut rng = fixed_rng(vec![0.5]);
+        assert_eq!(distribution.sample_energy(5.0, &mut rng).unwrap().energy, 1.0);
+    }
+
+    #[test]
+    fn test_continuous_tabular_sampling() {
+        // Uniform distributions over [0, 1] and [0, 3], sampled halfway between the incident energies
+        let make_uniform = |width: f64| TabulatedEnergyDistribution {
+            interpolation_scheme: InterpolationScheme::Histogram,
+            num_discrete_lines: 0,
+            energy_out: vec![0.0, width],
+            pdf: vec![1.0 / width, 0.0],
+            cdf: vec![0.0, 1.0],
+        };
+        let distribution = ContinuousTabularEnergyDistribution {
+            incident_energy: single_point_grid(vec![1.0, 3.0]),
+            distributions: vec![make_uniform(1.0), make_uniform(3.0)],
+        };
+        // Unit-base interpolation should give the midpoint of [0, 2] from either table
+        let mut rng = fixed_rng(vec![0.9, 0.5]);
+        assert!((distribution.sample_energy(2.0, &mut rng).unwrap().energy - 1.0).abs() < 1e-12);
+        let mut rng = fixed_rng(vec![0.1, 0.5]);
+        assert!((distribution.sample_energy(2.0, &mut rng).unwrap().energy - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_maxwell_and_evaporation_sampling() {
+        let maxwell = MaxwellFissionEnergyDistribution { temperature: constant_table(1.0), restriction_energy: -1.0e3 };
+        let evaporation = EvaporationEnergyDistribution { temperature: constant_table(1.0), restriction_energy: -1.0e3 };
+        let num_samples = 100_000;
+
+        // The mean of a Maxwellian is 3T/2, and the mean of an untruncated evaporation spectrum is 2T
+        let mut rng = lcg_rng(1);
+        let maxwell_mean = (0..num_samples)
+            .map(|_| maxwell.sample_energy(1.0, &mut rng).unwrap().energy)
+            .sum::<f64>() / num_samples as f64;
+        assert!((maxwell_mean - 1.5).abs() < 0.02);
+        let evaporation_mean = (0..num_samples)
+            .map(|_| evaporation.sample_energy(1.0, &mut rng).unwrap().energy)
+            .sum::<f64>() / num_samples as f64;
+        assert!((evaporation_mean - 2.0).abs() < 0.03);
+
+        // No energy is available below the restriction energy
+        let restricted = EvaporationEnergyDistribution { temperature: constant_table(1.0), restriction_energy: 2.0 };
+        assert!(restricted.sample_energy(1.0, &mut rng).is_err());
+        for _ in 0..1000 {
+            assert!(restricted.sample_energy(3.0, &mut rng).unwrap().energy <= 1.0);
+        }
+    }
+
+    #[test]
+    fn test_watt_sampling() {
+        // The mean of a Watt spectrum is 3a/2 + a^2 b / 4
+        let watt = WattEnergyDistribution { a: constant_table(1.0), b: constant_table(2.0), restriction_energy: -1.0e3 };
+        let num_samples = 100_000;
+        let mut rng = lcg_rng(2);
+        let mean = (0..num_samples)
+            .map(|_| watt.sample_energy(1.0, &mut rng).unwrap().energy)
+            .sum::<f64>() / num_samples as f64;
+        assert!((mean - 2.0).abs() < 0.03);
+    }
+
+    #[test]
+    fn test_tabular_linear_functions_sampling() {
+        let distribution = TabularLinearFunctionsEnergyDistribution {
+            incident_energy: single_point_grid(vec![1.0, 10.0]),
+            functions: vec![
+                LinearFunctions { probability: vec![0.5, 0.5], threshold: vec![0.0, 1.0], slope: vec![0.5, 1.0] },
+                LinearFunctions { probability: vec![1.0], threshold: vec![0.0], slope: vec![0.1] },
+            ],
+        };
+        let mut rng = fixed_rng(vec![0.25]);
+        assert_eq!(distribution.sample_energy(4.0, &mut rng).unwrap().energy, 2.0);
+        let mut rng = fixed_rng(vec![0.75]);
+        assert_eq!(distribution.sample_energy(4.0, &mut rng).unwrap().energy, 3.0);
+    }
+
+    #[test]
+    fn test_tabular_energy_multipliers_sampling() {
+        let distribution = TabularEnergyMultipliersEnergyDistribution {
+            incident_energy: single_point_grid(vec![1.0]),
+            multipliers: vec![vec![0.0, 0.5, 1.0]],
+        };
+        let mut rng = fixed_rng(vec![0.75, 0.5]);
+        assert_eq!(distribution.sample_energy(4.0, &mut rng).unwrap().energy, 3.0);
+    }
+
+    #[test]
+    fn test_kalbach_sampling() {
+        let distribution = KalbachEnergyDistribution {
+            incident_energy: single_point_grid(vec![1.0]),
+            distributions: vec![KalbachTabulatedDistribution {
+                distribution: TabulatedEnergyDistribution {
+                    interpolation_scheme: InterpolationScheme::Histogram,
+                    num_discrete_lines: 0,
+                    energy_out: vec![0.0, 1.0],
+                    pdf: vec![1.0, 0.0],
+                    cdf: vec![0.0, 1.0],
+                },
+                precompound_fraction: vec![0.5, 0.5],
+                angular_slope: vec![2.0, 2.0],
+            }],
+        };
+        let mut rng = lcg_rng(3);
+        let num_samples = 100_000;
+        let (mut energy_sum, mut cos_theta_sum) = (0.0, 0.0);
+        for _ in 0..num_samples {
+            let sample = distribution.sample_energy(1.0, &mut rng).unwrap();
+            let cos_theta = sample.cos_theta.unwrap();
+            assert!((0.0..=1.0).contains(&sample.energy));
+            assert!((-1.0..=1.0).contains(&cos_theta));
+            energy_sum += sample.energy;
+            cos_theta_sum += cos_theta;
+        }
+
+        // The Kalbach angular PDF is a / (2 sinh(a)) * (cosh(a mu) + r sinh(a mu)), which has a
+        // mean cosine of r * (coth(a) - 1 / a). The energy is uniform on [0, 1].
+        let (r, a) = (0.5_f64, 2.0_f64);
+        let mean_cos_theta = r * (1.0 / a.tanh() - 1.0 / a);
+        assert!((cos_theta_sum / num_samples as f64 - mean_cos_theta).abs() < 0.01);
+        assert!((energy_sum / num_samples as f64 - 0.5).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_correlated_energy_angle_sampling() {
+        let forward = TabulatedAngularDistribution::new(InterpolationScheme::LinLin, vec![0.0, 1.0], vec![0.0, 1.0]).unwrap();
+        let distribution = CorrelatedEnergyAngleDistribution {
+            incident_energy: single_point_grid(vec![1.0]),
+            distributions: vec![CorrelatedTabulatedDistribution {
+                distribution: TabulatedEnergyDistribution {
+                    interpolation_scheme: InterpolationScheme::Histogram,
+                    num_discrete_lines: 0,
+                    energy_out: vec![0.0, 1.0, 2.0],
+                    pdf: vec![0.5, 0.5, 0.0],
+                    cdf: vec![0.0, 0.5, 1.0],
+                },
+                angular_distributions: vec![
+                    AngularDistribution::Isotropic(IsotropicAngularDistribution {}),
+                    AngularDistribution::Tabulated(forward),
+                    AngularDistribution::Isotropic(IsotropicAngularDistribution {}),
+                ],
+            }],
+        };
+        // First bin uses the isotropic distribution, second bin the forward peaked one
+        let mut rng = fixed_rng(vec![0.25, 0.0]);
+        assert_eq!(distribution.sample_energy(1.0, &mut rng).unwrap(), SampledEnergy::correlated(0.5, -1.0));
+        let mut rng = fixed_rng(vec![0.75, 0.0]);
+        assert_eq!(distribution.sample_energy(1.0, &mut rng).unwrap(), SampledEnergy::correlated(1.5, 0.0));
+    }
+
+    #[test]
+    fn test_n_body_phase_space_sampling() {
+        let distribution = NBodyPhaseSpaceDistribution { num_bodies: 3, total_mass_ratio: 2.0, awr: 1.0, q_value: 0.0 };
+        let mut rng = lcg_rng(4);
+        // Maximum energy is (Ap - 1) / Ap * A / (A + 1) * E = E / 4
+        for _ in 0..1000 {
+            let sample = distribution.sample_energy(4.0, &mut rng).unwrap();
+            assert!((0.0..=1.0).contains(&sample.energy));
+            assert!((-1.0..=1.0).contains(&sample.cos_theta.unwrap()));
+        }
+    }
+
+    #[test]
+    fn test_lab_angle_energy_sampling() {
+        let uniform = TabulatedEnergyDistribution {
+            interpolation_scheme: InterpolationScheme::Histogram,
+            num_discrete_lines: 0,
+            energy_out: vec![0.0, 1.0],
+            pdf: vec![1.0, 0.0],
+            cdf: vec![0.0, 1.0],
+        };
+        let distribution = LabAngleEnergyDistribution {
+            incident_energy: single_point_grid(vec![1.0]),
+            tables: vec![LabAngleEnergyTable {
+                interpolation_scheme: InterpolationScheme::LinLin,
+                cos_theta: vec![-1.0, 1.0],
+                energy_distributions: vec![uniform.clone(), uniform],
+            }],
+        };
+        let mut rng = fixed_rng(vec![0.5, 0.5]);
+        assert_eq!(distribution.sample_energy(1.0, &mut rng).unwrap(), SampledEnergy::correlated(0.5, 0.0));
+    }
+}
@@ -0,0 +1,29 @@
+mod energy_distribution_types;
+mod secondary_energy_distribution;
+
+// Traits
+pub use energy_distribution_types::SampleEnergy;
+
+// Structs
+pub use energy_distribution_types::EnergyDistribution;
+pub use energy_distribution_types::{SampledEnergy, EnergyDistributionError, ReactionKinematics};
+pub use energy_distribution_types::IncidentEnergyGrid;
+pub use energy_distribution_types::TabulatedEnergyDistribution;
+pub use energy_distribution_types::EquiprobableBinsEnergyDistribution;
+pub use energy_distribution_types::DiscretePhotonEnergyDistribution;
+pub use energy_distribution_types::LevelScatteringEnergyDistribution;
+pub use energy_distribution_types::ContinuousTabularEnergyDistribution;
+pub use energy_distribution_types::GeneralEvaporationEnergyDistribution;
+pub use energy_distribution_types::MaxwellFissionEnergyDistribution;
+pub use energy_distribution_types::EvaporationEnergyDistribution;
+pub use energy_distribution_types::WattEnergyDistribution;
+pub use energy_distribution_types::{LinearFunctions, TabularLinearFunctionsEnergyDistribution};
+pub use energy_distribution_types::TabularEnergyMultipliersEnergyDistribution;
+pub use energy_distribution_types::{KalbachTabulatedDistribution, KalbachEnergyDistribution};
+pub use energy_distribution_types::{CorrelatedTabulatedDistribution, CorrelatedEnergyAngleDistribution};
+pub use energy_distribution_types::NBodyPhaseSpaceDistribution;
+pub use energy_distribution_types::{LabAngleEnergyTable, LabAngleEnergyDistribution};
+pub use secondary_energy_distribution::{EnergyDistributionLaw, SecondaryEnergyDistribution};
+
+// Helpers
+pub(crate) use energy_distribution_types::interpolate_clamped;
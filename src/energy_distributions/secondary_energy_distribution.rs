@@ -0,0 +1,88 @@
+use crate::unitf64::UnitF64;
+use crate::interpolation::InterpolationTable;
+use crate::energy_distributions::{
+    EnergyDistribution,
+    EnergyDistributionError,
+    ReactionKinematics,
+    SampleEnergy,
+    SampledEnergy,
+};
+use crate::energy_distributions::energy_distribution_types::interpolate_clamped;
+use crate::arrays::{XxsArray, XxsWords};
+use crate::pace_error::{PaceError, locator_to_index};
+
+// A single law in the chain of energy distribution laws for a reaction, along with the
+// tabulated probability that the law applies as a function of incident energy.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EnergyDistributionLaw {
+    pub applicability: InterpolationTable,
+    pub distribution: EnergyDistribution,
+}
+
+impl EnergyDistributionLaw {
+    // The ACE law number of the distribution
+    pub fn law(&self) -> usize {
+        self.distribution.law()
+    }
+}
+
+// This struct contains the full chain of energy distribution laws for a reaction.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SecondaryEnergyDistribution {
+    pub laws: Vec<EnergyDistributionLaw>,
+}
+
+impl SecondaryEnergyDistribution {
+    // Process the chain of laws which starts at `locator` (relative to the start of the block data).
+    // Each law is laid out as LNW, LAW, IDAT, followed by the law applicability table, where LNW is the
+    // location of the next law (0 if this is the last law) and IDAT is the location of the law data.
+    pub fn process(data: XxsArray<'_>, locator: usize, kinematics: ReactionKinematics) -> Result<Self, PaceError> {
+        let mut laws = Vec::new();
+        let mut law_start = locator_to_index(locator)?;
+        loop {
+            let next_law_locator = data.uint(law_start)?;
+            let law = data.uint(law_start + 1)?;
+            let ldat_start = locator_to_index(data.uint(law_start + 2)?)?;
+
+            // Construct the interpolation table which describes the probability of this law applying
+            let applicability = InterpolationTable::process(data.tail(law_start + 3)?)?;
+            let distribution = EnergyDistribution::process(law, data, ldat_start, kinematics)?;
+            laws.push(EnergyDistributionLaw { applicability, distribution });
+
+            // Move on to the next law, if there is one
+            if next_law_locator == 0 {
+                break;
+            }
+            law_start = locator_to_index(next_law_locator)?;
+        }
+        Ok(Self { laws })
+    }
+}
+
+impl SampleEnergy for SecondaryEnergyDistribution {
+    fn sample_energy<R: FnMut() -> UnitF64>(&self, incident_energy: f64, rng: &mut R) -> Result<SampledEnergy, EnergyDistributionError> {
+        let last_law = self.laws.last().ok_or(EnergyDistributionError::EmptyDistribution)?;
+
+        // Pick a law using the probability of each law applying at the incident energy,
+        // with the last law taking up any remaining probability
+        let xi = rng().0;
+        let mut cumulative = 0.0;
+        for law in self.laws.iter() {
+            cumulative += interpolate_clamped(&law.applicability, incident_energy)?;
+            if xi < cumulative {
+                return law.distribution.sample_energy(incident_energy, rng);
+            }
+        }
+        last_law.distribution.sample_energy(incident_energy, rng)
+    }
+}
+
+impl std::fmt::Display for SecondaryEnergyDistribution {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let laws = self.laws.iter()
+            .map(|law| law.law().to_string())
+            .collect::<Vec<String>>()
+            .join(", ");
+        write!(f, "SecondaryEnergyDistribution(laws {})", laws)
+    }
+}
@@ -4,6 +4,8 @@ use std::io::BufReader;
 use anyhow::Result;
 
 use crate::utils;
+use crate::pace_error::PaceError;
+use crate::blocks::ParticleType;
 
 //=====================================================================
 // Support for the headers of ACE files. These contain high-level
@@ -14,6 +16,31 @@ use crate::utils;
 // ACE header formats (>2.0.0 and legacy).
 //=====================================================================
 
+// Class of an ACE table, given by the letter at the end of the ZAID
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TableClass {
+    ContinuousNeutron,  // "c"
+    ThermalScattering,  // "t"
+    Photoatomic,        // "p"
+    Photonuclear,       // "u"
+    Dosimetry,          // "y"
+    ChargedParticle,    // "h", "o", "r", "s" and "a"
+}
+
+impl TableClass {
+    pub fn from_class_letter(letter: char) -> Option<Self> {
+        match letter {
+            'c' => Some(Self::ContinuousNeutron),
+            't' => Some(Self::ThermalScattering),
+            'p' => Some(Self::Photoatomic),
+            'u' => Some(Self::Photonuclear),
+            'y' => Some(Self::Dosimetry),
+            'h' | 'o' | 'r' | 's' | 'a' => Some(Self::ChargedParticle),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct Header {
     pub zaid: String,
@@ -56,11 +83,16 @@ impl Header {
         Ok(Self { zaid, szaid, atomic_mass_fraction, kT, temperature })
     }
 
-    pub fn from_PACE(mmap: &utils::PaceMmap) -> Result<Self> {
+    pub fn from_PACE(mmap: &utils::PaceMmap) -> Result<Self, PaceError> {
         let header_bytes = mmap.header_bytes();
+        let read_string = |offset: usize, name: &str| {
+            String::from_utf8(header_bytes[offset..offset + 16].trim_ascii_end().to_vec())
+                .map_err(|_| PaceError::InvalidHeader(format!("{} is not valid UTF-8", name)))
+        };
+        let read_f64 = |offset: usize| f64::from_ne_bytes(std::array::from_fn(|i| header_bytes[offset + i]));
         let mut offset = 0;
         // Read SZAID (first 16 bytes)
-        let szaid_str = String::from_utf8(header_bytes[offset..offset + 16].trim_ascii_end().to_vec()).unwrap();
+        let szaid_str = read_string(offset, "SZAID")?;
         offset += 16;
 
         let szaid = {
@@ -72,15 +104,15 @@ impl Header {
         };
 
         // Read ZAID (next 16 bytes), cast to String
-        let zaid = String::from_utf8(header_bytes[offset..offset + 16].trim_ascii_end().to_vec()).unwrap();
+        let zaid = read_string(offset, "ZAID")?;
         offset += 16;
 
         // Read atomic mass fraction, cast to f64
-        let atomic_mass_fraction = f64::from_ne_bytes(header_bytes[offset..offset + 8].try_into().unwrap());
+        let atomic_mass_fraction = read_f64(offset);
         offset += 8;
 
         // Read kT, cast to f64
-        let kT = f64::from_ne_bytes(header_bytes[offset..offset + 8].try_into().unwrap());
+        let kT = read_f64(offset);
 
         // Calculate temperature in Kelvin from kT
         let temperature = utils::compute_temperature_from_kT(kT);
@@ -93,6 +125,32 @@ impl Header {
             temperature,
         })
     }
+
+    // Letter at the end of the SZAID if it is available, and otherwise at the end of the ZAID,
+    // which gives the class of the table
+    fn class_letter(&self) -> Option<char> {
+        self.szaid.as_deref().unwrap_or(&self.zaid).chars().last()
+    }
+
+    // Incident particle type, given by the class letter (see class_letter). Continuous neutron
+    // tables end in "c", while incident charged-particle tables end in "h" (protons),
+    // "o" (deuterons), "r" (tritons), "s" (helions) or "a" (alphas).
+    pub fn projectile(&self) -> ParticleType {
+        match self.class_letter() {
+            Some('h') => ParticleType::Proton,
+            Some('o') => ParticleType::Deuteron,
+            Some('r') => ParticleType::Triton,
+            Some('s') => ParticleType::Helion,
+            Some('a') => ParticleType::Alpha,
+            _ => ParticleType::Neutron,
+        }
+    }
+
+    // Class of the table, given by the class letter (see class_letter). None for classes that
+    // are not supported, such as the older el03 ("e" class) electron tables.
+    pub fn table_class(&self) -> Option<TableClass> {
+        TableClass::from_class_letter(self.class_letter()?)
+    }
 }
 
 
@@ -137,4 +195,40 @@ mod tests {
         assert!((header.kT - 2.5301e-08).abs() < 1e-6);
         assert!((header.temperature - 293.605912998).abs() < 1e-6);
     }
+
+    // Header with only a ZAID, for checking what is read from the ZAID
+    fn header(zaid: &str) -> Header {
+        Header {
+            zaid: zaid.to_string(),
+            szaid: None,
+            atomic_mass_fraction: 1.0,
+            kT: 0.0,
+            temperature: 0.0,
+        }
+    }
+
+    #[test]
+    fn test_projectile_from_zaid() {
+        assert_eq!(header("1100.00c").projectile(), ParticleType::Neutron);
+        assert_eq!(header("3006.24h").projectile(), ParticleType::Proton);
+        assert_eq!(header("1003.00o").projectile(), ParticleType::Deuteron);
+        assert_eq!(header("1002.24r").projectile(), ParticleType::Triton);
+        assert_eq!(header("2003.24s").projectile(), ParticleType::Helion);
+        assert_eq!(header("2004.24a").projectile(), ParticleType::Alpha);
+    }
+
+    #[test]
+    fn test_table_class_from_zaid() {
+        assert_eq!(header("1100.00c").table_class(), Some(TableClass::ContinuousNeutron));
+        assert_eq!(header("lwtr.20t").table_class(), Some(TableClass::ThermalScattering));
+        assert_eq!(header("1000.14p").table_class(), Some(TableClass::Photoatomic));
+        assert_eq!(header("6012.00u").table_class(), Some(TableClass::Photonuclear));
+        assert_eq!(header("13027.00y").table_class(), Some(TableClass::Dosimetry));
+        assert_eq!(header("1003.00o").table_class(), Some(TableClass::ChargedParticle));
+        assert_eq!(header("1000.03e").table_class(), None);
+
+        // The SZAID is used when it is available
+        let header = Header { szaid: Some(String::from("6012.800u")), ..header("6012.00c") };
+        assert_eq!(header.table_class(), Some(TableClass::Photonuclear));
+    }
 }
@@ -1,3 +1,4 @@
+#[allow(clippy::module_inception)]
 mod header;
 
-pub use header::Header;
+pub use header::{Header, TableClass};
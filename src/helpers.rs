@@ -6,7 +6,11 @@ pub enum MTNumber {
     Total = 1,
     ElasticScattering = 2,
     Fission = 18,
+    FirstChanceFission = 19,
+    SecondChanceFission = 20,
+    ThirdChanceFission = 21,
     NeutronAbsorption = 27,
+    FourthChanceFission = 38,
     Dissapearance = 101,
     RadiativeCapture = 102,
 }
@@ -29,7 +29,7 @@ impl InterpolationRegion {
         }
 
         // Zip the x and y vectors together into a vector of XY structs
-        let data = x.into_iter().zip(y.into_iter()).map(|(x, y)| XY { x, y }).collect();
+        let data = x.into_iter().zip(y).map(|(x, y)| XY { x, y }).collect();
 
         Self { data, interpolation_scheme }
     }
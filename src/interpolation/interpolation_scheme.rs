@@ -1,3 +1,6 @@
+use crate::pace_error::PaceError;
+use crate::interpolation::InterpolationError;
+
 //=====================================================================
 // Enum for possible interpolation schemes from ENDF standard.
 //=====================================================================
@@ -11,20 +14,62 @@ pub enum InterpolationScheme {
     Gamow = 6,
 }
 
-impl From<usize> for InterpolationScheme {
-    fn from(value: usize) -> Self {
+impl TryFrom<usize> for InterpolationScheme {
+    type Error = PaceError;
+
+    fn try_from(value: usize) -> Result<Self, Self::Error> {
         match value {
-            1 => InterpolationScheme::Histogram,
-            2 => InterpolationScheme::LinLin,
-            3 => InterpolationScheme::LinLog,
-            4 => InterpolationScheme::LogLin,
-            5 => InterpolationScheme::LogLog,
-            6 => InterpolationScheme::Gamow,
-            _ => panic!("Invalid interpolation scheme"),
+            1 => Ok(InterpolationScheme::Histogram),
+            2 => Ok(InterpolationScheme::LinLin),
+            3 => Ok(InterpolationScheme::LinLog),
+            4 => Ok(InterpolationScheme::LogLin),
+            5 => Ok(InterpolationScheme::LogLog),
+            6 => Ok(InterpolationScheme::Gamow),
+            _ => Err(PaceError::unknown_code("interpolation scheme", value)),
         }
     }
 }
 
+impl InterpolationScheme {
+    // Interpolate between (x0, y0) and (x1, y1) at x_val. Gamow interpolation is not supported.
+    pub fn interpolate(&self, (x0, y0): (f64, f64), (x1, y1): (f64, f64), x_val: f64) -> Result<f64, InterpolationError> {
+        Ok(match self {
+            InterpolationScheme::Histogram => y0,
+            InterpolationScheme::LinLin => y0 + (y1 - y0) * (x_val - x0) / (x1 - x0),
+            InterpolationScheme::LinLog => y0 + (y1 - y0) * (x_val.log10() - x0.log10()) / (x1.log10() - x0.log10()),
+            InterpolationScheme::LogLin => y0 * ((x_val - x0) * (y1 / y0).ln() / (x1 - x0)).exp(),
+            InterpolationScheme::LogLog => y0 * ((x_val / x0).ln() * (y1 / y0).ln() / (x1 / x0).ln()).exp(),
+            InterpolationScheme::Gamow => return Err(InterpolationError::UnsupportedScheme(*self)),
+        })
+    }
+
+    // Integrate the interpolant between (x0, y0) and (x1, y1) over [x0, x1]. Each scheme is
+    // integrated exactly, so the result does not depend on how finely the interval is split.
+    // Gamow interpolation is not supported.
+    pub fn integrate(&self, (x0, y0): (f64, f64), (x1, y1): (f64, f64)) -> Result<f64, InterpolationError> {
+        let width = x1 - x0;
+        Ok(match self {
+            InterpolationScheme::Gamow => return Err(InterpolationError::UnsupportedScheme(*self)),
+            _ if width == 0.0 || y0 == y1 => y0 * width,
+            InterpolationScheme::Histogram => y0 * width,
+            InterpolationScheme::LinLin => 0.5 * (y0 + y1) * width,
+            // y = y0 + (y1 - y0) ln(x / x0) / ln(x1 / x0)
+            InterpolationScheme::LinLog => y0 * width + (y1 - y0) * (x1 - width / (x1 / x0).ln()),
+            // y = y0 exp(k (x - x0)) with k = ln(y1 / y0) / (x1 - x0)
+            InterpolationScheme::LogLin => (y1 - y0) * width / (y1 / y0).ln(),
+            // y = y0 (x / x0)^p with p = ln(y1 / y0) / ln(x1 / x0)
+            InterpolationScheme::LogLog => {
+                let exponent = (y1 / y0).ln() / (x1 / x0).ln();
+                if (exponent + 1.0).abs() < 1e-12 {
+                    y0 * x0 * (x1 / x0).ln()
+                } else {
+                    (y1 * x1 - y0 * x0) / (exponent + 1.0)
+                }
+            },
+        })
+    }
+}
+
 impl std::fmt::Display for InterpolationScheme {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -6,6 +6,8 @@ use thiserror::Error;
 
 use crate::interpolation::interpolation_region::{InterpolationRegion, XY};
 use crate::interpolation::InterpolationScheme;
+use crate::arrays::{XxsArray, XxsWords};
+use crate::pace_error::PaceError;
 
 //=====================================================================
 // An interpolation table contains a list of interpolation regions.
@@ -44,90 +46,39 @@ impl InterpolationTable {
         )
     }
 
-    pub fn process(data: &[f64]) -> Self {
-        // This function is meant to process an InteroilationTable from raw ACE input data
-        // First, get the number of interpolation regions
-        let num_interp_regions = data[0].to_bits() as usize;
-
-        // If the number of regions is zero, this means we use linear-linear interpolation
-        if num_interp_regions == 0 {
-            let num_data_points = data[1].to_bits() as usize;
-            let x_start = 2;
-            let y_start = x_start + num_data_points;
-
-            let x = data[x_start..y_start].to_vec();
-            let y = data[y_start..y_start + num_data_points].to_vec();
-
-            return Self::from_x_and_y(x, y, InterpolationScheme::LinLin);
-        }
-
-        // We have a list of interpolation parameters and schemes
-        // Split out raw data into interpolation bounds, regions, and xy data
-        let bounds_start = 1;
-        let schemes_start = bounds_start + num_interp_regions;
-        let schemes_end = schemes_start + num_interp_regions;
-        let num_data_points = data[schemes_end].to_bits() as usize;
-        let x_start = schemes_end + 1;
-        let y_start = x_start + num_data_points;
-
-        // Bounds, convert to zero-indexed for sanity
-        let bounds = std::iter::once(0)
-            .chain(data[bounds_start..schemes_start].iter().map(|&val| val.to_bits() as usize - 1));
-
-        // Schemes
-        let schemes = data[schemes_start..schemes_end]
-            .iter()
-            .map(|&val| InterpolationScheme::from(val.to_bits() as usize));
-
-        // Data points
-        let data_points = zip(
-            data[x_start..y_start].iter(), 
-            data[y_start..].iter()).map(|(x, y)| XY {
-                x: *x,
-                y: *y,
-                }
-        );
-
-        // Create interpolation regions
-        let regions = bounds.clone().zip(bounds.skip(1)).zip(schemes).map(|((start, end), scheme)| {
-            let region_data = data_points.clone().skip(start).take(end - start + 1);
-            InterpolationRegion {
-                data: region_data.collect(),
-                interpolation_scheme: scheme,
-            }
-        });
-
-        InterpolationTable(regions.collect())
+    pub fn process(data: XxsArray<'_>) -> Result<Self, PaceError> {
+        // This function is meant to process an InterpolationTable from raw ACE input data
+        InterpolationTableView::process(data).map(|view| Self::from(&view))
     }
 
-    pub fn get_table_length(table_start: usize, array_containing_table: &[f64]) -> usize {
+    pub fn get_table_length(table_start: usize, array_containing_table: XxsArray<'_>) -> Result<usize, PaceError> {
         let mut table_length = 0;
 
         // First, get the number of interpolation regions
-        let num_interp_regions = array_containing_table[table_start].to_bits() as usize;
+        let num_interp_regions = array_containing_table.uint(table_start)?;
         // If the number of regions is zero, this means we use linear-linear interpolation
         if num_interp_regions == 0 {
-            let num_data_points_per_vec = array_containing_table[table_start + 1].to_bits() as usize;
+            let num_data_points_per_vec = array_containing_table.uint(table_start + 1)?;
             table_length += 2 + 2 * num_data_points_per_vec;
         } else {
             // We have a list of interpolation parameters and schemes
             table_length += 1 + 2 * num_interp_regions;
-            let num_data_points_per_vec = array_containing_table[table_start + table_length].to_bits() as usize;
+            let num_data_points_per_vec = array_containing_table.uint(table_start + table_length)?;
             table_length += 1 + 2 * num_data_points_per_vec;
         }
-        table_length
+        Ok(table_length)
     }
 
     // Interpolate a value from the table
     pub fn interpolate(&self, x_val: f64) -> Result<f64, InterpolationError> {
         // Check if the table is valid
-        if self.len() < 1 {
+        if self.is_empty() {
             return Err(InterpolationError::InvalidTable());
         }
         // Find the region that x_val falls into
         let region = self.iter().find(|region| {
             region.data[0].x <= x_val && x_val <= region.data.iter().last().unwrap().x
-        }).ok_or_else(|| InterpolationError::RegionNotFound(x_val))?;
+        }).ok_or(InterpolationError::RegionNotFound(x_val))?;
 
         // Find the index of the bin that x_val falls into
         let idx = match region.data.binary_search_by(|xy| xy.x.partial_cmp(&x_val).unwrap()) {
@@ -141,21 +92,116 @@ impl InterpolationTable {
         let start = &region.data[idx];
         let end = region.data.get(idx + 1).unwrap();
 
-        // Here are the values we need for interpolation
-        let x0 = start.x;
-        let x1 = end.x;
-        let y0 = start.y;
-        let y1 = end.y;
-
         // Perform the interpolation
-        match &region.interpolation_scheme {
-            InterpolationScheme::Histogram => Ok(y0),
-            InterpolationScheme::LinLin => Ok(y0 + (y1 - y0) * (x_val - x0) / (x1 - x0)),
-            InterpolationScheme::LinLog => Ok(y0 + (y1 - y0) * (x_val.log10() - x0.log10()) / (x1.log10() - x0.log10())),
-            InterpolationScheme::LogLin => Ok(y0 * ((x_val - x0) * (y1 / y0).ln() / (x1 - x0)).exp()),
-            InterpolationScheme::LogLog => Ok(y0 * ((x_val / x0).ln() * (y1 / y0).ln() / (x1 / x0).ln()).exp()),
-            InterpolationScheme::Gamow => todo!("Gamow interpolation")
+        region.interpolation_scheme.interpolate((start.x, start.y), (end.x, end.y), x_val)
+    }
+}
+
+impl From<&InterpolationTableView<'_>> for InterpolationTable {
+    fn from(view: &InterpolationTableView<'_>) -> Self {
+        let regions = view.regions()
+            .map(|(start, end, interpolation_scheme)| {
+                let end = (end + 1).min(view.x.len());
+                let data = zip(&view.x[start.min(end)..end], &view.y[start.min(end)..end])
+                    .map(|(&x, &y)| XY { x, y })
+                    .collect();
+                InterpolationRegion { data, interpolation_scheme }
+            })
+            .collect();
+        Self(regions)
+    }
+}
+
+//=====================================================================
+// Borrowed view of an interpolation table, which holds slices of the
+// XXS array for the x and y values rather than copies of them. Only
+// the (short) lists of region boundaries and schemes are decoded.
+//=====================================================================
+#[derive(Debug, Clone, PartialEq)]
+pub struct InterpolationTableView<'a> {
+    // Index of the last point in each interpolation region
+    pub bounds: Vec<usize>,
+    pub schemes: Vec<InterpolationScheme>,
+    pub x: &'a [f64],
+    pub y: &'a [f64],
+}
+
+impl<'a> InterpolationTableView<'a> {
+    pub fn process(data: XxsArray<'a>) -> Result<Self, PaceError> {
+        // Make sure that the whole table is inside of the data before we start slicing it up
+        let table_length = InterpolationTable::get_table_length(0, data)?;
+        let data = data.slice(0, table_length)?;
+
+        // First, get the number of interpolation regions
+        let num_interp_regions = data.uint(0)?;
+
+        // If the number of regions is zero, this means we use linear-linear interpolation
+        if num_interp_regions == 0 {
+            let num_data_points = data.uint(1)?;
+            return Ok(Self {
+                bounds: vec![num_data_points.saturating_sub(1)],
+                schemes: vec![InterpolationScheme::LinLin],
+                x: data.floats(2, num_data_points)?,
+                y: data.floats(2 + num_data_points, num_data_points)?,
+            });
         }
+
+        // We have a list of interpolation parameters and schemes, followed by the xy data
+        let bounds_start = 1;
+        let schemes_start = bounds_start + num_interp_regions;
+        let schemes_end = schemes_start + num_interp_regions;
+        let num_data_points = data.uint(schemes_end)?;
+
+        // Bounds, convert to zero-indexed for sanity
+        let bounds = data.uints(bounds_start, num_interp_regions)?
+            .into_iter()
+            .map(|bound| bound.saturating_sub(1))
+            .collect();
+        let schemes = data.uints(schemes_start, num_interp_regions)?
+            .into_iter()
+            .map(InterpolationScheme::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            bounds,
+            schemes,
+            x: data.floats(schemes_end + 1, num_data_points)?,
+            y: data.floats(schemes_end + 1 + num_data_points, num_data_points)?,
+        })
+    }
+
+    // The first and last point of each region, and its interpolation scheme. Neighbouring regions
+    // share their boundary point.
+    fn regions(&self) -> impl Iterator<Item = (usize, usize, InterpolationScheme)> + '_ {
+        std::iter::once(0)
+            .chain(self.bounds.iter().copied())
+            .zip(&self.bounds)
+            .zip(&self.schemes)
+            .map(|((start, &end), &scheme)| (start, end, scheme))
+    }
+
+    // Interpolate a value from the table
+    pub fn interpolate(&self, x_val: f64) -> Result<f64, InterpolationError> {
+        if self.x.is_empty() {
+            return Err(InterpolationError::InvalidTable());
+        }
+        // Find the region that x_val falls into
+        let last = self.x.len() - 1;
+        let (start, end, scheme) = self.regions()
+            .map(|(start, end, scheme)| (start, end.min(last), scheme))
+            .find(|&(start, end, _)| start <= end && self.x[start] <= x_val && x_val <= self.x[end])
+            .ok_or(InterpolationError::RegionNotFound(x_val))?;
+        let (x, y) = (&self.x[start..=end], &self.y[start..=end]);
+
+        // Find the index of the bin that x_val falls into
+        let idx = match x.binary_search_by(|x| x.partial_cmp(&x_val).unwrap()) {
+            // We are exactly on a data point, exit early by returning the value
+            Ok(idx) => return Ok(y[idx]),
+            // We are inside a bin
+            Err(idx) => idx - 1,
+        };
+
+        scheme.interpolate((x[idx], y[idx]), (x[idx + 1], y[idx + 1]), x_val)
     }
 }
 
@@ -166,6 +212,9 @@ pub enum InterpolationError {
 
     #[error("Interpolation region for x={0} not found")]
     RegionNotFound(f64),
+
+    #[error("{0} interpolation is not supported")]
+    UnsupportedScheme(InterpolationScheme),
 }
 
 
@@ -174,6 +223,36 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn test_process_errors() {
+        // One region with an unknown interpolation scheme
+        let data = [1.0, 2.0, 9.0, 2.0, 1.0, 2.0, 3.0, 4.0];
+        assert!(matches!(InterpolationTable::process(XxsArray::untyped(&data)), Err(PaceError::UnknownCode { code: 9, .. })));
+
+        // Linear-linear table missing its last y value
+        let data = [0.0, 2.0, 1.0, 2.0, 3.0];
+        assert!(matches!(InterpolationTable::process(XxsArray::untyped(&data)), Err(PaceError::LocatorOutOfBounds { .. })));
+    }
+
+    #[test]
+    fn test_table_view() {
+        // Histogram up to the third point, then linear-linear
+        let data = [2.0, 3.0, 4.0, 1.0, 2.0, 4.0, 1.0, 2.0, 3.0, 4.0, 10.0, 20.0, 30.0, 40.0];
+        let view = InterpolationTableView::process(XxsArray::untyped(&data)).unwrap();
+        assert_eq!(view.bounds, vec![2, 3]);
+        assert_eq!(view.x.as_ptr(), data[6..].as_ptr());
+        assert_eq!(view.y, &data[10..]);
+
+        let table = InterpolationTable::process(XxsArray::untyped(&data)).unwrap();
+        assert_eq!(table, InterpolationTable::from(&view));
+        for x in [1.0, 1.5, 2.5, 3.0, 3.5, 4.0] {
+            assert_eq!(view.interpolate(x).unwrap(), table.interpolate(x).unwrap());
+        }
+        assert_eq!(view.interpolate(2.5).unwrap(), 20.0);
+        assert_eq!(view.interpolate(3.5).unwrap(), 35.0);
+        assert!(matches!(view.interpolate(5.0), Err(InterpolationError::RegionNotFound(_))));
+    }
+
     #[test]
     fn test_histogram_interpolation() {
         let table = InterpolationTable(vec![
@@ -201,6 +280,19 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_gamow_interpolation() {
+        let table = InterpolationTable::from_x_and_y(vec![1.0, 2.0], vec![2.0, 4.0], InterpolationScheme::Gamow);
+
+        // Gamow interpolation is reported as unsupported rather than panicking
+        assert!(matches!(table.interpolate(1.5), Err(InterpolationError::UnsupportedScheme(InterpolationScheme::Gamow))));
+        assert!(matches!(
+            InterpolationScheme::Gamow.integrate((1.0, 2.0), (2.0, 4.0)),
+            Err(InterpolationError::UnsupportedScheme(InterpolationScheme::Gamow))
+        ));
+        assert_eq!(InterpolationScheme::LinLin.integrate((1.0, 2.0), (2.0, 4.0)).unwrap(), 3.0);
+    }
+
     #[test]
     fn test_linlin_interpolation() {
         let table = InterpolationTable(vec![
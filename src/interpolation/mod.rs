@@ -3,4 +3,4 @@ mod interpolation_scheme;
 mod interpolation_table;
 
 pub use interpolation_scheme::InterpolationScheme;
-pub use interpolation_table::{InterpolationTable, InterpolationError};
\ No newline at end of file
+pub use interpolation_table::{InterpolationTable, InterpolationTableView, InterpolationError};
\ No newline at end of file
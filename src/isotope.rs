@@ -1,2 +1,192 @@
+use std::fmt;
+use std::str::FromStr;
+
+use thiserror::Error;
+
+use crate::arrays::NxsArray;
+use crate::header::TableClass;
+use crate::helpers;
+
+//=====================================================================
+// Identity of the nuclide (or element) a table is for, parsed from a
+// ZAID such as "92235.80c" or an SZAID such as "1100.800nc". The part
+// before the "." is the ZA (1000 Z + A, with A = 0 for elements), and
+// the part after it is the library ID followed by the class suffix.
+//
+// Metastable states are encoded in the ZA following the MCNP
+// convention of adding 300 + 100 m to A, so that Am242m1 is given as
+// 95642. Where this is ambiguous, the lowest state with a plausible
+// mass number (no more than 3 Z) is taken. Tables with a version 2.0
+// header give Z, A and the excited state directly in the NXS array,
+// see `from_nxs`.
+//=====================================================================
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct Isotope {
-}
\ No newline at end of file
+    pub z: usize,           // Atomic number
+    pub a: usize,           // Mass number, 0 for elemental tables
+    pub metastable: usize,  // Excited state, 0 for the ground state
+    pub library: String,    // Library ID, e.g. "80" or "800"
+    pub suffix: String,     // Class suffix, e.g. "c" or "nc"
+}
+
+// Offsets used to encode metastable states in the ZA
+const METASTABLE_OFFSET: usize = 300;
+const METASTABLE_STEP: usize = 100;
+
+impl Isotope {
+    // Parse a ZAID or SZAID
+    pub fn from_zaid(zaid: &str) -> Result<Self, IsotopeError> {
+        let invalid = || IsotopeError::InvalidIdentifier(zaid.to_string());
+        let (za, identifier) = zaid.trim().split_once('.').ok_or_else(invalid)?;
+        let za: usize = za.parse().map_err(|_| invalid())?;
+
+        // The library ID is numeric and the class suffix alphabetic
+        let suffix_start = identifier.find(|c: char| !c.is_ascii_digit()).ok_or_else(invalid)?;
+        let (library, suffix) = identifier.split_at(suffix_start);
+        if library.is_empty() || !suffix.chars().all(|c| c.is_ascii_alphabetic()) {
+            return Err(invalid());
+        }
+
+        let (z, encoded_a) = (za / 1000, za % 1000);
+        if z == 0 {
+            return Err(invalid());
+        }
+        let (a, metastable) = Self::decode_metastable(z, encoded_a);
+
+        Ok(Self { z, a, metastable, library: library.to_string(), suffix: suffix.to_string() })
+    }
+
+    // Identify the nuclide of a continuous-energy table from its ZAID and NXS array. Tables with a
+    // version 2.0 header give Z, A and the excited state in the NXS array, which are used in
+    // place of the values encoded in the ZAID.
+    pub fn from_nxs(zaid: &str, nxs: &NxsArray) -> Result<Self, IsotopeError> {
+        let mut isotope = Self::from_zaid(zaid)?;
+        if nxs.z != 0 {
+            isotope.z = nxs.z;
+            isotope.a = nxs.a;
+            isotope.metastable = nxs.s;
+        }
+        Ok(isotope)
+    }
+
+    // Split an encoded mass number into the mass number and metastable state
+    fn decode_metastable(z: usize, encoded_a: usize) -> (usize, usize) {
+        if encoded_a <= METASTABLE_OFFSET {
+            return (encoded_a, 0);
+        }
+
+        let excess = encoded_a - METASTABLE_OFFSET;
+        (1..=excess / METASTABLE_STEP)
+            .map(|metastable| (excess - METASTABLE_STEP * metastable, metastable))
+            .find(|&(a, _)| a <= 3 * z)
+            .unwrap_or((encoded_a, 0))
+    }
+
+    // ZA of the nuclide, without any metastable encoding
+    #[inline]
+    pub fn za(&self) -> usize {
+        1000 * self.z + self.a
+    }
+
+    // Whether the table is for a naturally occurring element rather than a single nuclide
+    #[inline]
+    pub fn is_elemental(&self) -> bool {
+        self.a == 0
+    }
+
+    // Element symbol
+    #[inline]
+    pub fn element(&self) -> String {
+        helpers::element_from_Z(self.z)
+    }
+
+    // Class of the table, from the last letter of the suffix
+    pub fn table_class(&self) -> Option<TableClass> {
+        TableClass::from_class_letter(self.suffix.chars().last()?)
+    }
+
+    // Display name, e.g. "U235", "Am242m1" or "C" for an elemental table
+    pub fn name(&self) -> String {
+        match (self.a, self.metastable) {
+            (0, _) => self.element(),
+            (a, 0) => helpers::isotope_name_from_Z_A(self.z, a),
+            (a, metastable) => format!("{}m{}", helpers::isotope_name_from_Z_A(self.z, a), metastable),
+        }
+    }
+}
+
+impl FromStr for Isotope {
+    type Err = IsotopeError;
+
+    fn from_str(zaid: &str) -> Result<Self, Self::Err> {
+        Self::from_zaid(zaid)
+    }
+}
+
+impl fmt::Display for Isotope {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[derive(Debug, Error, PartialEq)]
+pub enum IsotopeError {
+    #[error("Could not identify a nuclide from \"{0}\"")]
+    InvalidIdentifier(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::utils::get_parsed_test_file;
+
+    #[test]
+    fn test_zaid_parsing() {
+        let isotope: Isotope = "92235.80c".parse().unwrap();
+        assert_eq!((isotope.z, isotope.a, isotope.metastable), (92, 235, 0));
+        assert_eq!(isotope.library, "80");
+        assert_eq!(isotope.table_class(), Some(TableClass::ContinuousNeutron));
+        assert_eq!(isotope.to_string(), "U235");
+
+        let isotope = Isotope::from_zaid("1100.800nc").unwrap();
+        assert_eq!((isotope.z, isotope.a, isotope.library.as_str(), isotope.suffix.as_str()), (1, 100, "800", "nc"));
+
+        let isotope = Isotope::from_zaid("6000.14p").unwrap();
+        assert!(isotope.is_elemental());
+        assert_eq!(isotope.name(), "C");
+        assert_eq!(isotope.table_class(), Some(TableClass::Photoatomic));
+    }
+
+    #[test]
+    fn test_metastable_parsing() {
+        let isotope = Isotope::from_zaid("95642.80c").unwrap();
+        assert_eq!((isotope.z, isotope.a, isotope.metastable), (95, 242, 1));
+        assert_eq!(isotope.za(), 95242);
+        assert_eq!(isotope.name(), "Am242m1");
+
+        // A mass number of 150 is not plausible for Mn, so this is the second excited state of Mn50
+        let isotope = Isotope::from_zaid("25550.80c").unwrap();
+        assert_eq!((isotope.a, isotope.metastable), (50, 2));
+    }
+
+    #[test]
+    fn test_invalid_identifiers() {
+        for zaid in ["h-h2o.40t", "92235", "92235.c", "92235.80", "0.80c", "92235.80c!"] {
+            assert_eq!(Isotope::from_zaid(zaid), Err(IsotopeError::InvalidIdentifier(zaid.to_string())));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_isotope_from_nxs() {
+        let parsed_ace = get_parsed_test_file().await;
+        let isotope = Isotope::from_nxs(&parsed_ace.zaid(), &parsed_ace.nxs_array).unwrap();
+        assert_eq!(isotope.z, parsed_ace.z());
+        assert_eq!(isotope.a, parsed_ace.a());
+        assert_eq!(isotope.metastable, parsed_ace.nxs_array.s);
+
+        // The SZAID is preferred over the ZAID when it is available
+        let isotope = parsed_ace.isotope().unwrap();
+        assert_eq!((isotope.library.as_str(), isotope.suffix.as_str()), ("800", "nc"));
+    }
+}
@@ -1,15 +1,49 @@
 #![allow(non_snake_case, clippy::upper_case_acronyms)]
 
-mod angular_distributions;
-mod arrays;
-mod blocks;
-mod header;
-mod interpolation;
+mod ace_table;
+pub mod angular_distributions;
+pub mod arrays;
+pub mod blocks;
+pub mod dosimetry;
+pub mod energy_distributions;
+pub mod header;
+pub mod interpolation;
+pub mod library;
+pub mod photoatomic;
+pub mod electron;
+pub mod photonuclear;
+pub mod thermal;
 mod pace_data;
+mod pace_view;
+mod pace_error;
 mod utils;
 
 mod isotope;
-mod helpers;
+pub mod helpers;
 mod unitf64;
 
-pub use isotope::Isotope;
\ No newline at end of file
+pub use ace_table::AceTable;
+pub use isotope::{Isotope, IsotopeError};
+pub use library::Library;
+pub use dosimetry::DosimetryData;
+pub use pace_data::{PaceData, LazyPaceData};
+pub use pace_view::PaceView;
+pub use pace_error::PaceError;
+pub use photoatomic::PhotoatomicData;
+pub use electron::ElectronData;
+pub use photonuclear::PhotonuclearData;
+pub use thermal::ThermalScatteringData;
+pub use unitf64::UnitF64;
+pub use utils::{
+    convert_ACE_to_PACE,
+    convert_ACE_to_PACE_at,
+    convert_all_ACE_to_PACE,
+    convert_type2_ACE_to_PACE,
+    convert_all_type2_ACE_to_PACE,
+    migrate_PACE,
+    is_pace_file,
+    source_checksum,
+    PaceMmap,
+    SectionOffsets,
+    PACE_FORMAT_VERSION,
+};
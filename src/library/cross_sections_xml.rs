@@ -0,0 +1,207 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+
+use crate::library::{Xsdir, XsdirEntry};
+
+//=====================================================================
+// Support for OpenMC cross_sections.xml listings of ACE tables, as used
+// by OpenMC before it moved to HDF5 data. These locate each table in
+// the same way as an xsdir file:
+//
+//    <cross_sections>
+//      <directory>/data/endf71x</directory>
+//      <filetype>ascii</filetype>
+//      <ace_table name="1001.71c" alias="H-1.71c" zaid="1001" awr="0.999167"
+//                 location="1" path="H/1001.71c" temperature="2.5301e-08"/>
+//    </cross_sections>
+//
+// The filetype ("ascii" or "binary"), record_length and entries may be
+// given for the whole listing or on each table. Paths are relative to
+// the directory if it is given, and otherwise to the directory holding
+// the listing. Listings are read into an Xsdir, with each table under
+// both its name and its alias. Newer listings of HDF5 files can not be
+// converted to PACE, so they are rejected.
+//=====================================================================
+impl Xsdir {
+    pub fn from_cross_sections_xml<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read cross sections listing {}", path.display()))?;
+        let directory = path.parent().unwrap_or(Path::new("."));
+        Self::parse_cross_sections_xml(&content, directory)
+    }
+
+    // Parse the contents of a cross_sections.xml listing, with relative paths resolved against
+    // `directory` unless the listing gives its own
+    pub fn parse_cross_sections_xml(content: &str, directory: &Path) -> Result<Self> {
+        let content = strip_comments(content);
+        let tables = elements(&content, "ace_table");
+        if tables.is_empty() && !elements(&content, "library").is_empty() {
+            return Err(anyhow::anyhow!("Cross sections listing holds HDF5 libraries, which can not be converted to PACE"));
+        }
+
+        // Defaults for every table in the listing
+        let datapath = text_element(&content, "directory").map(PathBuf::from);
+        let defaults: HashMap<String, String> = ["filetype", "record_length", "entries"]
+            .into_iter()
+            .filter_map(|tag| Some((tag.to_string(), text_element(&content, tag)?)))
+            .collect();
+
+        let mut xsdir = Self { datapath, ..Self::default() };
+        for table in tables {
+            let attributes = parse_attributes(table)
+                .with_context(|| format!("Invalid ace_table element \"{}\"", table.trim()))?;
+            let entry = xsdir.parse_ace_table(&attributes, &defaults, directory)
+                .with_context(|| format!("Invalid ace_table element \"{}\"", table.trim()))?;
+            if let Some(alias) = attributes.get("alias").filter(|alias| !alias.is_empty()) {
+                xsdir.entries.insert(alias.clone(), entry.clone());
+            }
+            xsdir.entries.insert(entry.name.clone(), entry);
+        }
+        Ok(xsdir)
+    }
+
+    fn parse_ace_table(&self, attributes: &HashMap<String, String>, defaults: &HashMap<String, String>, directory: &Path) -> Result<XsdirEntry> {
+        let attribute = |key: &str| attributes.get(key).or_else(|| defaults.get(key)).map(String::as_str);
+        let required = |key: &str| attribute(key).ok_or_else(|| anyhow::anyhow!("Missing {} attribute", key));
+        let optional = |key: &str| attribute(key).map_or(Ok(0), |value| value.parse::<usize>());
+
+        let file_type = match attribute("filetype") {
+            None | Some("ascii") => 1,
+            Some("binary") => 2,
+            Some(file_type) => return Err(anyhow::anyhow!("Unknown filetype \"{}\"", file_type)),
+        };
+
+        Ok(XsdirEntry {
+            name: required("name")?.to_string(),
+            awr: required("awr")?.parse()?,
+            path: self.datapath.as_deref().unwrap_or(directory).join(required("path")?),
+            file_type,
+            address: attribute("location").map_or(Ok(1), |location| location.parse())?,
+            // The listing does not give the table length
+            table_length: 0,
+            record_length: optional("record_length")?,
+            entries_per_record: optional("entries")?,
+            kT: attribute("temperature").map_or(Ok(0.0), |kT| kT.parse())?,
+            ptable: false,
+        })
+    }
+}
+
+// Remove the comments from an XML document
+fn strip_comments(content: &str) -> String {
+    let mut stripped = String::with_capacity(content.len());
+    let mut rest = content;
+    while let Some(start) = rest.find("<!--") {
+        stripped.push_str(&rest[..start]);
+        rest = rest[start..].find("-->").map_or("", |end| &rest[start + end + 3..]);
+    }
+    stripped.push_str(rest);
+    stripped
+}
+
+// The attribute text of every element with the name `tag`, i.e. everything between the name and
+// the closing ">" or "/>"
+fn elements<'a>(content: &'a str, tag: &str) -> Vec<&'a str> {
+    let opening = format!("<{}", tag);
+    content
+        .match_indices(&opening)
+        .map(|(start, _)| &content[start + opening.len()..])
+        .filter(|rest| rest.starts_with(|c: char| c.is_whitespace() || c == '/' || c == '>'))
+        .map(|rest| {
+            let element = &rest[..rest.find('>').unwrap_or(rest.len())];
+            element.strip_suffix('/').unwrap_or(element)
+        })
+        .collect()
+}
+
+// Text content of the first element with the name `tag`, such as <directory>/data</directory>
+fn text_element(content: &str, tag: &str) -> Option<String> {
+    let start = content.find(&format!("<{}>", tag))? + tag.len() + 2;
+    let end = start + content[start..].find(&format!("</{}>", tag))?;
+    Some(unescape(content[start..end].trim()))
+}
+
+// Attributes of an element, given as name="value" or name='value'
+fn parse_attributes(element: &str) -> Result<HashMap<String, String>> {
+    let mut attributes = HashMap::new();
+    let mut rest = element.trim_start();
+    while !rest.is_empty() {
+        let (name, value) = rest.split_once('=').ok_or_else(|| anyhow::anyhow!("Expected name=\"value\" at \"{}\"", rest))?;
+        let value = value.trim_start();
+        let quote = value.chars().next().filter(|&c| c == '"' || c == '\'')
+            .ok_or_else(|| anyhow::anyhow!("Attribute {} is not quoted", name.trim()))?;
+        let end = value[1..].find(quote).ok_or_else(|| anyhow::anyhow!("Attribute {} is not closed", name.trim()))? + 1;
+        attributes.insert(name.trim().to_string(), unescape(&value[1..end]));
+        rest = value[end + 1..].trim_start();
+    }
+    Ok(attributes)
+}
+
+// Replace the predefined XML entities
+fn unescape(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CROSS_SECTIONS_XML: &str = concat!(
+        "<?xml version='1.0' encoding='utf-8'?>\n",
+        "<cross_sections>\n",
+        "  <!-- <ace_table name=\"commented.80c\" awr=\"1.0\" path=\"none\"/> -->\n",
+        "  <directory>/data/endf71x</directory>\n",
+        "  <filetype>ascii</filetype>\n",
+        "  <ace_table alias=\"H-1.71c\" awr=\"0.999167\" location=\"1\" name=\"1001.71c\"\n",
+        "             path=\"H/1001.71c\" temperature=\"2.5301e-08\" zaid=\"1001\"/>\n",
+        "  <ace_table alias=\"\" awr='11.8969' location='3' name='6000.50c' path='rmccsa'\n",
+        "             filetype='binary' record_length='512' entries='512' temperature='2.5301e-08' zaid='6000' />\n",
+        "</cross_sections>\n",
+    );
+
+    #[test]
+    fn test_cross_sections_xml_parsing() {
+        let xsdir = Xsdir::parse_cross_sections_xml(CROSS_SECTIONS_XML, Path::new("/unused")).unwrap();
+        assert_eq!(xsdir.datapath, Some(PathBuf::from("/data/endf71x")));
+        assert_eq!(xsdir.entries.len(), 3);
+
+        let hydrogen = xsdir.get("1001.71c").unwrap();
+        assert_eq!(hydrogen.path, PathBuf::from("/data/endf71x/H/1001.71c"));
+        assert_eq!((hydrogen.file_type, hydrogen.address), (1, 1));
+        assert!((hydrogen.awr - 0.999167).abs() < 1e-12);
+        assert!((hydrogen.temperature() - 293.6059).abs() < 1e-3);
+        assert_eq!(xsdir.get("H-1.71c"), Some(hydrogen));
+
+        // Type-2 binary table, with the file type overridden on the table
+        let carbon = xsdir.get("6000.50c").unwrap();
+        assert_eq!((carbon.file_type, carbon.address, carbon.record_length, carbon.entries_per_record), (2, 3, 512, 512));
+
+        assert!(xsdir.get("commented.80c").is_none());
+    }
+
+    #[test]
+    fn test_cross_sections_xml_errors() {
+        let xsdir = Xsdir::parse_cross_sections_xml(
+            "<cross_sections><ace_table name=\"1001.71c\" awr=\"1.0\" path=\"H/1001.71c\"/></cross_sections>",
+            Path::new("/library"),
+        ).unwrap();
+        assert_eq!(xsdir.get("1001.71c").unwrap().path, PathBuf::from("/library/H/1001.71c"));
+
+        // Missing attributes and unknown file types
+        assert!(Xsdir::parse_cross_sections_xml("<ace_table name=\"1001.71c\" awr=\"1.0\"/>", Path::new("/library")).is_err());
+        assert!(Xsdir::parse_cross_sections_xml(
+            "<ace_table name=\"1001.71c\" awr=\"1.0\" path=\"H\" filetype=\"hdf5\"/>", Path::new("/library")
+        ).is_err());
+
+        // Listings of HDF5 files
+        let hdf5 = "<cross_sections><library materials=\"H1\" path=\"H1.h5\" type=\"neutron\"/></cross_sections>";
+        assert!(Xsdir::parse_cross_sections_xml(hdf5, Path::new("/library")).is_err());
+    }
+}
@@ -0,0 +1,7 @@
+mod xsdir;
+mod cross_sections_xml;
+mod nuclide_library;
+
+// Structs
+pub use xsdir::{Xsdir, XsdirEntry};
+pub use nuclide_library::Library;
@@ -0,0 +1,181 @@
+use std::error::Error;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use dashmap::DashMap;
+use tokio::sync::OnceCell;
+
+use crate::{AceTable, PaceData};
+use crate::library::{Xsdir, XsdirEntry};
+use crate::utils::{convert_table_to_PACE, PaceMmap};
+
+type LibraryError = Box<dyn Error + Send + Sync>;
+
+//=====================================================================
+// A data library described by an xsdir file, or by an OpenMC
+// cross_sections.xml listing of ACE tables. Tables are looked up by
+// their ZAID, converted to PACE files in a cache directory the first
+// time they are needed and reused from there afterwards. A cached PACE
+// file is converted again if the ACE file it came from is newer.
+// Parsed continuous-energy tables are also kept in memory.
+//
+// Converting and parsing tables is done on the blocking thread pool.
+// Each ZAID has its own cell in the table cache, so lookups of
+// different tables do not wait on each other, while concurrent lookups
+// of the same table share a single load.
+//=====================================================================
+pub struct Library {
+    pub xsdir: Xsdir,
+    cache_directory: PathBuf,
+    tables: DashMap<String, Arc<OnceCell<Arc<PaceData>>>>,
+}
+
+impl Library {
+    pub fn new<P: AsRef<Path>, Q: AsRef<Path>>(xsdir_path: P, cache_directory: Q) -> Result<Self, LibraryError> {
+        Self::from_xsdir(Xsdir::from_file(xsdir_path)?, cache_directory)
+    }
+
+    // Library described by an OpenMC cross_sections.xml listing of ACE tables
+    pub fn from_cross_sections_xml<P: AsRef<Path>, Q: AsRef<Path>>(listing_path: P, cache_directory: Q) -> Result<Self, LibraryError> {
+        Self::from_xsdir(Xsdir::from_cross_sections_xml(listing_path)?, cache_directory)
+    }
+
+    pub fn from_xsdir<Q: AsRef<Path>>(xsdir: Xsdir, cache_directory: Q) -> Result<Self, LibraryError> {
+        let cache_directory = cache_directory.as_ref().to_path_buf();
+        std::fs::create_dir_all(&cache_directory)?;
+        Ok(Self { xsdir, cache_directory, tables: DashMap::new() })
+    }
+
+    // Whether the library has a table
+    #[inline]
+    pub fn contains(&self, zaid: &str) -> bool {
+        self.xsdir.entries.contains_key(zaid)
+    }
+
+    // Directory entry of a table
+    fn entry(&self, zaid: &str) -> Result<&XsdirEntry, LibraryError> {
+        self.xsdir.get(zaid)
+            .ok_or_else(|| format!("Table {} is not in the library", zaid).into())
+    }
+
+    // Path of the PACE file for a table in the cache directory
+    fn cached_path(&self, zaid: &str) -> PathBuf {
+        self.cache_directory.join(format!("{}.pace", zaid))
+    }
+
+    // Path of the PACE file for a table, converting the table first if there is no up to date
+    // PACE file in the cache directory
+    pub fn pace_path(&self, zaid: &str) -> Result<PathBuf, LibraryError> {
+        let pace_path = self.cached_path(zaid);
+        update_cached_PACE(self.entry(zaid)?, &pace_path)?;
+        Ok(pace_path)
+    }
+
+    // Continuous-energy table for a ZAID, such as "92235.80c"
+    pub async fn get(&self, zaid: &str) -> Result<Arc<PaceData>, LibraryError> {
+        // Unknown tables are rejected before a cell is made for them. The map is only locked for
+        // long enough to find the cell of the table, not while the table is loaded.
+        self.entry(zaid)?;
+        let cell = self.tables.entry(zaid.to_string()).or_default().clone();
+        let table = cell
+            .get_or_try_init(|| self.load(zaid, |pace_path| PaceData::open(pace_path).map(Arc::new)))
+            .await?;
+        Ok(table.clone())
+    }
+
+    // Table of any class for a ZAID. These are not kept in memory.
+    pub async fn get_table(&self, zaid: &str) -> Result<AceTable, LibraryError> {
+        self.load(zaid, |pace_path| AceTable::from_file(pace_path)).await
+    }
+
+    // Bring the cached PACE file of a table up to date and parse it with `parse`, on the blocking
+    // thread pool
+    async fn load<T, F>(&self, zaid: &str, parse: F) -> Result<T, LibraryError>
+    where
+        T: Send + 'static,
+        F: FnOnce(&Path) -> Result<T, LibraryError> + Send + 'static,
+    {
+        let entry = self.entry(zaid)?.clone();
+        let pace_path = self.cached_path(zaid);
+        tokio::task::spawn_blocking(move || {
+            update_cached_PACE(&entry, &pace_path)?;
+            parse(&pace_path)
+        }).await?
+    }
+}
+
+// Convert a table to its PACE file, unless the PACE file is already up to date
+fn update_cached_PACE(entry: &XsdirEntry, pace_path: &Path) -> Result<(), LibraryError> {
+    // Reuse the cached PACE file unless the ACE file has changed since it was converted, or
+    // it was written with a version of the format which can no longer be read
+    let is_up_to_date = match (std::fs::metadata(pace_path), std::fs::metadata(&entry.path)) {
+        (Ok(pace), Ok(ace)) => pace.modified()? >= ace.modified()? && PaceMmap::from_file(pace_path).is_ok(),
+        (Ok(_), Err(_)) => PaceMmap::from_file(pace_path).is_ok(),
+        (Err(_), _) => false,
+    };
+    if is_up_to_date {
+        return Ok(());
+    }
+
+    // Convert to a temporary file first, so that a PACE file is never seen half written
+    let cache_directory = pace_path.parent().unwrap_or(Path::new("."));
+    let temporary_file = tempfile::NamedTempFile::new_in(cache_directory)?;
+    convert_table_to_PACE(&entry.path, entry.file_type, entry.address, temporary_file.path())?;
+    temporary_file.persist(pace_path)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use tempfile::tempdir;
+
+    use crate::utils::{get_parsed_test_file, get_parsed_dosimetry_test_file};
+
+    #[tokio::test]
+    async fn test_library_lookup() {
+        // Make sure the uncommented test files exist
+        let _ = get_parsed_test_file().await;
+        let _ = get_parsed_dosimetry_test_file().await;
+
+        let directory = tempdir().unwrap();
+        let xsdir_path = directory.path().join("xsdir");
+        let data_path = std::fs::canonicalize("test_nuclear_data_files").unwrap();
+        std::fs::write(&xsdir_path, format!(
+            "datapath={}\ndirectory\n 1100.00c 99.999 test_ascii_ace.no_comment 0 1 1 500 0 0 2.5301E-08\n \
+            13027.00y 26.74975 test_dosimetry_ace.no_comment 0 1 1 22 +\n 0 0 2.5301E-08\n",
+            data_path.display()
+        )).unwrap();
+
+        let library = Library::new(&xsdir_path, directory.path().join("cache")).unwrap();
+        assert!(library.contains("1100.00c"));
+
+        // The table is converted on the first lookup and cached afterwards
+        let table = library.get("1100.00c").await.unwrap();
+        assert_eq!(table.szaid(), Some(String::from("1100.800nc")));
+        assert!(directory.path().join("cache/1100.00c.pace").exists());
+        assert!(Arc::ptr_eq(&table, &library.get("1100.00c").await.unwrap()));
+
+        // Concurrent lookups of a table which is not loaded yet share a single load
+        let library = Library::new(&xsdir_path, directory.path().join("cache")).unwrap();
+        let (first, second) = tokio::join!(library.get("1100.00c"), library.get("1100.00c"));
+        assert!(Arc::ptr_eq(&first.unwrap(), &second.unwrap()));
+
+        match library.get_table("13027.00y").await.unwrap() {
+            AceTable::Dosimetry(data) => assert_eq!(data.zaid(), String::from("13027.00y")),
+            _ => panic!("Expected a dosimetry table"),
+        }
+        assert!(library.get("92235.80c").await.is_err());
+
+        // The same table located through a cross_sections.xml listing
+        let listing_path = directory.path().join("cross_sections.xml");
+        std::fs::write(&listing_path, format!(
+            "<cross_sections>\n  <directory>{}</directory>\n  \
+            <ace_table name=\"1100.00c\" alias=\"H100.00c\" awr=\"99.999\" path=\"test_ascii_ace.no_comment\"/>\n</cross_sections>\n",
+            data_path.display()
+        )).unwrap();
+        let library = Library::from_cross_sections_xml(&listing_path, directory.path().join("listing_cache")).unwrap();
+        assert_eq!(library.get("H100.00c").await.unwrap().szaid(), Some(String::from("1100.800nc")));
+    }
+}
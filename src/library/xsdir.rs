@@ -0,0 +1,188 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+
+use crate::utils;
+
+//=====================================================================
+// Support for MCNP xsdir files, which locate each table of a data
+// library. An xsdir file holds an optional "datapath" line, a section
+// of atomic weight ratios and a "directory" section with one entry
+// per table. A "+" at the end of a line continues an entry on the
+// next line.
+//
+// Each directory entry is given as:
+//    name, awr, file name, access route, file type, address,
+//    table length, record length, entries per record, kT, ptable
+// where only the first seven are required. File names are relative to
+// the access route if it is not 0, then to the datapath if it is
+// given, and otherwise to the directory holding the xsdir file.
+//=====================================================================
+#[derive(Clone, Debug, PartialEq)]
+pub struct XsdirEntry {
+    pub name: String,               // ZAID of the table
+    pub awr: f64,                   // Atomic weight ratio
+    pub path: PathBuf,              // Resolved path of the file holding the table
+    pub file_type: usize,           // 1 for ASCII (Type-1) tables, 2 for binary (Type-2) tables
+    pub address: usize,             // Starting line (Type-1) or record (Type-2) of the table
+    pub table_length: usize,        // Length of the XXS array
+    pub record_length: usize,       // Record length (Type-2 only)
+    pub entries_per_record: usize,  // Number of entries per record (Type-2 only)
+    pub kT: f64,                    // Temperature in MeV
+    pub ptable: bool,               // Whether the table has unresolved resonance probability tables
+}
+
+impl XsdirEntry {
+    // Temperature in Kelvin
+    #[inline]
+    pub fn temperature(&self) -> f64 {
+        utils::compute_temperature_from_kT(self.kT)
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct Xsdir {
+    pub datapath: Option<PathBuf>,
+    pub atomic_weight_ratios: HashMap<usize, f64>,
+    pub entries: HashMap<String, XsdirEntry>,
+}
+
+impl Xsdir {
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read xsdir file {}", path.display()))?;
+        let directory = path.parent().unwrap_or(Path::new("."));
+        Self::parse(&content, directory)
+    }
+
+    // Parse the contents of an xsdir file, with relative file names resolved against `directory`
+    // unless a datapath is given
+    pub fn parse(content: &str, directory: &Path) -> Result<Self> {
+        let mut xsdir = Self::default();
+        let mut lines = content.lines().peekable();
+
+        // The optional datapath comes before anything else
+        if let Some(line) = lines.next_if(|line| line.trim_start().to_lowercase().starts_with("datapath")) {
+            let datapath = line.split_once('=').map_or("", |(_, value)| value).trim();
+            xsdir.datapath = Some(PathBuf::from(datapath));
+        }
+
+        // Atomic weight ratios, given as pairs of ZA and AWR
+        let mut in_directory = false;
+        let mut entry_tokens: Vec<&str> = Vec::new();
+        for line in lines {
+            if !in_directory {
+                let lowercase = line.trim().to_lowercase();
+                if lowercase.starts_with("directory") {
+                    in_directory = true;
+                } else if !lowercase.starts_with("atomic weight ratios") {
+                    let tokens: Vec<&str> = line.split_whitespace().collect();
+                    for pair in tokens.chunks(2) {
+                        if let [za, awr] = pair {
+                            xsdir.atomic_weight_ratios.insert(za.parse()?, awr.parse()?);
+                        }
+                    }
+                }
+                continue;
+            }
+
+            // Directory entries, which may be continued on the next line
+            let tokens: Vec<&str> = line.split_whitespace().collect();
+            let continued = tokens.last() == Some(&"+");
+            entry_tokens.extend(tokens.iter().filter(|&&token| token != "+"));
+            if !continued && !entry_tokens.is_empty() {
+                let entry = xsdir.parse_entry(&entry_tokens, directory)
+                    .with_context(|| format!("Invalid xsdir entry \"{}\"", entry_tokens.join(" ")))?;
+                xsdir.entries.insert(entry.name.clone(), entry);
+                entry_tokens.clear();
+            }
+        }
+
+        Ok(xsdir)
+    }
+
+    fn parse_entry(&self, tokens: &[&str], directory: &Path) -> Result<XsdirEntry> {
+        if tokens.len() < 7 {
+            return Err(anyhow::anyhow!("Expected at least 7 fields, found {}", tokens.len()));
+        }
+        let optional = |index: usize| tokens.get(index).map_or(Ok(0), |token| token.parse::<usize>());
+
+        // Resolve the location of the file holding the table
+        let path = match tokens[3] {
+            "0" => self.datapath.as_deref().unwrap_or(directory).join(tokens[2]),
+            access_route => Path::new(access_route).join(tokens[2]),
+        };
+
+        Ok(XsdirEntry {
+            name: tokens[0].to_string(),
+            awr: tokens[1].parse()?,
+            path,
+            file_type: tokens[4].parse()?,
+            address: tokens[5].parse()?,
+            table_length: tokens[6].parse()?,
+            record_length: optional(7)?,
+            entries_per_record: optional(8)?,
+            kT: tokens.get(9).map_or(Ok(0.0), |token| token.parse::<f64>())?,
+            ptable: tokens.get(10).is_some_and(|token| token.eq_ignore_ascii_case("ptable")),
+        })
+    }
+
+    // Directory entry of a table
+    #[inline]
+    pub fn get(&self, name: &str) -> Option<&XsdirEntry> {
+        self.entries.get(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const XSDIR: &str = concat!(
+        "DATAPATH=/data/xs\n",
+        "atomic weight ratios\n",
+        "   1000   0.999167  1001   0.999167\n",
+        "  92235 233.024800\n",
+        "directory\n",
+        " 1001.80c 0.999167 Lib80x/H/1001.800nc 0 1 1 2389 0 0 2.5301E-08 ptable\n",
+        " 92235.80c 233.0248 endf71x/U/92235.710nc /other 1 4 +\n",
+        "   1226058 0 0 2.5301E-08\n",
+        " 6000.50c 11.8969 rmccsa 0 2 3 11004 512 512 2.5301E-08\n",
+    );
+
+    #[test]
+    fn test_xsdir_parsing() {
+        let xsdir = Xsdir::parse(XSDIR, Path::new("/unused")).unwrap();
+        assert_eq!(xsdir.datapath, Some(PathBuf::from("/data/xs")));
+        assert_eq!(xsdir.atomic_weight_ratios.len(), 3);
+        assert_eq!(xsdir.atomic_weight_ratios[&92235], 233.0248);
+        assert_eq!(xsdir.entries.len(), 3);
+
+        let hydrogen = xsdir.get("1001.80c").unwrap();
+        assert_eq!(hydrogen.path, PathBuf::from("/data/xs/Lib80x/H/1001.800nc"));
+        assert_eq!((hydrogen.file_type, hydrogen.address, hydrogen.table_length), (1, 1, 2389));
+        assert!(hydrogen.ptable);
+        assert!((hydrogen.temperature() - 293.6059).abs() < 1e-3);
+
+        // Continued on the next line, with an access route
+        let uranium = xsdir.get("92235.80c").unwrap();
+        assert_eq!(uranium.path, PathBuf::from("/other/endf71x/U/92235.710nc"));
+        assert_eq!((uranium.address, uranium.table_length), (4, 1226058));
+        assert!(!uranium.ptable);
+
+        // Type-2 binary table
+        let carbon = xsdir.get("6000.50c").unwrap();
+        assert_eq!((carbon.file_type, carbon.address, carbon.record_length, carbon.entries_per_record), (2, 3, 512, 512));
+    }
+
+    #[test]
+    fn test_xsdir_relative_paths() {
+        let xsdir = Xsdir::parse("directory\n 1001.80c 0.999167 H/1001.800nc 0 1 1 2389\n", Path::new("/library")).unwrap();
+        let hydrogen = xsdir.get("1001.80c").unwrap();
+        assert_eq!(hydrogen.path, PathBuf::from("/library/H/1001.800nc"));
+        assert_eq!(hydrogen.kT, 0.0);
+        assert!(Xsdir::parse("directory\n 1001.80c 0.999167 H/1001.800nc\n", Path::new("/library")).is_err());
+    }
+}
@@ -1,11 +1,15 @@
 use std::path::Path;
 use std::error::Error;
 
-use crate::utils::{is_ascii_file, PaceMmap};
+use futures::stream::{self, StreamExt};
+
+use crate::utils::{convert_table_to_PACE, ensure_not_ascii, is_ascii_file, PaceMmap};
 use crate::header::Header;
 use crate::arrays::{IzawArray, JxsArray, NxsArray};
-use crate::blocks::DataBlocks;
-use crate::helpers;
+use crate::blocks::{DataBlocks, LazyDataBlocks, ParticleType};
+use crate::helpers::{self, MTNumber};
+use crate::isotope::{Isotope, IsotopeError};
+use crate::pace_error::PaceError;
 
 #[derive(Clone)]
 pub struct PaceData {
@@ -18,40 +22,83 @@ pub struct PaceData {
 
 impl PaceData {
     pub async fn from_file<P: AsRef<Path>>(file_path: P) -> Result<Self, Box<dyn Error>> {
+        Self::open(file_path).map_err(|error| error as Box<dyn Error>)
+    }
+
+    // Synchronous version of from_file, which does not need an async runtime
+    pub fn open<P: AsRef<Path>>(file_path: P) -> Result<Self, Box<dyn Error + Send + Sync>> {
         let path = file_path.as_ref();
 
-        // If we have an ASCII file, request that it first be parsed to our own binary format
-        // using crate::ace::binary_format::convert_ascii_to_binary
-        if is_ascii_file(path)? {
-            return Err(
-                format!(
-                    "File {} is ASCII, this should first be converted to binary format with \
-                    crate::ace::binary_format::convert_ascii_to_binary", path.display()).into()
-            )
-        }
+        // If we have an ASCII file, request that it first be converted to our own binary format
+        ensure_not_ascii(path)?;
 
         // We have a binary file, so we can proceed with parsing it
         // Create a memory map of the binary file
-        let mmap = PaceMmap::from_file(path)?;
+        Self::from_PACE(&PaceMmap::from_file(path)?)
+    }
 
+    // Parse the table from a memory map of a PACE file
+    pub fn from_PACE(mmap: &PaceMmap) -> Result<Self, Box<dyn Error + Send + Sync>> {
         // Process the header
-        let header = Header::from_PACE(&mmap)?;
+        let header = Header::from_PACE(mmap)?;
 
         // Process the IZAW array
-        let izaw_array = IzawArray::from_PACE(&mmap)?;
+        let izaw_array = IzawArray::from_PACE(mmap)?;
 
         // Process the NXS array
-        let nxs_array = NxsArray::from_PACE(&mmap)?;
+        let nxs_array = NxsArray::from_PACE(mmap)?;
 
         // Process the JXS array
-        let jxs_array = JxsArray::from_PACE(&mmap)?;
+        let jxs_array = JxsArray::from_PACE(mmap)?;
 
         // Process the blocks out of the XXS array
-        let data_blocks = DataBlocks::from_PACE(&mmap, &nxs_array, &jxs_array)?;
+        let data_blocks = DataBlocks::from_PACE(mmap, &header, &nxs_array, &jxs_array)?;
 
         Ok(Self { header, izaw_array, nxs_array, jxs_array, data_blocks})
     }
 
+    // Load many files concurrently, with as many files in flight as there are available threads.
+    // See load_many_bounded.
+    pub async fn load_many<I, P>(paths: I) -> Vec<Result<Self, Box<dyn Error + Send + Sync>>>
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<Path>,
+    {
+        let max_concurrent = std::thread::available_parallelism().map_or(1, |threads| threads.get());
+        Self::load_many_bounded(paths, max_concurrent).await
+    }
+
+    // Load many files on the blocking thread pool, with at most `max_concurrent` files in flight at
+    // once. ASCII ACE files are first converted to temporary PACE files, so nothing is written next
+    // to them. The results are in the same order as the paths, and a file which fails to load does
+    // not stop the others.
+    pub async fn load_many_bounded<I, P>(paths: I, max_concurrent: usize) -> Vec<Result<Self, Box<dyn Error + Send + Sync>>>
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<Path>,
+    {
+        let loads = paths.into_iter().map(|path| {
+            let path = path.as_ref().to_path_buf();
+            async move {
+                tokio::task::spawn_blocking(move || Self::load(&path))
+                    .await
+                    .unwrap_or_else(|error| Err(error.into()))
+            }
+        });
+        stream::iter(loads).buffered(max_concurrent.max(1)).collect().await
+    }
+
+    // Convert an ASCII ACE file to a temporary PACE file if needed, then parse the PACE file. The
+    // parsed data does not borrow the file, so the temporary file is removed once it is parsed.
+    fn load(path: &Path) -> Result<Self, Box<dyn Error + Send + Sync>> {
+        if is_ascii_file(path)? {
+            let temporary_file = tempfile::NamedTempFile::new()?;
+            convert_table_to_PACE(path, 1, 1, temporary_file.path())?;
+            return Self::open(temporary_file.path());
+        }
+        Self::open(path)
+    }
+
     // ZAID of the isotope
     #[inline]
     pub fn zaid(&self) -> String {
@@ -105,13 +152,133 @@ impl PaceData {
     pub fn name(&self) -> String {
         helpers::isotope_name_from_Z_A(self.z(), self.a())
     }
+
+    // Identity of the nuclide, from the SZAID if it is available and otherwise the ZAID
+    pub fn isotope(&self) -> Result<Isotope, IsotopeError> {
+        let zaid = self.szaid().unwrap_or_else(|| self.zaid());
+        Isotope::from_nxs(&zaid, &self.nxs_array)
+    }
+
+    // Incident particle type of the table
+    #[inline]
+    pub fn projectile(&self) -> ParticleType {
+        self.header.projectile()
+    }
+
+    // Elastic scattering cross section at an energy (given in MeV). For incident neutrons this is
+    // taken from the ESZ block. Charged-particle tables do not tabulate Coulomb scattering and leave
+    // the ESZ elastic cross section at zero, so the nuclear elastic cross section (MT 2) is used
+    // instead when it is given.
+    pub fn elastic_xs(&self, energy: f64) -> f64 {
+        let elastic = MTNumber::ElasticScattering as usize;
+        match self.projectile() {
+            ParticleType::Neutron => self.data_blocks.ESZ.as_ref().map_or(0.0, |esz| esz.evaluate_elastic(energy)),
+            _ => self.data_blocks.SIG
+                .as_ref()
+                .and_then(|sig| sig.get(&elastic))
+                .map_or(0.0, |xs| xs.evaluate(energy)),
+        }
+    }
+
+    // Total fission cross section at an energy (given in MeV), None if the isotope has no fission data.
+    // This uses the FIS block if present, otherwise MT 18, otherwise the sum of the partial
+    // fission cross sections (MT 19, 20, 21, and 38).
+    pub fn fission_xs(&self, energy: f64) -> Option<f64> {
+        if let Some(fis) = &self.data_blocks.FIS {
+            return Some(fis.evaluate(energy));
+        }
+        let sig = self.data_blocks.SIG.as_ref()?;
+        if let Some(xs) = sig.get(&(MTNumber::Fission as usize)) {
+            return Some(xs.evaluate(energy));
+        }
+        let partial_fission_xs: Vec<f64> = [
+            MTNumber::FirstChanceFission,
+            MTNumber::SecondChanceFission,
+            MTNumber::ThirdChanceFission,
+            MTNumber::FourthChanceFission,
+        ]
+            .iter()
+            .filter_map(|&mt| sig.get(&(mt as usize)))
+            .map(|xs| xs.evaluate(energy))
+            .collect();
+        if partial_fission_xs.is_empty() {
+            None
+        } else {
+            Some(partial_fission_xs.iter().sum())
+        }
+    }
+}
+
+//=====================================================================
+// A continuous-energy table whose blocks are parsed the first time
+// they are asked for rather than all up front, see LazyDataBlocks.
+//=====================================================================
+pub struct LazyPaceData {
+    pub header: Header,
+    pub izaw_array: IzawArray,
+    pub nxs_array: NxsArray,
+    pub jxs_array: JxsArray,
+    pub data_blocks: LazyDataBlocks,
+}
+
+impl LazyPaceData {
+    pub async fn from_file<P: AsRef<Path>>(file_path: P) -> Result<Self, Box<dyn Error>> {
+        Self::open(file_path).map_err(|error| error as Box<dyn Error>)
+    }
+
+    // Synchronous version of from_file, which does not need an async runtime
+    pub fn open<P: AsRef<Path>>(file_path: P) -> Result<Self, Box<dyn Error + Send + Sync>> {
+        let path = file_path.as_ref();
+
+        // If we have an ASCII file, request that it first be converted to our own binary format
+        ensure_not_ascii(path)?;
+
+        Self::from_PACE(PaceMmap::from_file(path)?)
+    }
+
+    // Read the header and arrays from a memory map of a PACE file, which is kept for parsing the blocks
+    pub fn from_PACE(mmap: PaceMmap) -> Result<Self, Box<dyn Error + Send + Sync>> {
+        // Only the header and arrays are read up front, the memory map is kept for the blocks
+        let header = Header::from_PACE(&mmap)?;
+        let izaw_array = IzawArray::from_PACE(&mmap)?;
+        let nxs_array = NxsArray::from_PACE(&mmap)?;
+        let jxs_array = JxsArray::from_PACE(&mmap)?;
+        let data_blocks = LazyDataBlocks::from_PACE(mmap, &header, &nxs_array, &jxs_array);
+
+        Ok(Self { header, izaw_array, nxs_array, jxs_array, data_blocks })
+    }
+
+    // ZAID of the isotope
+    #[inline]
+    pub fn zaid(&self) -> String {
+        self.header.zaid.clone()
+    }
+
+    // SZAID of the isotope (version 2.0.0 and later)
+    #[inline]
+    pub fn szaid(&self) -> Option<String> {
+        self.header.szaid.clone()
+    }
+
+    // Parse all of the remaining blocks into an owned PaceData
+    pub fn to_pace_data(&self) -> Result<PaceData, PaceError> {
+        Ok(PaceData {
+            header: self.header.clone(),
+            izaw_array: self.izaw_array.clone(),
+            nxs_array: self.nxs_array.clone(),
+            jxs_array: self.jxs_array.clone(),
+            data_blocks: self.data_blocks.to_data_blocks()?,
+        })
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    use crate::utils::get_parsed_test_file;
+    use std::path::PathBuf;
+
+    use crate::utils::{get_parsed_test_file, get_parsed_charged_particle_test_file};
 
     #[tokio::test]
     async fn test_parse_test_file() {
@@ -132,6 +299,57 @@ mod tests {
         // We can just test this on the License file
         let result = PaceData::from_file("LICENSE").await;
         assert!(result.is_err());
+        let error = LazyPaceData::from_file("LICENSE").await.err().unwrap();
+        assert!(error.to_string().contains("convert_ACE_to_PACE"));
+    }
+
+    #[tokio::test]
+    async fn test_open() {
+        let parsed_ace = get_parsed_test_file().await;
+        let opened = PaceData::open("test_nuclear_data_files/1100.800nc.pace").unwrap();
+        assert_eq!(opened.szaid(), parsed_ace.szaid());
+        assert_eq!(opened.fission_xs(1.5), parsed_ace.fission_xs(1.5));
+        assert!(PaceData::open("LICENSE").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_load_many() {
+        let parsed_ace = get_parsed_test_file().await;
+
+        // An ASCII table which needs converting, kept out of the way of the test files
+        let directory = tempfile::tempdir().unwrap();
+        let ascii_path = directory.path().join("table_ace");
+        std::fs::copy("test_nuclear_data_files/test_ascii_ace.no_comment", &ascii_path).unwrap();
+
+        let paths = [
+            directory.path().join("missing.pace"),
+            PathBuf::from("test_nuclear_data_files/1100.800nc.pace"),
+            ascii_path,
+            PathBuf::from("LICENSE"),
+        ];
+        let results = PaceData::load_many_bounded(&paths, 2).await;
+        assert_eq!(results.len(), paths.len());
+        assert!(results[0].is_err());
+        assert_eq!(results[1].as_ref().unwrap().szaid(), parsed_ace.szaid());
+        assert_eq!(results[2].as_ref().unwrap().fission_xs(1.5), parsed_ace.fission_xs(1.5));
+        // The conversion does not leave a PACE file next to the ASCII table
+        assert_eq!(std::fs::read_dir(directory.path()).unwrap().count(), 1);
+        assert!(results[3].is_err());
+
+        assert_eq!(PaceData::load_many(&paths[1..2]).await.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_lazy_parsing() {
+        let parsed_ace = get_parsed_test_file().await;
+        let lazy = LazyPaceData::from_file("test_nuclear_data_files/1100.800nc.pace").await.unwrap();
+        assert_eq!(lazy.szaid(), parsed_ace.szaid());
+        let esz = lazy.data_blocks.ESZ().unwrap().as_ref().unwrap();
+        assert_eq!(esz.evaluate_elastic(1.5), parsed_ace.elastic_xs(1.5));
+        assert_eq!(lazy.to_pace_data().unwrap().fission_xs(1.5), parsed_ace.fission_xs(1.5));
+
+        let mmap = PaceMmap::from_file("test_nuclear_data_files/1100.800nc.pace").unwrap();
+        assert_eq!(LazyPaceData::from_PACE(mmap).unwrap().szaid(), parsed_ace.szaid());
     }
 
     #[tokio::test]
@@ -187,4 +405,54 @@ mod tests {
         let parsed_ace = get_parsed_test_file().await;
         assert_eq!(parsed_ace.name(), "H100");
     }
+
+    #[tokio::test]
+    async fn test_neutron_projectile() {
+        let parsed_ace = get_parsed_test_file().await;
+        assert_eq!(parsed_ace.projectile(), ParticleType::Neutron);
+        assert_eq!(parsed_ace.elastic_xs(1.5), 5.5);
+    }
+
+    #[tokio::test]
+    async fn test_charged_particle_table() {
+        let parsed_ace = get_parsed_charged_particle_test_file().await;
+        assert_eq!(parsed_ace.projectile(), ParticleType::Deuteron);
+        assert_eq!(parsed_ace.za(), 1003);
+
+        // The ESZ elastic cross section is zero, so the nuclear elastic cross section is used
+        assert_eq!(parsed_ace.data_blocks.ESZ.as_ref().unwrap().elastic_xs, vec![0.0, 0.0]);
+        assert_eq!(parsed_ace.elastic_xs(0.55), 1.25);
+
+        // Coulomb scattering is not tabulated, so there is no elastic angular distribution
+        let land = parsed_ace.data_blocks.LAND.as_ref().unwrap();
+        assert_eq!(land.get(&(MTNumber::ElasticScattering as usize)), Some(&-1));
+        assert!(parsed_ace.data_blocks.AND.as_ref().unwrap().is_empty());
+        assert!(parsed_ace.data_blocks.SIG.as_ref().unwrap().contains_key(&50));
+    }
+
+    #[tokio::test]
+    async fn test_fission_xs() {
+        let mut parsed_ace = get_parsed_test_file().await;
+        assert_eq!(parsed_ace.fission_xs(1.5), Some(27.5));
+
+        // Without FIS, we should fall back to MT 18
+        parsed_ace.data_blocks.FIS = None;
+        assert_eq!(parsed_ace.fission_xs(1.5), Some(27.5));
+
+        // Without MT 18, we should fall back to the sum of the partial fission cross sections
+        let mut sig = parsed_ace.data_blocks.SIG.clone().unwrap();
+        let mut first_chance = sig.0.remove(&18).unwrap();
+        first_chance.mt = 19;
+        let mut second_chance = first_chance.clone();
+        second_chance.mt = 20;
+        sig.0.insert(19, first_chance);
+        sig.0.insert(20, second_chance);
+        parsed_ace.data_blocks.SIG = Some(sig.clone());
+        assert_eq!(parsed_ace.fission_xs(1.5), Some(55.0));
+
+        // Without any fission data, there is no fission cross section
+        sig.0.clear();
+        parsed_ace.data_blocks.SIG = Some(sig);
+        assert_eq!(parsed_ace.fission_xs(1.5), None);
+    }
 }
\ No newline at end of file
@@ -0,0 +1,149 @@
+use thiserror::Error;
+
+use crate::blocks::BlockType;
+use crate::angular_distributions::{TabulatedAngularDistributionError, EquiprobableBinsAngularDistributionError};
+
+//=====================================================================
+// Errors produced while reading a PACE file. Malformed or truncated
+// data is reported through these rather than by panicking, so that a
+// bad table can be skipped without taking down the caller.
+//
+// Errors raised while processing the data of a block are wrapped in
+// InBlock, which names the block and the (zero indexed) XXS index at
+// which the block starts. Blocks of the thermal, photoatomic and
+// electron tables are not BlockTypes, so they are named by their ACE
+// spec name in InTableBlock instead.
+//=====================================================================
+#[derive(Debug, Error)]
+pub enum PaceError {
+    #[error("{block} block was expected but not found: {reason}")]
+    MissingBlock { block: BlockType, reason: String },
+
+    #[error("{0} block was found when it was not expected")]
+    UnexpectedBlock(BlockType),
+
+    #[error("{block} block runs past the end of the XXS array (XXS index {index}, XXS length {length})")]
+    BlockOutOfBounds { block: BlockType, index: usize, length: usize },
+
+    #[error("Index {index} is out of bounds of the block data (length {length})")]
+    LocatorOutOfBounds { index: usize, length: usize },
+
+    #[error("Word {index} holds {word}, which is not a valid integer here")]
+    NotAnInteger { index: usize, word: f64 },
+
+    #[error("Word {index} holds the float {word} where an integer was expected")]
+    FloatAsInteger { index: usize, word: f64 },
+
+    #[error("Invalid locator {0}, locators are one indexed")]
+    InvalidLocator(usize),
+
+    #[error("Unknown {kind}: {code}")]
+    UnknownCode { kind: &'static str, code: i64 },
+
+    #[error("{0} has no entries")]
+    EmptyTable(&'static str),
+
+    #[error("Invalid tabulated angular distribution: {0}")]
+    TabulatedAngularDistribution(#[from] TabulatedAngularDistributionError),
+
+    #[error("Invalid equiprobable bin angular distribution: {0}")]
+    EquiprobableBinsAngularDistribution(#[from] EquiprobableBinsAngularDistributionError),
+
+    #[error("Error in {block} block starting at XXS index {index}: {source}")]
+    InBlock { block: BlockType, index: usize, source: Box<PaceError> },
+
+    #[error("Error in {block} block starting at XXS index {index}: {source}")]
+    InTableBlock { block: &'static str, index: usize, source: Box<PaceError> },
+
+    #[error("Truncated file: {0}")]
+    TruncatedFile(String),
+
+    #[error("Not a PACE file, or a PACE file written before the format was versioned (see migrate_PACE)")]
+    MissingMagic,
+
+    #[error("PACE format version {found} is not supported, expected version {supported}")]
+    UnsupportedVersion { found: u32, supported: u32 },
+
+    #[error("PACE file was written on a machine with a different byte order")]
+    ByteOrderMismatch,
+
+    #[error("Invalid file layout: {0}")]
+    InvalidLayout(String),
+
+    #[error("Invalid header: {0}")]
+    InvalidHeader(String),
+
+    #[error("Invalid token \"{0}\" when trying to convert ASCII to binary")]
+    InvalidToken(String),
+}
+
+impl PaceError {
+    // Attach the block, and the XXS index it starts at, to an error raised while processing it
+    pub fn in_block(self, block: BlockType, index: usize) -> Self {
+        match self {
+            // These already name their block
+            Self::MissingBlock { .. } | Self::UnexpectedBlock(_) | Self::BlockOutOfBounds { .. } | Self::InBlock { .. } | Self::InTableBlock { .. } => self,
+            _ => Self::InBlock { block, index, source: Box::new(self) },
+        }
+    }
+
+    // Attach the name of a thermal, photoatomic or electron block, and the XXS index it starts at
+    pub fn in_table_block(self, block: &'static str, index: usize) -> Self {
+        match self {
+            Self::InBlock { .. } | Self::InTableBlock { .. } => self,
+            _ => Self::InTableBlock { block, index, source: Box::new(self) },
+        }
+    }
+
+    pub(crate) fn unknown_code(kind: &'static str, code: impl TryInto<i64>) -> Self {
+        Self::UnknownCode { kind, code: code.try_into().unwrap_or(i64::MAX) }
+    }
+}
+
+// Check that the words up to (but not including) `end` lie inside of data with length `length`
+pub(crate) fn check_bounds(end: usize, length: usize) -> Result<(), PaceError> {
+    if end > length {
+        return Err(PaceError::LocatorOutOfBounds { index: end - 1, length });
+    }
+    Ok(())
+}
+
+// Convert a (one indexed) locator into an index
+pub(crate) fn locator_to_index(locator: usize) -> Result<usize, PaceError> {
+    locator.checked_sub(1).ok_or(PaceError::InvalidLocator(locator))
+}
+
+// Bounds checked slice of `data`, starting at `start` and `length` words long
+pub(crate) fn get_slice(data: &[f64], start: usize, length: usize) -> Result<&[f64], PaceError> {
+    let end = start.checked_add(length).ok_or(PaceError::LocatorOutOfBounds { index: start, length: data.len() })?;
+    check_bounds(end, data.len())?;
+    Ok(&data[start..end])
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_in_block() {
+        let error = PaceError::unknown_code("interpolation scheme", 9).in_block(BlockType::DLW, 120);
+        assert!(matches!(error, PaceError::InBlock { block: BlockType::DLW, index: 120, .. }));
+        assert_eq!(error.to_string(), "Error in DLW block starting at XXS index 120: Unknown interpolation scheme: 9");
+
+        // Errors which already name a block are left alone
+        let error = PaceError::UnexpectedBlock(BlockType::NU).in_block(BlockType::DLW, 120);
+        assert!(matches!(error, PaceError::UnexpectedBlock(BlockType::NU)));
+
+        let error = PaceError::InvalidLocator(0).in_table_block("ITXE", 40).in_table_block("ITIE", 0);
+        assert_eq!(error.to_string(), "Error in ITXE block starting at XXS index 40: Invalid locator 0, locators are one indexed");
+    }
+
+    #[test]
+    fn test_bounds_checks() {
+        let data = [1.0, 2.0, 3.0];
+        assert_eq!(get_slice(&data, 1, 2).unwrap(), &[2.0, 3.0]);
+        assert!(matches!(get_slice(&data, 2, 2), Err(PaceError::LocatorOutOfBounds { index: 3, length: 3 })));
+        assert!(get_slice(&data, usize::MAX, 2).is_err());
+    }
+}
@@ -0,0 +1,171 @@
+use std::error::Error;
+
+use crate::utils::PaceMmap;
+use crate::header::Header;
+use crate::arrays::{Arrays, IzawArray, JxsArray, NxsArray, XxsArray};
+use crate::blocks::{DataBlocks, ParticleType, ESZ, EszView, MTR, LSIG, SIG, SigView, FIS, CrossSectionView, NU, NuView};
+use crate::blocks::{Parse, PullFromXXS};
+use crate::helpers::MTNumber;
+use crate::pace_data::PaceData;
+use crate::pace_error::PaceError;
+
+//=====================================================================
+// A zero-copy view of a continuous-energy table in a PACE file. The
+// energy grid, cross sections and nu tables borrow from the memory
+// map of the file instead of being copied out of it, so a view is
+// cheap to make and holds little memory of its own. Only the small
+// header, arrays, reaction lists and table bounds are decoded.
+//
+// The remaining blocks are only available from the owned PaceData,
+// which can be made from the view with to_pace_data.
+//=====================================================================
+pub struct PaceView<'mmap> {
+    mmap: &'mmap PaceMmap,
+    pub header: Header,
+    pub izaw_array: IzawArray,
+    pub nxs_array: NxsArray,
+    pub jxs_array: JxsArray,
+    pub ESZ: Option<EszView<'mmap>>,
+    pub MTR: Option<MTR>,
+    pub SIG: Option<SigView<'mmap>>,
+    pub FIS: Option<CrossSectionView<'mmap>>,
+    pub NU: Option<NuView<'mmap>>,
+}
+
+impl<'mmap> PaceView<'mmap> {
+    pub fn new(mmap: &'mmap PaceMmap) -> Result<Self, Box<dyn Error>> {
+        let header = Header::from_PACE(mmap)?;
+        let izaw_array = IzawArray::from_PACE(mmap)?;
+        let nxs_array = NxsArray::from_PACE(mmap)?;
+        let jxs_array = JxsArray::from_PACE(mmap)?;
+
+        let xxs = mmap.xxs_array();
+        let arrays = Arrays { nxs: &nxs_array, jxs: &jxs_array, xxs };
+
+        // Views of the energy grid, and of the cross sections on it
+        let esz = view_block::<ESZ, _>(&arrays, |data| EszView::process(data, nxs_array.nes))?;
+        let mtr = MTR::parse(&arrays, ())?;
+        let lsig = LSIG::parse(&arrays, ())?;
+        let energy = esz.map_or(&[][..], |esz| esz.energy);
+        let sig = match (&mtr, &lsig) {
+            (Some(mtr), Some(lsig)) => view_block::<SIG, _>(&arrays, |data| SigView::process(data, mtr, lsig, energy))?,
+            _ => None,
+        };
+        let fis = view_block::<FIS, _>(&arrays, |data| FIS::process_view(data, energy))?;
+
+        // View of the fission nu tables
+        let nu = view_block::<NU, _>(&arrays, |data| NuView::process(data, &arrays))?;
+
+        Ok(Self { mmap, header, izaw_array, nxs_array, jxs_array, ESZ: esz, MTR: mtr, SIG: sig, FIS: fis, NU: nu })
+    }
+
+    // Parse all of the blocks of the table into an owned PaceData, which no longer borrows the mapping
+    pub fn to_pace_data(&self) -> Result<PaceData, PaceError> {
+        let data_blocks = DataBlocks::from_PACE(self.mmap, &self.header, &self.nxs_array, &self.jxs_array)?;
+        Ok(PaceData {
+            header: self.header.clone(),
+            izaw_array: self.izaw_array.clone(),
+            nxs_array: self.nxs_array.clone(),
+            jxs_array: self.jxs_array.clone(),
+            data_blocks,
+        })
+    }
+
+    // Incident particle type of the table
+    #[inline]
+    pub fn projectile(&self) -> ParticleType {
+        self.header.projectile()
+    }
+
+    // Cross section of a reaction, None if the table has no cross section for it
+    pub fn cross_section(&self, mt: usize) -> Option<&CrossSectionView<'mmap>> {
+        self.SIG.as_ref()?.get(&mt)
+    }
+
+    // Elastic scattering cross section at an energy (given in MeV), see PaceData::elastic_xs
+    pub fn elastic_xs(&self, energy: f64) -> f64 {
+        match self.projectile() {
+            ParticleType::Neutron => self.ESZ.as_ref().map_or(0.0, |esz| esz.evaluate_elastic(energy)),
+            _ => self.cross_section(MTNumber::ElasticScattering as usize).map_or(0.0, |xs| xs.evaluate(energy)),
+        }
+    }
+}
+
+// Pull a block out of the XXS array and process it into a view. The pulled data borrows from the
+// mapping rather than from the arrays, so the view can too. Errors are tagged with the block, as they
+// are by Parse.
+fn view_block<'mmap, T, V>(
+    arrays: &Arrays<'_, 'mmap>,
+    process: impl FnOnce(XxsArray<'mmap>) -> Result<V, PaceError>,
+) -> Result<Option<V>, PaceError>
+where
+    T: PullFromXXS<'mmap>,
+{
+    let jxs_start = arrays.jxs.get(&T::BLOCK_TYPE).saturating_sub(1);
+    let Some(block) = T::pull_from_xxs_array(arrays).map_err(|error| error.in_block(T::BLOCK_TYPE, jxs_start))? else {
+        return Ok(None);
+    };
+    process(block.data)
+        .map(Some)
+        .map_err(|error| error.in_block(T::BLOCK_TYPE, block.start))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::blocks::NuFormulationView;
+    use crate::utils::{get_parsed_test_file, get_parsed_charged_particle_test_file};
+
+    #[tokio::test]
+    async fn test_view_matches_parsed_data() {
+        let parsed_ace = get_parsed_test_file().await;
+        let mmap = PaceMmap::from_file("test_nuclear_data_files/1100.800nc.pace").unwrap();
+        let view = PaceView::new(&mmap).unwrap();
+
+        // The views borrow from the mapping itself
+        let xxs = mmap.xxs_array().as_ptr_range();
+        let esz = view.ESZ.unwrap();
+        assert!(xxs.contains(&esz.energy.as_ptr()));
+        assert_eq!(ESZ::from(&esz).energy, parsed_ace.data_blocks.ESZ.as_ref().unwrap().energy);
+
+        let sig = parsed_ace.data_blocks.SIG.as_ref().unwrap();
+        assert_eq!(view.SIG.as_ref().unwrap().len(), sig.len());
+        for (mt, xs) in sig.iter() {
+            let xs_view = view.cross_section(*mt).unwrap();
+            assert!(xxs.contains(&xs_view.xs_val.as_ptr()));
+            assert_eq!(xs_view.energy, xs.energy.as_slice());
+            assert_eq!(xs_view.xs_val, xs.xs_val.as_slice());
+            assert_eq!(xs_view.evaluate(1.5), xs.evaluate(1.5));
+        }
+        assert_eq!(view.FIS.unwrap().xs_val, parsed_ace.data_blocks.FIS.as_ref().unwrap().xs_val.as_slice());
+        assert_eq!(view.elastic_xs(1.5), parsed_ace.elastic_xs(1.5));
+
+        // The tabulated total nu borrows its table from the mapping
+        let nu = view.NU.as_ref().unwrap();
+        let Some(NuFormulationView::Tabulated(table)) = &nu.total else {
+            panic!("Total nu should be tabulated");
+        };
+        assert!(xxs.contains(&table.x.as_ptr()));
+        let owned_nu = parsed_ace.data_blocks.NU.as_ref().unwrap();
+        for energy in [1e-11, 1.0, 5.5] {
+            assert_eq!(nu.total.as_ref().unwrap().evaluate(energy).unwrap(), owned_nu.total.as_ref().unwrap().evaluate(energy).unwrap());
+            assert_eq!(nu.prompt.as_ref().unwrap().evaluate(energy).unwrap(), owned_nu.prompt.as_ref().unwrap().evaluate(energy).unwrap());
+        }
+
+        // Converting to owned data gives the same blocks as parsing the file
+        let owned = view.to_pace_data().unwrap();
+        assert_eq!(owned.zaid(), parsed_ace.zaid());
+        assert_eq!(owned.fission_xs(1.5), parsed_ace.fission_xs(1.5));
+        assert_eq!(owned.data_blocks.MTR, parsed_ace.data_blocks.MTR);
+    }
+
+    #[tokio::test]
+    async fn test_charged_particle_view() {
+        let parsed_ace = get_parsed_charged_particle_test_file().await;
+        let mmap = PaceMmap::from_file("test_nuclear_data_files/1003.800o.pace").unwrap();
+        let view = PaceView::new(&mmap).unwrap();
+        assert_eq!(view.projectile(), ParticleType::Deuteron);
+        assert_eq!(view.elastic_xs(0.55), parsed_ace.elastic_xs(0.55));
+    }
+}
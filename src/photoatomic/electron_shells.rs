@@ -0,0 +1,116 @@
+use crate::interpolation::InterpolationScheme;
+use crate::photoatomic::{PhotoatomicNxsArray, PhotoatomicJxsArray};
+use crate::arrays::{XxsArray, XxsWords};
+use crate::pace_error::{PaceError, locator_to_index};
+
+//=====================================================================
+// LNEPS, LBEPS, LPIPS, LSWD and SWD data blocks
+//
+// Electron shell data from the newer eprdata libraries, derived from
+// EPDL. For each of the NXS(5) shells this gives the number of
+// electrons, the binding energy and the probability of an incoherent
+// interaction with the shell. LSWD gives the location of each shell's
+// Compton profile relative to the start of the SWD block.
+//=====================================================================
+#[derive(Debug, Clone, PartialEq)]
+pub struct ElectronShells {
+    pub electrons: Vec<f64>,
+    pub binding_energy: Vec<f64>,
+    pub interaction_probability: Vec<f64>,
+    pub compton_profiles: Vec<ComptonProfile>,
+}
+
+impl ElectronShells {
+    // Process the electron shell data from the XXS array, None for tables without shell data
+    pub fn process(xxs: XxsArray<'_>, nxs: &PhotoatomicNxsArray, jxs: &PhotoatomicJxsArray) -> Result<Option<Self>, PaceError> {
+        if nxs.nsh == 0 || jxs.lneps == 0 {
+            return Ok(None);
+        }
+
+        let num_shells = nxs.nsh;
+        let shell_values = |block: &'static str, locator: usize| {
+            locator_to_index(locator)
+                .and_then(|start| Ok(xxs.floats(start, num_shells)?.to_vec()))
+                .map_err(|error| error.in_table_block(block, locator.saturating_sub(1)))
+        };
+        let compton_profiles = match (jxs.lswd, jxs.swd) {
+            (0, _) | (_, 0) => Vec::new(),
+            (lswd, swd) => xxs.uints(lswd - 1, num_shells)
+                .map_err(|error| error.in_table_block("LSWD", lswd - 1))?
+                .into_iter()
+                .map(|offset| ComptonProfile::process(xxs, swd - 1 + offset))
+                .collect::<Result<_, _>>()
+                .map_err(|error: PaceError| error.in_table_block("SWD", swd - 1))?,
+        };
+
+        Ok(Some(Self {
+            electrons: shell_values("LNEPS", jxs.lneps)?,
+            binding_energy: shell_values("LBEPS", jxs.lbeps)?,
+            interaction_probability: shell_values("LPIPS", jxs.lpips)?,
+            compton_profiles,
+        }))
+    }
+
+    // Number of electron shells
+    pub fn len(&self) -> usize {
+        self.electrons.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.electrons.is_empty()
+    }
+}
+
+// Compton profile of a single shell, tabulated in the projected electron momentum. The layout
+// matches a tabulated energy distribution: JJ, NP, followed by the momenta, PDF and CDF.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ComptonProfile {
+    pub interpolation_scheme: InterpolationScheme,
+    pub momentum: Vec<f64>,
+    pub pdf: Vec<f64>,
+    pub cdf: Vec<f64>,
+}
+
+impl ComptonProfile {
+    pub fn process(data: XxsArray<'_>, start: usize) -> Result<Self, PaceError> {
+        let interpolation_scheme = InterpolationScheme::try_from(data.uint(start)?)?;
+        let num_points = data.uint(start + 1)?;
+        let momentum_start = start + 2;
+        let pdf_start = momentum_start + num_points;
+        let cdf_start = pdf_start + num_points;
+
+        Ok(Self {
+            interpolation_scheme,
+            momentum: data.floats(momentum_start, num_points)?.to_vec(),
+            pdf: data.floats(pdf_start, num_points)?.to_vec(),
+            cdf: data.floats(cdf_start, num_points)?.to_vec(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::utils::get_parsed_photoatomic_test_file;
+
+    #[tokio::test]
+    async fn test_electron_shell_parsing() {
+        let parsed_ace = get_parsed_photoatomic_test_file().await;
+        let shells = parsed_ace.electron_shells.unwrap();
+
+        assert_eq!(shells.len(), 1);
+        assert_eq!(shells.electrons, vec![1.0]);
+        assert_eq!(shells.binding_energy, vec![1.36E-05]);
+        assert_eq!(shells.interaction_probability, vec![1.0]);
+        assert_eq!(
+            shells.compton_profiles,
+            vec![ComptonProfile {
+                interpolation_scheme: InterpolationScheme::LinLin,
+                momentum: vec![0.0, 1.0, 2.0],
+                pdf: vec![1.0, 0.5, 0.0],
+                cdf: vec![0.0, 0.75, 1.0],
+            }]
+        );
+    }
+}
@@ -0,0 +1,134 @@
+use crate::photoatomic::{PhotoatomicNxsArray, PhotoatomicJxsArray};
+use crate::arrays::{XxsArray, XxsWords};
+use crate::pace_error::{PaceError, locator_to_index};
+
+//=====================================================================
+// ESZG data block
+//
+// Contains the photon energy grid followed by the incoherent, coherent,
+// photoelectric and pair production cross sections, each with NXS(3)
+// entries. All values are stored as natural logarithms, with a value of
+// zero marking a cross section which is zero (e.g. pair production
+// below its threshold). Energies are given in MeV and cross sections
+// in barns.
+//=====================================================================
+#[derive(Debug, Clone, PartialEq)]
+pub struct PhotoatomicCrossSections {
+    pub energy: Vec<f64>,
+    pub incoherent: Vec<f64>,
+    pub coherent: Vec<f64>,
+    pub photoelectric: Vec<f64>,
+    pub pair_production: Vec<f64>,
+}
+
+impl PhotoatomicCrossSections {
+    pub fn process(xxs: XxsArray<'_>, nxs: &PhotoatomicNxsArray, jxs: &PhotoatomicJxsArray) -> Result<Self, PaceError> {
+        let num_energies = nxs.nes;
+        let eszg = locator_to_index(jxs.eszg)?;
+        let data = xxs.floats(eszg, num_energies.saturating_mul(5))
+            .map_err(|error| error.in_table_block("ESZG", eszg))?;
+        let column = |i: usize| &data[i * num_energies..(i + 1) * num_energies];
+
+        let energy = column(0).iter().map(|ln_energy| ln_energy.exp()).collect();
+        let xs = |i: usize| column(i)
+            .iter()
+            .map(|&ln_xs| if ln_xs == 0.0 { 0.0 } else { ln_xs.exp() })
+            .collect();
+
+        Ok(Self {
+            energy,
+            incoherent: xs(1),
+            coherent: xs(2),
+            photoelectric: xs(3),
+            pair_production: xs(4),
+        })
+    }
+
+    // Interpolate a cross section log-log on the energy grid, zero outside of the tabulated range.
+    // Intervals where the cross section drops to zero are interpolated linearly instead.
+    fn interpolate(&self, xs: &[f64], energy: f64) -> f64 {
+        let grid = &self.energy;
+        if grid.is_empty() || energy < grid[0] || energy > grid[grid.len() - 1] {
+            return 0.0;
+        }
+        let bin = grid.partition_point(|&e| e <= energy).saturating_sub(1).min(grid.len() - 1);
+        if bin == grid.len() - 1 {
+            return xs[bin];
+        }
+
+        let (e0, e1) = (grid[bin], grid[bin + 1]);
+        let (xs0, xs1) = (xs[bin], xs[bin + 1]);
+        if xs0 <= 0.0 || xs1 <= 0.0 {
+            xs0 + (energy - e0) / (e1 - e0) * (xs1 - xs0)
+        } else {
+            xs0 * (xs1 / xs0).powf((energy / e0).ln() / (e1 / e0).ln())
+        }
+    }
+
+    // Incoherent (Compton) scattering cross section at an energy
+    pub fn incoherent_xs(&self, energy: f64) -> f64 {
+        self.interpolate(&self.incoherent, energy)
+    }
+
+    // Coherent (Rayleigh) scattering cross section at an energy
+    pub fn coherent_xs(&self, energy: f64) -> f64 {
+        self.interpolate(&self.coherent, energy)
+    }
+
+    // Photoelectric absorption cross section at an energy
+    pub fn photoelectric_xs(&self, energy: f64) -> f64 {
+        self.interpolate(&self.photoelectric, energy)
+    }
+
+    // Pair production cross section at an energy
+    pub fn pair_production_xs(&self, energy: f64) -> f64 {
+        self.interpolate(&self.pair_production, energy)
+    }
+
+    // Total photon interaction cross section at an energy
+    pub fn total_xs(&self, energy: f64) -> f64 {
+        self.incoherent_xs(energy)
+            + self.coherent_xs(energy)
+            + self.photoelectric_xs(energy)
+            + self.pair_production_xs(energy)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::get_parsed_photoatomic_test_file;
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!((actual - expected).abs() < 1e-9 * expected.abs().max(1.0), "{actual} != {expected}");
+    }
+
+    #[tokio::test]
+    async fn test_eszg_parsing() {
+        let parsed_ace = get_parsed_photoatomic_test_file().await;
+        let cross_sections = parsed_ace.cross_sections;
+
+        assert_eq!(cross_sections.energy.len(), 3);
+        assert_close(cross_sections.energy[0], 1.0E-03);
+        assert_close(cross_sections.energy[2], 1.0E-01);
+        assert_close(cross_sections.incoherent[1], 4.0);
+        assert_close(cross_sections.photoelectric[0], 100.0);
+        assert_eq!(cross_sections.pair_production, vec![0.0, 0.0, 0.0]);
+    }
+
+    #[tokio::test]
+    async fn test_eszg_interpolation() {
+        let parsed_ace = get_parsed_photoatomic_test_file().await;
+        let cross_sections = parsed_ace.cross_sections;
+
+        // Log-log interpolation halfway between grid points in log space
+        let energy = (1.0E-03_f64 * 1.0E-02).sqrt();
+        assert_close(cross_sections.incoherent_xs(energy), 8.0_f64.sqrt());
+        assert_close(cross_sections.coherent_xs(energy), 32.0_f64.sqrt());
+        assert_close(cross_sections.photoelectric_xs(1.0E-02), 10.0);
+        assert_eq!(cross_sections.pair_production_xs(energy), 0.0);
+        assert_close(cross_sections.total_xs(1.0E-01), 8.0 + 2.0 + 2.0);
+
+        // No data outside of the tabulated range
+        assert_eq!(cross_sections.total_xs(1.0), 0.0);
+    }
+}
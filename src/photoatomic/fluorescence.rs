@@ -0,0 +1,56 @@
+use crate::photoatomic::{PhotoatomicNxsArray, PhotoatomicJxsArray};
+use crate::arrays::{XxsArray, XxsWords};
+use crate::pace_error::PaceError;
+
+//=====================================================================
+// JFLO data block
+//
+// Contains the fluorescence data for NXS(4) absorption edges: the edge
+// energies, the relative probabilities of ejecting an electron from
+// each edge, the fluorescence yields and the fluorescent photon
+// energies. Each is given as a separate array of NXS(4) entries.
+//=====================================================================
+#[derive(Debug, Clone, PartialEq)]
+pub struct Fluorescence {
+    pub edge_energy: Vec<f64>,
+    pub probability: Vec<f64>,
+    pub fluorescence_yield: Vec<f64>,
+    pub fluorescence_energy: Vec<f64>,
+}
+
+impl Fluorescence {
+    // Process the fluorescence data from the XXS array, None if there is no fluorescence data
+    pub fn process(xxs: XxsArray<'_>, nxs: &PhotoatomicNxsArray, jxs: &PhotoatomicJxsArray) -> Result<Option<Self>, PaceError> {
+        if jxs.jflo == 0 || nxs.nflo == 0 {
+            return Ok(None);
+        }
+
+        let num_edges = nxs.nflo;
+        let jflo = jxs.jflo - 1;
+        let data = xxs.floats(jflo, num_edges.saturating_mul(4))
+            .map_err(|error| error.in_table_block("JFLO", jflo))?;
+        let column = |i: usize| data[i * num_edges..(i + 1) * num_edges].to_vec();
+        Ok(Some(Self {
+            edge_energy: column(0),
+            probability: column(1),
+            fluorescence_yield: column(2),
+            fluorescence_energy: column(3),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::get_parsed_photoatomic_test_file;
+
+    #[tokio::test]
+    async fn test_jflo_parsing() {
+        let parsed_ace = get_parsed_photoatomic_test_file().await;
+        let fluorescence = parsed_ace.fluorescence.unwrap();
+
+        assert_eq!(fluorescence.edge_energy, vec![1.36E-05]);
+        assert_eq!(fluorescence.probability, vec![1.0]);
+        assert_eq!(fluorescence.fluorescence_yield, vec![0.0]);
+        assert_eq!(fluorescence.fluorescence_energy, vec![1.36E-05]);
+    }
+}
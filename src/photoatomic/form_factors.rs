@@ -0,0 +1,290 @@
+use crate::unitf64::UnitF64;
+use crate::energy_distributions::{SampleEnergy, SampledEnergy, EnergyDistributionError};
+use crate::photoatomic::PhotoatomicJxsArray;
+use crate::arrays::{XxsArray, XxsWords};
+use crate::pace_error::PaceError;
+
+// Electron rest mass energy in MeV
+const ELECTRON_REST_MASS_ENERGY: f64 = 0.51099895;
+
+// Converts a photon energy α (in units of the electron rest mass energy) and scattering cosine μ
+// to the momentum transfer v = κα√(1 - μ), in inverse Angstroms
+const MOMENTUM_TRANSFER_FACTOR: f64 = 29.1445;
+
+// Linearly interpolate a table, holding it constant outside of the tabulated range
+fn interpolate_linear(x: &[f64], y: &[f64], x_val: f64) -> f64 {
+    let bin = x.partition_point(|&x_i| x_i <= x_val);
+    match bin {
+        0 => y[0],
+        bin if bin == x.len() => y[bin - 1],
+        bin => y[bin - 1] + (x_val - x[bin - 1]) / (x[bin] - x[bin - 1]) * (y[bin] - y[bin - 1]),
+    }
+}
+
+// Sample an outgoing energy and cosine for a photon scattering off a free electron, following the
+// Klein-Nishina cross section. Uses Kahn's rejection method, which is valid at all energies.
+pub fn sample_klein_nishina<R: FnMut() -> UnitF64>(energy: f64, rng: &mut R) -> SampledEnergy {
+    let alpha = energy / ELECTRON_REST_MASS_ENERGY;
+    let beta = 1.0 + 2.0 * alpha;
+    let branch_probability = beta / (beta + 8.0);
+
+    // x is the ratio of the incident to the outgoing energy
+    let x = loop {
+        if rng().0 < branch_probability {
+            let x = 1.0 + 2.0 * alpha * rng().0;
+            if rng().0 <= 4.0 * (1.0 / x - 1.0 / (x * x)) {
+                break x;
+            }
+        } else {
+            let x = beta / (1.0 + 2.0 * alpha * rng().0);
+            let cos_theta = 1.0 + (1.0 - x) / alpha;
+            if rng().0 <= 0.5 * (cos_theta * cos_theta + 1.0 / x) {
+                break x;
+            }
+        }
+    };
+
+    let cos_theta = (1.0 + (1.0 - x) / alpha).clamp(-1.0, 1.0);
+    SampledEnergy { energy: energy / x, cos_theta: Some(cos_theta) }
+}
+
+//=====================================================================
+// JINC data block
+//
+// Contains the incoherent scattering function S(v), which corrects the
+// Klein-Nishina cross section for the binding of the electrons. The
+// block holds the momentum transfer values v followed by the values
+// of S(v), with the length of the block found from the start of the
+// JCOH block.
+//=====================================================================
+#[derive(Debug, Clone, PartialEq)]
+pub struct IncoherentScatteringFunction {
+    pub momentum_transfer: Vec<f64>,
+    pub scattering_function: Vec<f64>,
+}
+
+impl IncoherentScatteringFunction {
+    // Process the incoherent scattering function from the XXS array, None if it is not given
+    pub fn process(xxs: XxsArray<'_>, jxs: &PhotoatomicJxsArray) -> Result<Option<Self>, PaceError> {
+        if jxs.jinc == 0 || jxs.jcoh <= jxs.jinc {
+            return Ok(None);
+        }
+
+        let jinc = jxs.jinc - 1;
+        let num_points = (jxs.jcoh - jxs.jinc) / 2;
+        let data = xxs.floats(jinc, 2 * num_points)
+            .map_err(|error| error.in_table_block("JINC", jinc))?;
+        Ok(Some(Self {
+            momentum_transfer: data[..num_points].to_vec(),
+            scattering_function: data[num_points..].to_vec(),
+        }))
+    }
+
+    // Incoherent scattering function at a momentum transfer (in inverse Angstroms)
+    pub fn evaluate(&self, momentum_transfer: f64) -> f64 {
+        interpolate_linear(&self.momentum_transfer, &self.scattering_function, momentum_transfer)
+    }
+}
+
+// Sample an incoherent scattering event by sampling the Klein-Nishina distribution and rejecting
+// according to the incoherent scattering function
+impl SampleEnergy for IncoherentScatteringFunction {
+    fn sample_energy<R: FnMut() -> UnitF64>(&self, incident_energy: f64, rng: &mut R) -> Result<SampledEnergy, EnergyDistributionError> {
+        if self.momentum_transfer.is_empty() {
+            return Err(EnergyDistributionError::EmptyDistribution);
+        }
+
+        // The scattering function is largest at the largest momentum transfer (backscatter)
+        let momentum_factor = MOMENTUM_TRANSFER_FACTOR * incident_energy / ELECTRON_REST_MASS_ENERGY;
+        let max_scattering_function = self.evaluate(momentum_factor * 2.0_f64.sqrt());
+
+        loop {
+            let sample = sample_klein_nishina(incident_energy, rng);
+            let momentum_transfer = momentum_factor * (1.0 - sample.cos_theta.unwrap()).sqrt();
+            if rng().0 * max_scattering_function <= self.evaluate(momentum_transfer) {
+                return Ok(sample);
+            }
+        }
+    }
+}
+
+
+//=====================================================================
+// JCOH data block
+//
+// Contains the momentum transfer values v, the integrated form factors
+// I(v) and the form factors F(v) used for coherent scattering. Each
+// has a third of the entries between the start of the JCOH block and
+// the start of the JFLO block. The integrated form factors are taken
+// to vary linearly in v² between the tabulated points.
+//=====================================================================
+#[derive(Debug, Clone, PartialEq)]
+pub struct CoherentFormFactors {
+    pub momentum_transfer: Vec<f64>,
+    pub integrated_form_factor: Vec<f64>,
+    pub form_factor: Vec<f64>,
+}
+
+impl CoherentFormFactors {
+    // Process the coherent form factors from the XXS array, None if they are not given
+    pub fn process(xxs: XxsArray<'_>, jxs: &PhotoatomicJxsArray) -> Result<Option<Self>, PaceError> {
+        if jxs.jcoh == 0 || jxs.jflo <= jxs.jcoh {
+            return Ok(None);
+        }
+
+        let jcoh = jxs.jcoh - 1;
+        let num_points = (jxs.jflo - jxs.jcoh) / 3;
+        let data = xxs.floats(jcoh, 3 * num_points)
+            .map_err(|error| error.in_table_block("JCOH", jcoh))?;
+        Ok(Some(Self {
+            momentum_transfer: data[..num_points].to_vec(),
+            integrated_form_factor: data[num_points..2 * num_points].to_vec(),
+            form_factor: data[2 * num_points..].to_vec(),
+        }))
+    }
+
+    // Form factor at a momentum transfer (in inverse Angstroms)
+    pub fn evaluate(&self, momentum_transfer: f64) -> f64 {
+        interpolate_linear(&self.momentum_transfer, &self.form_factor, momentum_transfer)
+    }
+
+    // Integrated form factor at a momentum transfer (in inverse Angstroms)
+    pub fn integrated(&self, momentum_transfer: f64) -> f64 {
+        let momentum_squared: Vec<f64> = self.momentum_transfer.iter().map(|v| v * v).collect();
+        interpolate_linear(&momentum_squared, &self.integrated_form_factor, momentum_transfer * momentum_transfer)
+    }
+
+    // Momentum transfer at which the integrated form factor reaches a value
+    fn invert_integrated(&self, integrated_form_factor: f64) -> f64 {
+        let (v, integrated) = (&self.momentum_transfer, &self.integrated_form_factor);
+        let bin = integrated
+            .partition_point(|&value| value <= integrated_form_factor)
+            .clamp(1, integrated.len() - 1);
+        let (lower, upper) = (bin - 1, bin);
+        if integrated[upper] <= integrated[lower] {
+            return v[lower];
+        }
+
+        let fraction = (integrated_form_factor - integrated[lower]) / (integrated[upper] - integrated[lower]);
+        (v[lower] * v[lower] + fraction * (v[upper] * v[upper] - v[lower] * v[lower])).sqrt()
+    }
+}
+
+// Sample a coherent scattering event. The momentum transfer is sampled from the square of the form
+// factor through the integrated form factor, and then rejected according to the Thomson cross
+// section. Coherent scattering does not change the energy of the photon.
+impl SampleEnergy for CoherentFormFactors {
+    fn sample_energy<R: FnMut() -> UnitF64>(&self, incident_energy: f64, rng: &mut R) -> Result<SampledEnergy, EnergyDistributionError> {
+        if self.momentum_transfer.len() < 2 {
+            return Err(EnergyDistributionError::EmptyDistribution);
+        }
+
+        let momentum_factor = MOMENTUM_TRANSFER_FACTOR * incident_energy / ELECTRON_REST_MASS_ENERGY;
+        let max_integrated_form_factor = self.integrated(momentum_factor * 2.0_f64.sqrt());
+        if max_integrated_form_factor <= 0.0 {
+            return Err(EnergyDistributionError::EmptyDistribution);
+        }
+
+        loop {
+            let momentum_transfer = self.invert_integrated(rng().0 * max_integrated_form_factor);
+            let cos_theta = (1.0 - (momentum_transfer / momentum_factor).powi(2)).clamp(-1.0, 1.0);
+            if rng().0 <= 0.5 * (1.0 + cos_theta * cos_theta) {
+                return Ok(SampledEnergy { energy: incident_energy, cos_theta: Some(cos_theta) });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::utils::get_parsed_photoatomic_test_file;
+
+    // Simple linear congruential generator so that the rejection loops see varied random numbers
+    fn lcg() -> impl FnMut() -> UnitF64 {
+        let mut state: u64 = 12345;
+        move || {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            UnitF64((state >> 11) as f64 / (1u64 << 53) as f64)
+        }
+    }
+
+    #[test]
+    fn test_klein_nishina_sampling() {
+        // At α = 1, the first branch with x = 2 gives a 90 degree scatter at half the energy
+        let values = [0.1, 0.5, 0.1];
+        let mut i = 0;
+        let mut rng = || { i += 1; UnitF64(values[(i - 1) % values.len()]) };
+        let sample = sample_klein_nishina(ELECTRON_REST_MASS_ENERGY, &mut rng);
+        assert!((sample.energy - 0.5 * ELECTRON_REST_MASS_ENERGY).abs() < 1e-12);
+        assert!(sample.cos_theta.unwrap().abs() < 1e-12);
+
+        // All samples must satisfy the Compton formula
+        let mut rng = lcg();
+        for energy in [1.0E-03, 1.0, 1.0E+02] {
+            for _ in 0..100 {
+                let sample = sample_klein_nishina(energy, &mut rng);
+                let cos_theta = sample.cos_theta.unwrap();
+                let compton_energy = energy / (1.0 + energy / ELECTRON_REST_MASS_ENERGY * (1.0 - cos_theta));
+                assert!((-1.0..=1.0).contains(&cos_theta));
+                assert!((sample.energy - compton_energy).abs() < 1e-9 * energy);
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_jinc_parsing() {
+        let parsed_ace = get_parsed_photoatomic_test_file().await;
+        let incoherent = parsed_ace.incoherent_scattering.unwrap();
+
+        assert_eq!(incoherent.momentum_transfer, vec![0.0, 1.0, 10.0]);
+        assert_eq!(incoherent.scattering_function, vec![0.0, 0.5, 1.0]);
+        assert_eq!(incoherent.evaluate(0.5), 0.25);
+        assert_eq!(incoherent.evaluate(100.0), 1.0);
+    }
+
+    #[tokio::test]
+    async fn test_incoherent_sampling() {
+        let parsed_ace = get_parsed_photoatomic_test_file().await;
+        let incoherent = parsed_ace.incoherent_scattering.unwrap();
+
+        let mut rng = lcg();
+        for _ in 0..100 {
+            let sample = incoherent.sample_energy(1.0E-02, &mut rng).unwrap();
+            assert!(sample.energy <= 1.0E-02);
+            assert!((-1.0..=1.0).contains(&sample.cos_theta.unwrap()));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_jcoh_parsing() {
+        let parsed_ace = get_parsed_photoatomic_test_file().await;
+        let coherent = parsed_ace.coherent_form_factors.unwrap();
+
+        assert_eq!(coherent.momentum_transfer, vec![0.0, 1.0, 10.0]);
+        assert_eq!(coherent.integrated_form_factor, vec![0.0, 0.5, 1.0]);
+        assert_eq!(coherent.form_factor, vec![1.0, 0.5, 0.0]);
+        assert_eq!(coherent.evaluate(0.5), 0.75);
+        assert_eq!(coherent.integrated(0.5), 0.125);
+    }
+
+    #[tokio::test]
+    async fn test_coherent_sampling() {
+        let parsed_ace = get_parsed_photoatomic_test_file().await;
+        let coherent = parsed_ace.coherent_form_factors.unwrap();
+
+        // At 1 MeV the full integrated form factor is available, so half of it is reached at v = 1
+        let mut rng = || UnitF64(0.5);
+        let sample = coherent.sample_energy(1.0, &mut rng).unwrap();
+        let momentum_factor = MOMENTUM_TRANSFER_FACTOR / ELECTRON_REST_MASS_ENERGY;
+        assert_eq!(sample.energy, 1.0);
+        assert!((sample.cos_theta.unwrap() - (1.0 - 1.0 / momentum_factor.powi(2))).abs() < 1e-12);
+
+        let mut rng = lcg();
+        for _ in 0..100 {
+            let sample = coherent.sample_energy(1.0E-02, &mut rng).unwrap();
+            assert!((-1.0..=1.0).contains(&sample.cos_theta.unwrap()));
+        }
+    }
+}
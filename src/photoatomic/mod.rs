@@ -0,0 +1,17 @@
+mod photoatomic_arrays;
+mod eszg;
+mod form_factors;
+mod fluorescence;
+mod electron_shells;
+mod photoatomic_data;
+
+// Structs
+pub use photoatomic_arrays::{PhotoatomicNxsArray, PhotoatomicJxsArray};
+pub use eszg::PhotoatomicCrossSections;
+pub use form_factors::{IncoherentScatteringFunction, CoherentFormFactors};
+pub use fluorescence::Fluorescence;
+pub use electron_shells::{ElectronShells, ComptonProfile};
+pub use photoatomic_data::PhotoatomicData;
+
+// Samplers
+pub use form_factors::sample_klein_nishina;
@@ -0,0 +1,91 @@
+use anyhow::Result;
+
+use crate::utils::PaceMmap;
+
+//=====================================================================
+// Represents the NXS array from a continuous-energy photoatomic ("p"
+// class) ACE table. See the ACE format spec for a description. NXS(5)
+// and NXS(6) are only given by the eprdata libraries, and are zero for
+// older mcplib tables.
+//=====================================================================
+#[derive(Clone, Debug, PartialEq)]
+pub struct PhotoatomicNxsArray {
+    pub xxs_len: usize, // Number of entries in XXS array
+    pub z: usize,       // Atomic number
+    pub nes: usize,     // Number of energies in the ESZG block
+    pub nflo: usize,    // Number of fluorescence edges
+    pub nsh: usize,     // Number of electron shells
+    pub nepr: usize,    // Electron/photon relaxation data format (0 for mcplib)
+}
+
+impl PhotoatomicNxsArray {
+    pub fn from_PACE(mmap: &PaceMmap) -> Result<Self> {
+        let nxs_array: &[usize] = mmap.nxs_array();
+
+        Ok(Self {
+            xxs_len: nxs_array[0],
+            z: nxs_array[1],
+            nes: nxs_array[2],
+            nflo: nxs_array[3],
+            nsh: nxs_array[4],
+            nepr: nxs_array[5],
+        })
+    }
+}
+
+//=====================================================================
+// Represents the JXS array from a photoatomic ACE table. Each entry is
+// the (one indexed) start of a block in the XXS array, or 0 if the
+// block is not present.
+//=====================================================================
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PhotoatomicJxsArray {
+    pub eszg: usize,    // Energy grid and cross sections
+    pub jinc: usize,    // Incoherent scattering function
+    pub jcoh: usize,    // Coherent form factors
+    pub jflo: usize,    // Fluorescence data
+    pub lhnm: usize,    // Heating numbers
+    pub lneps: usize,   // Number of electrons per shell
+    pub lbeps: usize,   // Binding energy per shell
+    pub lpips: usize,   // Probability of interaction per shell
+    pub lswd: usize,    // Locators of the Compton profiles in SWD
+    pub swd: usize,     // Compton profiles
+}
+
+impl PhotoatomicJxsArray {
+    pub fn from_PACE(mmap: &PaceMmap) -> Result<Self> {
+        let jxs_array: &[usize] = mmap.jxs_array();
+
+        Ok(Self {
+            eszg: jxs_array[0],
+            jinc: jxs_array[1],
+            jcoh: jxs_array[2],
+            jflo: jxs_array[3],
+            lhnm: jxs_array[4],
+            lneps: jxs_array[5],
+            lbeps: jxs_array[6],
+            lpips: jxs_array[7],
+            lswd: jxs_array[8],
+            swd: jxs_array[9],
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::get_parsed_photoatomic_test_file;
+
+    #[tokio::test]
+    async fn test_photoatomic_array_parsing() {
+        let parsed_ace = get_parsed_photoatomic_test_file().await;
+
+        // Hydrogen, with a single electron shell from an eprdata library
+        assert_eq!(parsed_ace.nxs_array.xxs_len, 101);
+        assert_eq!(parsed_ace.nxs_array.z, 1);
+        assert_eq!(parsed_ace.nxs_array.nes, 3);
+        assert_eq!(parsed_ace.nxs_array.nsh, 1);
+        assert_eq!(parsed_ace.jxs_array.jinc, 16);
+        assert_eq!(parsed_ace.jxs_array.lhnm, 35);
+        assert_eq!(parsed_ace.jxs_array.swd, 42);
+    }
+}
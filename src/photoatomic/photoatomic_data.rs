@@ -0,0 +1,191 @@
+use std::path::Path;
+use std::error::Error;
+
+use crate::unitf64::UnitF64;
+use crate::utils::{ensure_not_ascii, PaceMmap};
+use crate::header::Header;
+use crate::arrays::IzawArray;
+use crate::energy_distributions::{SampleEnergy, SampledEnergy, EnergyDistributionError};
+use crate::photoatomic::{
+    PhotoatomicNxsArray,
+    PhotoatomicJxsArray,
+    PhotoatomicCrossSections,
+    IncoherentScatteringFunction,
+    CoherentFormFactors,
+    Fluorescence,
+    ElectronShells,
+    sample_klein_nishina,
+};
+
+//=====================================================================
+// Continuous-energy photon interaction data from a "p" class ACE
+// table. Both the older mcplib tables and the newer eprdata tables
+// (which add electron shell data) are supported.
+//=====================================================================
+#[derive(Clone, Debug)]
+pub struct PhotoatomicData {
+    pub header: Header,
+    pub izaw_array: IzawArray,
+    pub nxs_array: PhotoatomicNxsArray,
+    pub jxs_array: PhotoatomicJxsArray,
+    pub cross_sections: PhotoatomicCrossSections,                       // ESZG
+    pub incoherent_scattering: Option<IncoherentScatteringFunction>,    // JINC
+    pub coherent_form_factors: Option<CoherentFormFactors>,             // JCOH
+    pub fluorescence: Option<Fluorescence>,                             // JFLO
+    pub heating: Vec<f64>,                                              // LHNM
+    pub electron_shells: Option<ElectronShells>,                        // LNEPS, LBEPS, LPIPS, LSWD and SWD
+}
+
+impl PhotoatomicData {
+    pub fn from_file<P: AsRef<Path>>(file_path: P) -> Result<Self, Box<dyn Error + Send + Sync>> {
+        let path = file_path.as_ref();
+
+        // If we have an ASCII file, request that it first be converted to our own binary format
+        ensure_not_ascii(path)?;
+
+        // Create a memory map of the binary file and parse the table from it
+        Self::from_PACE(&PaceMmap::from_file(path)?)
+    }
+
+    // Parse the table from a memory map of a PACE file
+    pub fn from_PACE(mmap: &PaceMmap) -> Result<Self, Box<dyn Error + Send + Sync>> {
+        // Process the header and arrays
+        let header = Header::from_PACE(mmap)?;
+        let izaw_array = IzawArray::from_PACE(mmap)?;
+        let nxs_array = PhotoatomicNxsArray::from_PACE(mmap)?;
+        let jxs_array = PhotoatomicJxsArray::from_PACE(mmap)?;
+
+        // Process the blocks out of the XXS array
+        let xxs_array = mmap.xxs_array();
+        let cross_sections = PhotoatomicCrossSections::process(xxs_array, &nxs_array, &jxs_array)?;
+        let incoherent_scattering = IncoherentScatteringFunction::process(xxs_array, &jxs_array)?;
+        let coherent_form_factors = CoherentFormFactors::process(xxs_array, &jxs_array)?;
+        let fluorescence = Fluorescence::process(xxs_array, &nxs_array, &jxs_array)?;
+        let heating = match jxs_array.lhnm {
+            0 => Vec::new(),
+            lhnm => xxs_array[lhnm - 1..lhnm - 1 + nxs_array.nes].to_vec(),
+        };
+        let electron_shells = ElectronShells::process(xxs_array, &nxs_array, &jxs_array)?;
+
+        Ok(Self {
+            header,
+            izaw_array,
+            nxs_array,
+            jxs_array,
+            cross_sections,
+            incoherent_scattering,
+            coherent_form_factors,
+            fluorescence,
+            heating,
+            electron_shells,
+        })
+    }
+
+    // ZAID of the table
+    #[inline]
+    pub fn zaid(&self) -> String {
+        self.header.zaid.clone()
+    }
+
+    // SZAID of the table (version 2.0.0 and later)
+    #[inline]
+    pub fn szaid(&self) -> Option<String> {
+        self.header.szaid.clone()
+    }
+
+    // Atomic number of the element
+    #[inline]
+    pub fn z(&self) -> usize {
+        self.nxs_array.z
+    }
+
+    // Incoherent (Compton) scattering cross section at an energy (given in MeV)
+    pub fn incoherent_xs(&self, energy: f64) -> f64 {
+        self.cross_sections.incoherent_xs(energy)
+    }
+
+    // Coherent (Rayleigh) scattering cross section at an energy (given in MeV)
+    pub fn coherent_xs(&self, energy: f64) -> f64 {
+        self.cross_sections.coherent_xs(energy)
+    }
+
+    // Photoelectric absorption cross section at an energy (given in MeV)
+    pub fn photoelectric_xs(&self, energy: f64) -> f64 {
+        self.cross_sections.photoelectric_xs(energy)
+    }
+
+    // Pair production cross section at an energy (given in MeV)
+    pub fn pair_production_xs(&self, energy: f64) -> f64 {
+        self.cross_sections.pair_production_xs(energy)
+    }
+
+    // Total photon interaction cross section at an energy (given in MeV)
+    pub fn total_xs(&self, energy: f64) -> f64 {
+        self.cross_sections.total_xs(energy)
+    }
+
+    // Sample an incoherent scattering event. Without an incoherent scattering function, the
+    // electrons are treated as free and the Klein-Nishina distribution is sampled directly.
+    pub fn sample_incoherent<R: FnMut() -> UnitF64>(&self, energy: f64, rng: &mut R) -> Result<SampledEnergy, EnergyDistributionError> {
+        match &self.incoherent_scattering {
+            Some(incoherent_scattering) => incoherent_scattering.sample_energy(energy, rng),
+            None => Ok(sample_klein_nishina(energy, rng)),
+        }
+    }
+
+    // Sample a coherent scattering event
+    pub fn sample_coherent<R: FnMut() -> UnitF64>(&self, energy: f64, rng: &mut R) -> Result<SampledEnergy, EnergyDistributionError> {
+        self.coherent_form_factors
+            .as_ref()
+            .ok_or(EnergyDistributionError::EmptyDistribution)?
+            .sample_energy(energy, rng)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::utils::get_parsed_photoatomic_test_file;
+    use crate::pace_error::PaceError;
+
+    #[tokio::test]
+    async fn test_parse_photoatomic_test_file() {
+        let parsed_ace = get_parsed_photoatomic_test_file().await;
+        assert_eq!(parsed_ace.szaid(), Some(String::from("1000.14p")));
+        assert_eq!(parsed_ace.zaid(), String::from("1000.14p"));
+        assert_eq!(parsed_ace.z(), 1);
+        assert_eq!(parsed_ace.heating, vec![1.0E-03, 5.0E-03, 5.0E-02]);
+    }
+
+    #[test]
+    fn test_reject_ascii() {
+        let result = PhotoatomicData::from_file("LICENSE");
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_photoatomic_sampling() {
+        let parsed_ace = get_parsed_photoatomic_test_file().await;
+
+        // Coherent scattering leaves the energy unchanged, incoherent scattering never increases it
+        let mut rng = || UnitF64(0.5);
+        let sample = parsed_ace.sample_coherent(1.0, &mut rng).unwrap();
+        assert_eq!(sample.energy, 1.0);
+        let sample = parsed_ace.sample_incoherent(1.0, &mut rng).unwrap();
+        assert!(sample.energy < 1.0);
+    }
+
+    #[tokio::test]
+    async fn test_truncated_table() {
+        let parsed_ace = get_parsed_photoatomic_test_file().await;
+        let mmap = PaceMmap::from_file("test_nuclear_data_files/1000.14p.pace").unwrap();
+
+        // Cut the XXS array off part way through the ESZG block
+        let (nxs, jxs) = (&parsed_ace.nxs_array, &parsed_ace.jxs_array);
+        let xxs = mmap.xxs_array().slice(0, jxs.eszg + nxs.nes).unwrap();
+        let error = PhotoatomicCrossSections::process(xxs, nxs, jxs).unwrap_err();
+        assert!(matches!(error, PaceError::InTableBlock { block: "ESZG", .. }), "{}", error);
+    }
+
+}
@@ -0,0 +1,10 @@
+mod photonuclear_arrays;
+mod photonuclear_cross_sections;
+mod secondary_particles;
+mod photonuclear_data;
+
+// Structs
+pub use photonuclear_arrays::{PhotonuclearNxsArray, PhotonuclearJxsArray};
+pub use photonuclear_cross_sections::PhotonuclearCrossSections;
+pub use secondary_particles::{PhotonuclearParticleLocators, PhotonuclearParticleProduction};
+pub use photonuclear_data::PhotonuclearData;
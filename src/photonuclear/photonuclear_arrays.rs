@@ -0,0 +1,145 @@
+use anyhow::Result;
+use strum::IntoEnumIterator;
+
+use crate::arrays::{NxsArray, JxsArray};
+use crate::blocks::BlockType;
+use crate::utils::PaceMmap;
+
+//=====================================================================
+// Represents the NXS array from a photonuclear ("u" class) ACE table.
+// See the ACE format spec for a description. While much of the data
+// in these tables follows the continuous neutron layout, the NXS and
+// JXS entries are ordered differently.
+//=====================================================================
+#[derive(Clone, Debug, PartialEq)]
+pub struct PhotonuclearNxsArray {
+    pub xxs_len: usize, // Number of entries in XXS array
+    pub za: usize,      // ZA of the target
+    pub nes: usize,     // Number of energies
+    pub ntr: usize,     // Number of reactions
+    pub ntype: usize,   // Number of secondary particle types
+    pub npixs: usize,   // Number of entries in the IXSA array per particle type
+    pub neixs: usize,   // Number of entries in the IXS array per particle type
+}
+
+impl PhotonuclearNxsArray {
+    pub fn from_PACE(mmap: &PaceMmap) -> Result<Self> {
+        let nxs_array: &[usize] = mmap.nxs_array();
+
+        Ok(Self {
+            xxs_len: nxs_array[0],
+            za: nxs_array[1],
+            nes: nxs_array[2],
+            ntr: nxs_array[3],
+            ntype: nxs_array[4],
+            npixs: nxs_array[5],
+            neixs: nxs_array[6],
+        })
+    }
+}
+
+//=====================================================================
+// Represents the JXS array from a photonuclear ACE table. Each entry
+// is the (one indexed) start of a block in the XXS array, or 0 if the
+// block is not present.
+//=====================================================================
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PhotonuclearJxsArray {
+    pub esz: usize,     // Energy grid
+    pub tot: usize,     // Total cross section
+    pub non: usize,     // Nonelastic cross section
+    pub els: usize,     // Elastic cross section
+    pub thn: usize,     // Average heating numbers
+    pub mtr: usize,     // MT array
+    pub lqr: usize,     // Q-value array
+    pub lsig: usize,    // Table of cross section locators
+    pub sig: usize,     // Cross sections
+    pub ixsa: usize,    // Secondary particle types and number of producing reactions
+    pub ixs: usize,     // Table of secondary particle production locators
+}
+
+impl PhotonuclearJxsArray {
+    pub fn from_PACE(mmap: &PaceMmap) -> Result<Self> {
+        let jxs_array: &[usize] = mmap.jxs_array();
+
+        Ok(Self {
+            esz: jxs_array[0],
+            tot: jxs_array[1],
+            non: jxs_array[2],
+            els: jxs_array[3],
+            thn: jxs_array[4],
+            mtr: jxs_array[5],
+            lqr: jxs_array[6],
+            lsig: jxs_array[7],
+            sig: jxs_array[8],
+            ixsa: jxs_array[9],
+            ixs: jxs_array[10],
+        })
+    }
+
+    // The MTR, LQR, LSIG and SIG blocks share the continuous neutron layout. Map them onto neutron
+    // NXS and JXS arrays so that they can be parsed with the neutron blocks, leaving all other
+    // neutron blocks absent.
+    pub(crate) fn to_neutron_arrays(&self, nxs: &PhotonuclearNxsArray) -> (NxsArray, JxsArray) {
+        let neutron_nxs = NxsArray {
+            xxs_len: nxs.xxs_len,
+            za: nxs.za,
+            nes: nxs.nes,
+            ntr: nxs.ntr,
+            nr: 0,
+            ntrp: 0,
+            ntype: 0,
+            npcr: 0,
+            s: 0,
+            z: nxs.za / 1000,
+            a: nxs.za % 1000,
+        };
+
+        let mut neutron_jxs = JxsArray::default();
+        for block_type in BlockType::iter() {
+            let start = match block_type {
+                BlockType::MTR => self.mtr,
+                BlockType::LQR => self.lqr,
+                BlockType::LSIG => self.lsig,
+                BlockType::SIG => self.sig,
+                _ => 0,
+            };
+            neutron_jxs.insert(block_type, start);
+        }
+
+        (neutron_nxs, neutron_jxs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::blocks::BlockType;
+    use crate::utils::get_parsed_photonuclear_test_file;
+
+    #[tokio::test]
+    async fn test_photonuclear_array_parsing() {
+        let parsed_ace = get_parsed_photonuclear_test_file().await;
+
+        // Carbon-12, with a single reaction producing neutrons
+        assert_eq!(parsed_ace.nxs_array.xxs_len, 71);
+        assert_eq!(parsed_ace.nxs_array.za, 6012);
+        assert_eq!(parsed_ace.nxs_array.ntr, 1);
+        assert_eq!(parsed_ace.nxs_array.ntype, 1);
+        assert_eq!(parsed_ace.nxs_array.neixs, 10);
+        assert_eq!(parsed_ace.jxs_array.thn, 13);
+        assert_eq!(parsed_ace.jxs_array.sig, 19);
+        assert_eq!(parsed_ace.jxs_array.ixs, 25);
+    }
+
+    #[tokio::test]
+    async fn test_neutron_array_mapping() {
+        let parsed_ace = get_parsed_photonuclear_test_file().await;
+        let (nxs, jxs) = parsed_ace.jxs_array.to_neutron_arrays(&parsed_ace.nxs_array);
+
+        assert_eq!(nxs.ntr, 1);
+        assert_eq!((nxs.z, nxs.a), (6, 12));
+        assert_eq!(jxs.get(&BlockType::MTR), 16);
+        assert_eq!(jxs.get(&BlockType::SIG), 19);
+        assert_eq!(jxs.get(&BlockType::ESZ), 0);
+    }
+}
@@ -0,0 +1,100 @@
+use crate::blocks::{ESZ, CrossSection};
+use crate::photonuclear::{PhotonuclearNxsArray, PhotonuclearJxsArray};
+use crate::arrays::{XxsArray, XxsWords};
+use crate::pace_error::{PaceError, locator_to_index};
+
+// MT numbers of the cross sections given on the main energy grid
+const TOTAL_MT: usize = 1;
+const ELASTIC_MT: usize = 2;
+const NONELASTIC_MT: usize = 3;
+
+//=====================================================================
+// ESZ, TOT, NON, ELS and THN data blocks
+//
+// Unlike the ESZ block of continuous neutron tables, the energy grid,
+// total, nonelastic and elastic cross sections and heating numbers of
+// photonuclear tables are each located through their own JXS entry.
+// Each has NXS(3) entries, and the elastic cross section is omitted
+// (JXS(4) = 0) for most targets.
+//=====================================================================
+#[derive(Debug, Clone)]
+pub struct PhotonuclearCrossSections {
+    pub energy: Vec<f64>,
+    pub total: CrossSection,
+    pub nonelastic: CrossSection,
+    pub elastic: Option<CrossSection>,
+    pub average_heating_numbers: Vec<f64>,
+}
+
+impl PhotonuclearCrossSections {
+    pub fn process(xxs: XxsArray<'_>, nxs: &PhotonuclearNxsArray, jxs: &PhotonuclearJxsArray) -> Result<Self, PaceError> {
+        let num_energies = nxs.nes;
+        let values = |locator: usize| Ok::<_, PaceError>(xxs.floats(locator_to_index(locator)?, num_energies)?.to_vec());
+        let energy = values(jxs.esz)?;
+        let cross_section = |mt: usize, locator: usize| Ok::<_, PaceError>(CrossSection { mt, energy: energy.clone(), xs_val: values(locator)? });
+
+        Ok(Self {
+            total: cross_section(TOTAL_MT, jxs.tot)?,
+            nonelastic: cross_section(NONELASTIC_MT, jxs.non)?,
+            elastic: match jxs.els {
+                0 => None,
+                els => Some(cross_section(ELASTIC_MT, els)?),
+            },
+            average_heating_numbers: match jxs.thn {
+                0 => Vec::new(),
+                thn => values(thn)?,
+            },
+            energy,
+        })
+    }
+
+    // The reaction cross sections in SIG and the production data refer to the energy grid through
+    // an ESZ block, so we present the grid in that form
+    pub(crate) fn to_esz(&self) -> ESZ {
+        ESZ {
+            energy: self.energy.clone(),
+            total_xs: self.total.xs_val.clone(),
+            dissapearance_xs: Vec::new(),
+            elastic_xs: self.elastic.as_ref().map_or_else(Vec::new, |elastic| elastic.xs_val.clone()),
+            average_heating_numbers: self.average_heating_numbers.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::utils::get_parsed_photonuclear_test_file;
+
+    #[tokio::test]
+    async fn test_photonuclear_cross_section_parsing() {
+        let parsed_ace = get_parsed_photonuclear_test_file().await;
+        let cross_sections = parsed_ace.cross_sections;
+
+        assert_eq!(cross_sections.energy, vec![1.0E+01, 2.0E+01, 3.0E+01]);
+        assert_eq!(cross_sections.total.xs_val, vec![1.0, 2.0, 1.5]);
+        assert_eq!(cross_sections.nonelastic.xs_val, vec![0.5, 1.5, 1.0]);
+        assert_eq!(cross_sections.elastic.unwrap().xs_val, vec![0.5, 0.5, 0.5]);
+        assert_eq!(cross_sections.average_heating_numbers, vec![0.1, 1.0, 2.0]);
+    }
+
+    #[tokio::test]
+    async fn test_photonuclear_cross_section_errors() {
+        let parsed_ace = get_parsed_photonuclear_test_file().await;
+        let xxs = [1.0, 2.0, 3.0, 4.0, 5.0];
+
+        // The total cross section runs past the end of the XXS array
+        let mut jxs = parsed_ace.jxs_array.clone();
+        (jxs.esz, jxs.tot, jxs.non, jxs.els, jxs.thn) = (1, 4, 1, 0, 0);
+        let nxs = PhotonuclearNxsArray { nes: 3, ..parsed_ace.nxs_array.clone() };
+        assert!(matches!(
+            PhotonuclearCrossSections::process(XxsArray::untyped(&xxs), &nxs, &jxs),
+            Err(PaceError::LocatorOutOfBounds { index: 5, length: 5 })
+        ));
+
+        // The energy grid is required, so a zero locator is invalid
+        jxs.esz = 0;
+        assert!(matches!(PhotonuclearCrossSections::process(XxsArray::untyped(&xxs), &nxs, &jxs), Err(PaceError::InvalidLocator(0))));
+    }
+}
@@ -0,0 +1,171 @@
+use std::path::Path;
+use std::error::Error;
+
+use crate::utils::{ensure_not_ascii, PaceMmap};
+use crate::header::Header;
+use crate::arrays::{Arrays, IzawArray};
+use crate::blocks::{Parse, MTR, LQR, LSIG, SIG, ParticleType, ParticleProductionData};
+use crate::photonuclear::{
+    PhotonuclearNxsArray,
+    PhotonuclearJxsArray,
+    PhotonuclearCrossSections,
+    PhotonuclearParticleProduction,
+};
+
+//=====================================================================
+// Photonuclear data from a "u" class ACE table. The reaction cross
+// sections (MTR, LQR, LSIG and SIG) are parsed with the continuous
+// neutron blocks, while the energy grid and secondary particle data
+// are handled separately as their layouts differ.
+//=====================================================================
+#[derive(Clone, Debug)]
+pub struct PhotonuclearData {
+    pub header: Header,
+    pub izaw_array: IzawArray,
+    pub nxs_array: PhotonuclearNxsArray,
+    pub jxs_array: PhotonuclearJxsArray,
+    pub cross_sections: PhotonuclearCrossSections,                      // ESZ, TOT, NON, ELS and THN
+    pub mtr: Option<MTR>,                                               // MTR
+    pub lqr: Option<LQR>,                                               // LQR
+    pub sig: Option<SIG>,                                               // LSIG and SIG
+    pub particle_production: Option<PhotonuclearParticleProduction>,    // IXSA and IXS
+}
+
+impl PhotonuclearData {
+    pub fn from_file<P: AsRef<Path>>(file_path: P) -> Result<Self, Box<dyn Error + Send + Sync>> {
+        let path = file_path.as_ref();
+
+        // If we have an ASCII file, request that it first be converted to our own binary format
+        ensure_not_ascii(path)?;
+
+        // Create a memory map of the binary file and parse the table from it
+        Self::from_PACE(&PaceMmap::from_file(path)?)
+    }
+
+    // Parse the table from a memory map of a PACE file
+    pub fn from_PACE(mmap: &PaceMmap) -> Result<Self, Box<dyn Error + Send + Sync>> {
+        // Process the header and arrays
+        let header = Header::from_PACE(mmap)?;
+        let izaw_array = IzawArray::from_PACE(mmap)?;
+        let nxs_array = PhotonuclearNxsArray::from_PACE(mmap)?;
+        let jxs_array = PhotonuclearJxsArray::from_PACE(mmap)?;
+
+        // Process the energy grid and its cross sections
+        let xxs_array = mmap.xxs_array();
+        let cross_sections = PhotonuclearCrossSections::process(xxs_array, &nxs_array, &jxs_array)?;
+        let esz = cross_sections.to_esz();
+
+        // Process the reaction cross sections with the neutron blocks
+        let (neutron_nxs, neutron_jxs) = jxs_array.to_neutron_arrays(&nxs_array);
+        let arrays = Arrays { nxs: &neutron_nxs, jxs: &neutron_jxs, xxs: xxs_array };
+        let mtr = MTR::parse(&arrays, ())?;
+        let lqr = LQR::parse(&arrays, &mtr)?;
+        let lsig = LSIG::parse(&arrays, ())?;
+
+        // Process the secondary particle data
+        let particle_production = PhotonuclearParticleProduction::process(
+            xxs_array,
+            &nxs_array,
+            &jxs_array,
+            (&esz, &lqr, header.atomic_mass_fraction),
+        )?;
+
+        // SIG is processed last, as it takes the energy grid as an ESZ block
+        let sig = SIG::parse(&arrays, (&mtr, &lsig, &Some(esz)))?;
+
+        Ok(Self { header, izaw_array, nxs_array, jxs_array, cross_sections, mtr, lqr, sig, particle_production })
+    }
+
+    // ZAID of the table
+    #[inline]
+    pub fn zaid(&self) -> String {
+        self.header.zaid.clone()
+    }
+
+    // SZAID of the table (version 2.0.0 and later)
+    #[inline]
+    pub fn szaid(&self) -> Option<String> {
+        self.header.szaid.clone()
+    }
+
+    // ZA of the target
+    #[inline]
+    pub fn za(&self) -> usize {
+        self.nxs_array.za
+    }
+
+    // Total photonuclear cross section at an incident photon energy (given in MeV)
+    pub fn total_xs(&self, energy: f64) -> f64 {
+        self.cross_sections.total.evaluate(energy)
+    }
+
+    // Nonelastic photonuclear cross section at an incident photon energy (given in MeV)
+    pub fn nonelastic_xs(&self, energy: f64) -> f64 {
+        self.cross_sections.nonelastic.evaluate(energy)
+    }
+
+    // Elastic photonuclear cross section at an incident photon energy (given in MeV)
+    pub fn elastic_xs(&self, energy: f64) -> f64 {
+        self.cross_sections.elastic.as_ref().map_or(0.0, |elastic| elastic.evaluate(energy))
+    }
+
+    // Cross section of reaction `mt` at an incident photon energy (given in MeV), None if the
+    // reaction is not present in the table
+    pub fn reaction_xs(&self, mt: usize, energy: f64) -> Option<f64> {
+        self.sig.as_ref()?.get(&mt).map(|xs| xs.evaluate(energy))
+    }
+
+    // Production data for a secondary particle type, e.g. neutrons for (γ,n) production
+    pub fn production(&self, particle_type: ParticleType) -> Option<&ParticleProductionData> {
+        self.particle_production.as_ref()?.get(&particle_type)
+    }
+
+    // Total production cross section of a secondary particle type at an incident photon energy
+    // (given in MeV), zero if the particle type is not produced
+    pub fn production_xs(&self, particle_type: ParticleType, energy: f64) -> f64 {
+        self.production(particle_type).map_or(0.0, |production| production.total_xs.evaluate(energy))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::utils::get_parsed_photonuclear_test_file;
+
+    #[tokio::test]
+    async fn test_parse_photonuclear_test_file() {
+        let parsed_ace = get_parsed_photonuclear_test_file().await;
+        assert_eq!(parsed_ace.szaid(), Some(String::from("6012.800u")));
+        assert_eq!(parsed_ace.zaid(), String::from("6012.00u"));
+        assert_eq!(parsed_ace.za(), 6012);
+    }
+
+    #[test]
+    fn test_reject_ascii() {
+        let result = PhotonuclearData::from_file("LICENSE");
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_photonuclear_reactions() {
+        let parsed_ace = get_parsed_photonuclear_test_file().await;
+
+        // The reaction blocks are parsed with the neutron blocks
+        assert_eq!(*parsed_ace.mtr.as_ref().unwrap(), MTR(vec![5]));
+        assert_eq!(parsed_ace.lqr.as_ref().unwrap()[&5], -1.872E+01);
+        assert_eq!(parsed_ace.reaction_xs(5, 2.5E+01), Some(1.25));
+        assert_eq!(parsed_ace.reaction_xs(5, 1.0E+01), Some(0.0));
+        assert_eq!(parsed_ace.reaction_xs(102, 2.5E+01), None);
+    }
+
+    #[tokio::test]
+    async fn test_photonuclear_xs() {
+        let parsed_ace = get_parsed_photonuclear_test_file().await;
+        assert_eq!(parsed_ace.total_xs(1.5E+01), 1.5);
+        assert_eq!(parsed_ace.nonelastic_xs(1.5E+01), 1.0);
+        assert_eq!(parsed_ace.elastic_xs(1.5E+01), 0.5);
+        assert_eq!(parsed_ace.production_xs(ParticleType::Neutron, 2.5E+01), 1.0);
+        assert_eq!(parsed_ace.production_xs(ParticleType::Proton, 2.5E+01), 0.0);
+    }
+}
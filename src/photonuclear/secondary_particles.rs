@@ -0,0 +1,191 @@
+use std::collections::HashMap;
+use std::ops::Deref;
+
+use crate::blocks::{ESZ, LQR, CrossSection, ParticleType, ParticleProductionData, ParticleProductionLocators};
+use crate::photonuclear::{PhotonuclearNxsArray, PhotonuclearJxsArray};
+use crate::arrays::{XxsArray, XxsWords};
+use crate::pace_error::{PaceError, get_slice};
+
+type ParticleProductionMap = HashMap<ParticleType, ParticleProductionData>;
+
+//=====================================================================
+// IXSA and IXS data blocks, along with the secondary particle data
+//
+// The IXSA block gives the particle type and number of producing
+// reactions for each of the NXS(5) secondary particle types. The IXS
+// block gives NXS(7) locators per particle type, which point to the
+// PXS, PHN, MTRP, TYRP, LSIGP, SIGP, LANDP, ANDP, LDLWP and DLWP
+// sub-blocks. All locators are absolute in the XXS array. Apart from
+// the total production cross section (PXS) and heating numbers (PHN)
+// being given separately, the sub-blocks have the same layout as the
+// particle production data of continuous neutron tables.
+//=====================================================================
+#[derive(Debug, Clone)]
+pub struct PhotonuclearParticleProduction ( pub ParticleProductionMap );
+
+impl Deref for PhotonuclearParticleProduction {
+    type Target = ParticleProductionMap;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl PhotonuclearParticleProduction {
+    // Process the secondary particle data from the XXS array, None if no secondary particles are given
+    pub fn process(
+        xxs: XxsArray<'_>,
+        nxs: &PhotonuclearNxsArray,
+        jxs: &PhotonuclearJxsArray,
+        (esz, lqr, awr): (&ESZ, &Option<LQR>, f64),
+    ) -> Result<Option<Self>, PaceError> {
+        if nxs.ntype == 0 || jxs.ixsa == 0 || jxs.ixs == 0 {
+            return Ok(None);
+        }
+
+        // Locators are one indexed and absolute, a zero locator maps past the end of the array
+        let index = |locator: usize| locator.checked_sub(1).unwrap_or(usize::MAX);
+        let integer = |locator: usize| xxs.uint(index(locator));
+
+        let particle_production = (0..nxs.ntype)
+            .map(|i| {
+                let particle_type = ParticleType::from(integer(jxs.ixsa + i * nxs.npixs)?);
+                let num_reactions = integer(jxs.ixsa + i * nxs.npixs + 1)?;
+                let locators = PhotonuclearParticleLocators::process(xxs.tail(index(jxs.ixs + i * nxs.neixs))?)?;
+
+                // PXS and PHN: IE and NE, followed by the total production cross section or heating numbers
+                let (energy_start_index, num_energy_points) = (integer(locators.pxs)?, integer(locators.pxs + 1)?);
+                let total_xs = CrossSection {
+                    mt: particle_type.production_mt().unwrap_or(0),
+                    energy: get_slice(&esz.energy, index(energy_start_index), num_energy_points)?.to_vec(),
+                    xs_val: xxs.floats(index(locators.pxs + 2), num_energy_points)?.to_vec(),
+                };
+                let heating = match locators.phn {
+                    0 => Vec::new(),
+                    phn => {
+                        let num_heating_points = integer(phn + 1)?;
+                        xxs.floats(index(phn + 2), num_heating_points)?.to_vec()
+                    },
+                };
+
+                Ok((
+                    particle_type,
+                    ParticleProductionData::process(
+                        xxs,
+                        index,
+                        &locators.as_particle_production_locators(),
+                        num_reactions,
+                        (total_xs, heating),
+                        (esz, lqr, awr),
+                    )?
+                ))
+            })
+            .collect::<Result<_, PaceError>>()?;
+
+        Ok(Some(Self(particle_production)))
+    }
+}
+
+impl std::fmt::Display for PhotonuclearParticleProduction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "PhotonuclearParticleProduction({} particle types)", self.len())
+    }
+}
+
+// Locations of the production data sub-blocks for a single particle type (one row of the IXS
+// array). All locations are one indexed and absolute in the XXS array.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PhotonuclearParticleLocators {
+    pub pxs: usize,     // Total production cross section
+    pub phn: usize,     // Heating numbers
+    pub mtrp: usize,    // Production MT array
+    pub tyrp: usize,    // Frame of reference array
+    pub lsigp: usize,   // Table of production cross section locators
+    pub sigp: usize,    // Production cross sections
+    pub landp: usize,   // Table of angular distribution locators
+    pub andp: usize,    // Angular distributions
+    pub ldlwp: usize,   // Table of energy distribution locators
+    pub dlwp: usize,    // Energy distributions
+}
+
+impl PhotonuclearParticleLocators {
+    fn process(data: XxsArray<'_>) -> Result<Self, PaceError> {
+        let locator = |i: usize| data.uint(i);
+        Ok(Self {
+            pxs: locator(0)?,
+            phn: locator(1)?,
+            mtrp: locator(2)?,
+            tyrp: locator(3)?,
+            lsigp: locator(4)?,
+            sigp: locator(5)?,
+            landp: locator(6)?,
+            andp: locator(7)?,
+            ldlwp: locator(8)?,
+            dlwp: locator(9)?,
+        })
+    }
+
+    // The reaction sub-blocks line up with those of the neutron particle production data, which
+    // has no separate total cross section and heating sub-blocks and no yield multipliers
+    fn as_particle_production_locators(&self) -> ParticleProductionLocators {
+        ParticleProductionLocators {
+            hpd: self.pxs,
+            mtrh: self.mtrp,
+            tyrh: self.tyrp,
+            lsigh: self.lsigp,
+            sigh: self.sigp,
+            landh: self.landp,
+            andh: self.andp,
+            ldlwh: self.ldlwp,
+            dlwh: self.dlwp,
+            yh: 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::blocks::{PhotonProduction, ExitingNeutronFrameOfReference};
+    use crate::angular_distributions::AngularDistribution;
+    use crate::utils::get_parsed_photonuclear_test_file;
+
+    #[tokio::test]
+    async fn test_photonuclear_particle_production_parsing() {
+        let parsed_ace = get_parsed_photonuclear_test_file().await;
+        let particle_production = parsed_ace.particle_production.unwrap();
+
+        // Only neutrons are produced, by MT 5
+        assert_eq!(particle_production.len(), 1);
+        let neutrons = particle_production.get(&ParticleType::Neutron).unwrap();
+        assert_eq!(neutrons.total_xs.mt, 201);
+        assert_eq!(neutrons.total_xs.energy, vec![2.0E+01, 3.0E+01]);
+        assert_eq!(neutrons.total_xs.xs_val, vec![1.2, 0.8]);
+        assert_eq!(neutrons.heating, vec![5.0, 6.0]);
+        assert_eq!(neutrons.mt_values, vec![5]);
+        assert_eq!(neutrons.frames_of_reference.get(&5), Some(&ExitingNeutronFrameOfReference::Laboratory));
+        assert!(neutrons.yield_multipliers.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_photonuclear_particle_sub_block_parsing() {
+        let parsed_ace = get_parsed_photonuclear_test_file().await;
+        let particle_production = parsed_ace.particle_production.unwrap();
+        let neutrons = particle_production.get(&ParticleType::Neutron).unwrap();
+
+        // The neutron yield is given from ENDF File 6
+        let neutron_yield = match neutrons.production.get(&5).unwrap() {
+            PhotonProduction::ParticleYield(neutron_yield) => neutron_yield,
+            _ => panic!("This should be a yield"),
+        };
+        assert_eq!(neutron_yield.neutron_mt, 5);
+        assert_eq!(neutron_yield.yield_table.interpolate(2.5E+01).unwrap(), 1.5);
+
+        // Neutrons are emitted isotropically with an evaporation spectrum
+        let angular_distribution = neutrons.angular_distributions.get(&5).unwrap();
+        assert!(angular_distribution.distributions.iter().all(|distribution| matches!(distribution, AngularDistribution::Isotropic(_))));
+        let energy_distribution = neutrons.energy_distributions.get(&5).unwrap();
+        assert_eq!(energy_distribution.laws[0].law(), 9);
+    }
+}
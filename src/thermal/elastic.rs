@@ -0,0 +1,218 @@
+use crate::unitf64::UnitF64;
+use crate::energy_distributions::{IncidentEnergyGrid, SampleEnergy, SampledEnergy, EnergyDistributionError};
+use crate::thermal::{ThermalNxsArray, ThermalJxsArray};
+use crate::arrays::{XxsArray, XxsWords};
+use crate::pace_error::{PaceError, locator_to_index};
+
+// Elastic scattering modes given by NXS(5)
+const COHERENT_ELASTIC_MODE: usize = 4;
+const MIXED_ELASTIC_MODE: usize = 5;
+
+//=====================================================================
+// ITCE and ITCX data blocks (coherent elastic)
+//
+// Contains the Bragg edges and the cumulative structure factors at
+// each edge. The coherent elastic cross section at an energy E is the
+// structure factor of the last edge below E, divided by E. Scattering
+// does not change the energy of the neutron, and the cosine is fixed
+// by the Bragg edge which the neutron scatters from.
+//=====================================================================
+#[derive(Debug, Clone, PartialEq)]
+pub struct CoherentElastic {
+    pub bragg_edges: Vec<f64>,
+    pub structure_factors: Vec<f64>,
+}
+
+impl CoherentElastic {
+    // Process the coherent elastic data from the XXS array, None if there is no coherent elastic data
+    pub fn process(xxs: XxsArray<'_>, nxs: &ThermalNxsArray, jxs: &ThermalJxsArray) -> Result<Option<Self>, PaceError> {
+        if jxs.itce == 0 || !matches!(nxs.idpnc, COHERENT_ELASTIC_MODE | MIXED_ELASTIC_MODE) {
+            return Ok(None);
+        }
+
+        let itce = jxs.itce - 1;
+        let (num_edges, bragg_edges) = xxs.uint(itce)
+            .and_then(|num_edges| Ok((num_edges, xxs.floats(itce + 1, num_edges)?.to_vec())))
+            .map_err(|error| error.in_table_block("ITCE", itce))?;
+        let structure_factors = locator_to_index(jxs.itcx)
+            .and_then(|itcx| Ok(xxs.floats(itcx, num_edges)?.to_vec()))
+            .map_err(|error| error.in_table_block("ITCX", jxs.itcx.saturating_sub(1)))?;
+        Ok(Some(Self { bragg_edges, structure_factors }))
+    }
+
+    // Number of Bragg edges at or below an energy
+    fn num_edges_below(&self, energy: f64) -> usize {
+        self.bragg_edges.partition_point(|&edge| edge <= energy)
+    }
+
+    // Coherent elastic scattering cross section at an incident energy
+    pub fn evaluate(&self, energy: f64) -> f64 {
+        match self.num_edges_below(energy) {
+            0 => 0.0,
+            n => self.structure_factors[n - 1] / energy,
+        }
+    }
+}
+
+impl SampleEnergy for CoherentElastic {
+    fn sample_energy<R: FnMut() -> UnitF64>(&self, incident_energy: f64, rng: &mut R) -> Result<SampledEnergy, EnergyDistributionError> {
+        let num_edges = self.num_edges_below(incident_energy);
+        if num_edges == 0 {
+            return Err(EnergyDistributionError::EmptyDistribution);
+        }
+
+        // Pick a Bragg edge with probability proportional to its contribution to the structure factor
+        let xi = rng().0 * self.structure_factors[num_edges - 1];
+        let edge = self.structure_factors[..num_edges]
+            .partition_point(|&factor| factor <= xi)
+            .min(num_edges - 1);
+        let cos_theta = 1.0 - 2.0 * self.bragg_edges[edge] / incident_energy;
+
+        Ok(SampledEnergy { energy: incident_energy, cos_theta: Some(cos_theta) })
+    }
+}
+
+
+//=====================================================================
+// ITCE, ITCX and ITCA data blocks (incoherent elastic)
+//
+// Contains the incoherent elastic cross section along with NXS(6) + 1
+// equiprobable cosines at each incident energy. When both coherent and
+// incoherent elastic data are given (NXS(5) = 5), the incoherent data
+// is instead found in the ITCEI, ITCXI and ITCAI blocks with NXS(8) + 1
+// cosines at each incident energy.
+//=====================================================================
+#[derive(Debug, Clone, PartialEq)]
+pub struct IncoherentElastic {
+    pub incident_energy: IncidentEnergyGrid,
+    pub xs: Vec<f64>,
+    pub cos_theta: Vec<Vec<f64>>,
+}
+
+impl IncoherentElastic {
+    // Process the incoherent elastic data from the XXS array, None if there is no incoherent elastic data
+    pub fn process(xxs: XxsArray<'_>, nxs: &ThermalNxsArray, jxs: &ThermalJxsArray) -> Result<Option<Self>, PaceError> {
+        let (itce, itcx, itca, num_cosines) = match nxs.idpnc {
+            COHERENT_ELASTIC_MODE => return Ok(None),
+            MIXED_ELASTIC_MODE => (jxs.itcei, jxs.itcxi, jxs.itcai, nxs.ncli + 1),
+            _ => (jxs.itce, jxs.itcx, jxs.itca, nxs.ncl + 1),
+        };
+        if itce == 0 {
+            return Ok(None);
+        }
+
+        let (num_energies, energy) = xxs.uint(itce - 1)
+            .and_then(|num_energies| Ok((num_energies, xxs.floats(itce, num_energies)?.to_vec())))
+            .map_err(|error| error.in_table_block("ITCE", itce - 1))?;
+        let xs = locator_to_index(itcx)
+            .and_then(|itcx| Ok(xxs.floats(itcx, num_energies)?.to_vec()))
+            .map_err(|error| error.in_table_block("ITCX", itcx.saturating_sub(1)))?;
+        // If no angular data is given, the scattering is isotropic
+        let cos_theta = match itca {
+            0 => vec![Vec::new(); num_energies],
+            itca => xxs.floats(itca - 1, num_energies.saturating_mul(num_cosines))
+                .map_err(|error| error.in_table_block("ITCA", itca - 1))?
+                .chunks_exact(num_cosines)
+                .map(|cosines| cosines.to_vec())
+                .collect(),
+        };
+
+        let incident_energy = IncidentEnergyGrid { energy, ..Default::default() };
+        Ok(Some(Self { incident_energy, xs, cos_theta }))
+    }
+
+    // Incoherent elastic scattering cross section at an incident energy, zero outside of the tabulated range
+    pub fn evaluate(&self, energy: f64) -> f64 {
+        let grid = &self.incident_energy.energy;
+        if grid.is_empty() || energy < grid[0] || energy > grid[grid.len() - 1] {
+            return 0.0;
+        }
+        let (bin, factor) = self.incident_energy.interpolation_factor(energy);
+        let upper = self.xs.get(bin + 1).unwrap_or(&self.xs[bin]);
+        self.xs[bin] + factor * (upper - self.xs[bin])
+    }
+}
+
+impl SampleEnergy for IncoherentElastic {
+    fn sample_energy<R: FnMut() -> UnitF64>(&self, incident_energy: f64, rng: &mut R) -> Result<SampledEnergy, EnergyDistributionError> {
+        if self.incident_energy.is_empty() {
+            return Err(EnergyDistributionError::EmptyDistribution);
+        }
+        let (bin, factor) = self.incident_energy.interpolation_factor(incident_energy);
+        let lower = &self.cos_theta[bin];
+        let upper = self.cos_theta.get(bin + 1).unwrap_or(lower);
+
+        // Pick one of the equiprobable cosines and interpolate it between incident energies
+        let cos_theta = if lower.is_empty() {
+            2.0 * rng().0 - 1.0
+        } else {
+            let k = ((rng().0 * lower.len() as f64) as usize).min(lower.len() - 1);
+            lower[k] + factor * (upper[k] - lower[k])
+        };
+
+        Ok(SampledEnergy { energy: incident_energy, cos_theta: Some(cos_theta) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::utils::get_parsed_thermal_test_file;
+
+    #[tokio::test]
+    async fn test_coherent_elastic_parsing() {
+        let parsed_ace = get_parsed_thermal_test_file().await;
+        let coherent = parsed_ace.coherent_elastic.unwrap();
+
+        assert_eq!(coherent.bragg_edges, vec![1.0E-03, 2.0E-03]);
+        assert_eq!(coherent.structure_factors, vec![1.0E-03, 3.0E-03]);
+        assert_eq!(coherent.evaluate(5.0E-04), 0.0);
+        assert!((coherent.evaluate(1.5E-03) - 2.0 / 3.0).abs() < 1e-12);
+        assert!((coherent.evaluate(1.0) - 3.0E-03).abs() < 1e-12);
+    }
+
+    #[tokio::test]
+    async fn test_coherent_elastic_sampling() {
+        let parsed_ace = get_parsed_thermal_test_file().await;
+        let coherent = parsed_ace.coherent_elastic.unwrap();
+
+        // Only the first edge is available just above it
+        let mut rng = || UnitF64(0.9);
+        let sample = coherent.sample_energy(1.0E-03, &mut rng).unwrap();
+        assert_eq!(sample.energy, 1.0E-03);
+        assert!((sample.cos_theta.unwrap() + 1.0).abs() < 1e-12);
+
+        // The second edge makes up two thirds of the structure factor
+        let sample = coherent.sample_energy(4.0E-03, &mut rng).unwrap();
+        assert!((sample.cos_theta.unwrap() - 0.0).abs() < 1e-12);
+        let mut rng = || UnitF64(0.2);
+        let sample = coherent.sample_energy(4.0E-03, &mut rng).unwrap();
+        assert!((sample.cos_theta.unwrap() - 0.5).abs() < 1e-12);
+
+        // Below the first edge, there is no coherent scattering
+        assert!(coherent.sample_energy(5.0E-04, &mut rng).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_incoherent_elastic_parsing() {
+        let parsed_ace = get_parsed_thermal_test_file().await;
+        let incoherent = parsed_ace.incoherent_elastic.unwrap();
+
+        assert_eq!(incoherent.incident_energy.energy, vec![1.0E-05, 1.0]);
+        assert_eq!(incoherent.xs, vec![5.0, 6.0]);
+        assert_eq!(incoherent.cos_theta, vec![vec![-0.5, 0.5], vec![-1.0, 1.0]]);
+        assert_eq!(incoherent.evaluate(1.0), 6.0);
+    }
+
+    #[tokio::test]
+    async fn test_incoherent_elastic_sampling() {
+        let parsed_ace = get_parsed_thermal_test_file().await;
+        let incoherent = parsed_ace.incoherent_elastic.unwrap();
+
+        let mut rng = || UnitF64(0.75);
+        let sample = incoherent.sample_energy(1.0, &mut rng).unwrap();
+        assert_eq!(sample.energy, 1.0);
+        assert_eq!(sample.cos_theta, Some(1.0));
+    }
+}
@@ -0,0 +1,325 @@
+use crate::unitf64::UnitF64;
+use crate::interpolation::InterpolationScheme;
+use crate::energy_distributions::{
+    IncidentEnergyGrid,
+    TabulatedEnergyDistribution,
+    SampleEnergy,
+    SampledEnergy,
+    EnergyDistributionError,
+};
+use crate::thermal::{ThermalNxsArray, ThermalJxsArray};
+use crate::arrays::{XxsArray, XxsWords};
+use crate::pace_error::{PaceError, locator_to_index};
+
+// Secondary energy modes given by NXS(7)
+const SKEWED_ENERGY_MODE: usize = 1;
+const CONTINUOUS_ENERGY_MODE: usize = 2;
+
+//=====================================================================
+// ITIE, ITIX and ITXE data blocks
+//
+// Contains the incoherent inelastic scattering cross section and the
+// correlated outgoing energy and angle distributions for each incident
+// energy. See the ACE format spec for a description of these blocks.
+//=====================================================================
+#[derive(Debug, Clone, PartialEq)]
+pub struct InelasticScattering {
+    pub incident_energy: IncidentEnergyGrid,
+    pub xs: Vec<f64>,
+    pub distribution: InelasticDistribution,
+}
+
+impl InelasticScattering {
+    // Process the inelastic scattering data from the XXS array, None if there is no inelastic data
+    pub fn process(xxs: XxsArray<'_>, nxs: &ThermalNxsArray, jxs: &ThermalJxsArray) -> Result<Option<Self>, PaceError> {
+        if jxs.itie == 0 {
+            return Ok(None);
+        }
+
+        // ITIE: NE, followed by the incident energies, ITIX: the cross section at each energy
+        let itie = jxs.itie - 1;
+        let (num_energies, energy) = xxs.uint(itie)
+            .and_then(|num_energies| Ok((num_energies, xxs.floats(itie + 1, num_energies)?.to_vec())))
+            .map_err(|error| error.in_table_block("ITIE", itie))?;
+        let xs = locator_to_index(jxs.itix)
+            .and_then(|itix| Ok(xxs.floats(itix, num_energies)?.to_vec()))
+            .map_err(|error| error.in_table_block("ITIX", jxs.itix.saturating_sub(1)))?;
+
+        // ITXE: outgoing energies and cosines in one of three formats
+        let distribution = locator_to_index(jxs.itxe)
+            .and_then(|itxe| match nxs.ifeng {
+                CONTINUOUS_ENERGY_MODE => Ok(InelasticDistribution::Continuous(
+                    ContinuousInelasticDistribution::process(xxs, itxe, num_energies, nxs.nil)?
+                )),
+                ifeng => Ok(InelasticDistribution::Discrete(
+                    DiscreteInelasticDistribution::process(xxs.tail(itxe)?, num_energies, nxs.nieb, nxs.nil, ifeng == SKEWED_ENERGY_MODE)?
+                )),
+            })
+            .map_err(|error| error.in_table_block("ITXE", jxs.itxe.saturating_sub(1)))?;
+
+        let incident_energy = IncidentEnergyGrid { energy, ..Default::default() };
+        Ok(Some(Self { incident_energy, xs, distribution }))
+    }
+
+    // Inelastic scattering cross section at an incident energy, zero outside of the tabulated range
+    pub fn evaluate(&self, energy: f64) -> f64 {
+        let grid = &self.incident_energy.energy;
+        if grid.is_empty() || energy < grid[0] || energy > grid[grid.len() - 1] {
+            return 0.0;
+        }
+        let (bin, factor) = self.incident_energy.interpolation_factor(energy);
+        let upper = self.xs.get(bin + 1).unwrap_or(&self.xs[bin]);
+        self.xs[bin] + factor * (upper - self.xs[bin])
+    }
+}
+
+impl SampleEnergy for InelasticScattering {
+    fn sample_energy<R: FnMut() -> UnitF64>(&self, incident_energy: f64, rng: &mut R) -> Result<SampledEnergy, EnergyDistributionError> {
+        if self.incident_energy.is_empty() {
+            return Err(EnergyDistributionError::EmptyDistribution);
+        }
+        let (bin, factor) = self.incident_energy.interpolation_factor(incident_energy);
+        match &self.distribution {
+            InelasticDistribution::Discrete(distribution) => distribution.sample(bin, factor, rng),
+            InelasticDistribution::Continuous(distribution) => distribution.sample(bin, factor, rng),
+        }
+    }
+}
+
+// The outgoing energy and angle data in ITXE is given either as discrete outgoing energies
+// (equiprobable or skewed, NXS(7) = 0 or 1) or as continuous distributions (NXS(7) = 2)
+#[derive(Debug, Clone, PartialEq)]
+pub enum InelasticDistribution {
+    Discrete(DiscreteInelasticDistribution),
+    Continuous(ContinuousInelasticDistribution),
+}
+
+
+//=====================================================================
+// Discrete outgoing energies
+//
+// For each incident energy, NXS(4) outgoing energies are given, each
+// followed by NXS(3) + 1 equiprobable cosines. The outgoing energies
+// are equally likely, or for skewed data, the first and last two
+// energies are less likely so as to better represent the tails of the
+// distribution.
+//=====================================================================
+#[derive(Debug, Clone, PartialEq)]
+pub struct DiscreteInelasticDistribution {
+    pub skewed: bool,
+    pub energy_out: Vec<Vec<f64>>,
+    pub cos_theta: Vec<Vec<Vec<f64>>>,
+}
+
+impl DiscreteInelasticDistribution {
+    pub fn process(data: XxsArray<'_>, num_energies: usize, num_energy_out: usize, nil: usize, skewed: bool) -> Result<Self, PaceError> {
+        let num_cosines = nil + 1;
+        let entry_length = num_cosines + 1;
+        let table_length = num_energy_out.saturating_mul(entry_length);
+        let tables = (0..num_energies)
+            .map(|i| data.floats(i * table_length, table_length))
+            .collect::<Result<Vec<_>, PaceError>>()?;
+        let (energy_out, cos_theta) = tables
+            .into_iter()
+            .map(|table| {
+                table
+                    .chunks_exact(entry_length)
+                    .map(|entry| (entry[0], entry[1..].to_vec()))
+                    .unzip()
+            })
+            .unzip();
+        Ok(Self { skewed, energy_out, cos_theta })
+    }
+
+    // Pick an outgoing energy, then interpolate its energy and a randomly chosen cosine between incident energies
+    fn sample<R: FnMut() -> UnitF64>(&self, bin: usize, factor: f64, rng: &mut R) -> Result<SampledEnergy, EnergyDistributionError> {
+        let lower = bin;
+        let upper = (bin + 1).min(self.energy_out.len() - 1);
+        let num_energy_out = self.energy_out[lower].len();
+        if num_energy_out == 0 {
+            return Err(EnergyDistributionError::EmptyDistribution);
+        }
+
+        let j = if self.skewed && num_energy_out > 4 {
+            // The first and last bins have relative probability 0.1, the second and second to last 0.4
+            let r = rng().0 * (num_energy_out - 3) as f64;
+            match r {
+                r if r >= 1.0 => (r as usize + 1).min(num_energy_out - 3),
+                r if r >= 0.6 => num_energy_out - 2,
+                r if r >= 0.5 => num_energy_out - 1,
+                r if r >= 0.1 => 1,
+                _ => 0,
+            }
+        } else {
+            ((rng().0 * num_energy_out as f64) as usize).min(num_energy_out - 1)
+        };
+        let energy = self.energy_out[lower][j] + factor * (self.energy_out[upper][j] - self.energy_out[lower][j]);
+
+        let num_cosines = self.cos_theta[lower][j].len();
+        if num_cosines == 0 {
+            return Err(EnergyDistributionError::EmptyDistribution);
+        }
+        let k = ((rng().0 * num_cosines as f64) as usize).min(num_cosines - 1);
+        let cos_theta = self.cos_theta[lower][j][k] + factor * (self.cos_theta[upper][j][k] - self.cos_theta[lower][j][k]);
+
+        Ok(SampledEnergy { energy, cos_theta: Some(cos_theta) })
+    }
+}
+
+
+//=====================================================================
+// Continuous outgoing energies
+//
+// ITXE starts with a locator and the number of outgoing energies for
+// each incident energy. The locators are absolute in the XXS array and
+// point to the word before the data for the incident energy. For each
+// outgoing energy, the energy, PDF and CDF are followed by NXS(3) - 1
+// equiprobable cosines.
+//=====================================================================
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContinuousInelasticDistribution {
+    pub distributions: Vec<ContinuousInelasticTable>,
+}
+
+// Outgoing energy distribution for a single incident energy, along with the equiprobable
+// cosines for each outgoing energy
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContinuousInelasticTable {
+    pub energy_distribution: TabulatedEnergyDistribution,
+    pub cos_theta: Vec<Vec<f64>>,
+}
+
+impl ContinuousInelasticDistribution {
+    pub fn process(xxs: XxsArray<'_>, itxe: usize, num_energies: usize, nil: usize) -> Result<Self, PaceError> {
+        let num_cosines = nil.saturating_sub(1);
+        let entry_length = num_cosines + 3;
+        let distributions = (0..num_energies)
+            .map(|i| {
+                let start = xxs.uint(itxe + i)?;
+                let num_energy_out = xxs.uint(itxe + num_energies + i)?;
+                let entries = xxs.floats(start, num_energy_out.saturating_mul(entry_length))?.chunks_exact(entry_length);
+                let energy_distribution = TabulatedEnergyDistribution {
+                    interpolation_scheme: InterpolationScheme::LinLin,
+                    num_discrete_lines: 0,
+                    energy_out: entries.clone().map(|entry| entry[0]).collect(),
+                    pdf: entries.clone().map(|entry| entry[1]).collect(),
+                    cdf: entries.clone().map(|entry| entry[2]).collect(),
+                };
+                let cos_theta = entries.map(|entry| entry[3..].to_vec()).collect();
+                Ok(ContinuousInelasticTable { energy_distribution, cos_theta })
+            })
+            .collect::<Result<Vec<_>, PaceError>>()?;
+        Ok(Self { distributions })
+    }
+
+    // Stochastically pick the table of the lower or upper incident energy, sample an outgoing energy
+    // from it, then interpolate a randomly chosen cosine between the neighbouring outgoing energies
+    fn sample<R: FnMut() -> UnitF64>(&self, bin: usize, factor: f64, rng: &mut R) -> Result<SampledEnergy, EnergyDistributionError> {
+        let table_index = if factor > 0.0 && rng().0 < factor { bin + 1 } else { bin };
+        let table = self.distributions.get(table_index).ok_or(EnergyDistributionError::EmptyDistribution)?;
+        let (energy, k) = table.energy_distribution.sample(rng())?;
+
+        let num_cosines = table.cos_theta[k].len();
+        if num_cosines == 0 {
+            return Err(EnergyDistributionError::EmptyDistribution);
+        }
+        let m = ((rng().0 * num_cosines as f64) as usize).min(num_cosines - 1);
+        let energy_out = &table.energy_distribution.energy_out;
+        let cos_theta = match table.cos_theta.get(k + 1) {
+            Some(upper) if energy_out[k + 1] > energy_out[k] => {
+                let fraction = ((energy - energy_out[k]) / (energy_out[k + 1] - energy_out[k])).clamp(0.0, 1.0);
+                table.cos_theta[k][m] + fraction * (upper[m] - table.cos_theta[k][m])
+            },
+            _ => table.cos_theta[k][m],
+        };
+
+        Ok(SampledEnergy { energy, cos_theta: Some(cos_theta) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::utils::get_parsed_thermal_test_file;
+
+    // Two incident energies, three outgoing energies each with two cosines
+    fn discrete_test_data(ifeng: usize) -> InelasticScattering {
+        let mut xxs = vec![
+            2.0, 1.0E-05, 1.0,
+            10.0, 20.0,
+        ];
+        xxs.extend([0.0, -1.0, 1.0, 1.0, -0.5, 0.5, 2.0, 0.0, 1.0]);
+        xxs.extend([0.0, -1.0, 1.0, 2.0, -0.5, 0.5, 4.0, 0.0, 1.0]);
+        let nxs = ThermalNxsArray { xxs_len: xxs.len(), idpni: 3, nil: 1, nieb: 3, idpnc: 0, ncl: 0, ifeng, ncli: 0 };
+        let jxs = ThermalJxsArray { itie: 1, itix: 4, itxe: 6, ..Default::default() };
+        InelasticScattering::process(XxsArray::untyped(&xxs), &nxs, &jxs).unwrap().unwrap()
+    }
+
+    #[test]
+    fn test_discrete_inelastic_processing() {
+        let inelastic = discrete_test_data(0);
+        assert_eq!(inelastic.incident_energy.energy, vec![1.0E-05, 1.0]);
+        assert_eq!(inelastic.xs, vec![10.0, 20.0]);
+        let distribution = match &inelastic.distribution {
+            InelasticDistribution::Discrete(distribution) => distribution,
+            _ => panic!("This should be a discrete distribution"),
+        };
+        assert!(!distribution.skewed);
+        assert_eq!(distribution.energy_out[1], vec![0.0, 2.0, 4.0]);
+        assert_eq!(distribution.cos_theta[0][1], vec![-0.5, 0.5]);
+        assert_eq!(inelastic.evaluate(1.0), 20.0);
+        assert_eq!(inelastic.evaluate(2.0), 0.0);
+    }
+
+    #[test]
+    fn test_discrete_inelastic_sampling() {
+        let inelastic = discrete_test_data(0);
+
+        // The middle outgoing energy at the upper incident energy, with the second cosine
+        let mut values = vec![0.5, 0.75].into_iter();
+        let mut rng = || UnitF64(values.next().unwrap());
+        let sample = inelastic.sample_energy(1.0, &mut rng).unwrap();
+        assert_eq!(sample.energy, 2.0);
+        assert_eq!(sample.cos_theta, Some(0.5));
+
+        // Skewed data with only three outgoing energies falls back to equally likely energies
+        let inelastic = discrete_test_data(1);
+        let mut values = vec![0.9, 0.25].into_iter();
+        let mut rng = || UnitF64(values.next().unwrap());
+        let sample = inelastic.sample_energy(1.0E-05, &mut rng).unwrap();
+        assert_eq!(sample.energy, 2.0);
+        assert_eq!(sample.cos_theta, Some(0.0));
+    }
+
+    #[tokio::test]
+    async fn test_continuous_inelastic_parsing() {
+        let parsed_ace = get_parsed_thermal_test_file().await;
+        let inelastic = parsed_ace.inelastic.unwrap();
+
+        assert_eq!(inelastic.incident_energy.energy, vec![1.0E-05, 1.0]);
+        assert_eq!(inelastic.xs, vec![10.0, 20.0]);
+        let distribution = match &inelastic.distribution {
+            InelasticDistribution::Continuous(distribution) => distribution,
+            _ => panic!("This should be a continuous distribution"),
+        };
+        assert_eq!(distribution.distributions.len(), 2);
+        let table = &distribution.distributions[1];
+        assert_eq!(table.energy_distribution.energy_out, vec![0.0, 2.0]);
+        assert_eq!(table.energy_distribution.cdf, vec![0.0, 1.0]);
+        assert_eq!(table.cos_theta, vec![vec![-1.0, 0.0], vec![0.0, 1.0]]);
+    }
+
+    #[tokio::test]
+    async fn test_continuous_inelastic_sampling() {
+        let parsed_ace = get_parsed_thermal_test_file().await;
+        let inelastic = parsed_ace.inelastic.unwrap();
+
+        // Halfway up the outgoing energy range of the upper table, the first cosine is interpolated
+        let mut values = vec![0.5, 0.5, 0.25].into_iter();
+        let mut rng = || UnitF64(values.next().unwrap());
+        let sample = inelastic.sample_energy(1.0, &mut rng).unwrap();
+        assert!((sample.energy - 1.0).abs() < 1e-12);
+        assert!((sample.cos_theta.unwrap() + 0.5).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,16 @@
+mod thermal_arrays;
+mod inelastic;
+mod elastic;
+mod thermal_scattering_data;
+
+// Structs
+pub use thermal_arrays::{ThermalNxsArray, ThermalJxsArray};
+pub use inelastic::{
+    InelasticScattering,
+    InelasticDistribution,
+    DiscreteInelasticDistribution,
+    ContinuousInelasticDistribution,
+    ContinuousInelasticTable,
+};
+pub use elastic::{CoherentElastic, IncoherentElastic};
+pub use thermal_scattering_data::ThermalScatteringData;
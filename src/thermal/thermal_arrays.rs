@@ -0,0 +1,93 @@
+use anyhow::Result;
+
+use crate::utils::PaceMmap;
+
+//=====================================================================
+// Represents the NXS array from a thermal scattering ("t" class) ACE
+// table. See the ACE format spec for a description. These tables do
+// not share the layout of continuous neutron tables, so the NXS and
+// JXS arrays are given their own representations.
+//=====================================================================
+#[derive(Clone, Debug, PartialEq)]
+pub struct ThermalNxsArray {
+    pub xxs_len: usize, // Number of entries in XXS array
+    pub idpni: usize,   // Inelastic scattering mode
+    pub nil: usize,     // Inelastic dimensioning parameter
+    pub nieb: usize,    // Number of inelastic exiting energies
+    pub idpnc: usize,   // Elastic scattering mode (4 = coherent, 5 = coherent and incoherent, otherwise incoherent)
+    pub ncl: usize,     // Elastic dimensioning parameter
+    pub ifeng: usize,   // Secondary energy mode (0 = equiprobable, 1 = skewed, 2 = continuous)
+    pub ncli: usize,    // Incoherent elastic dimensioning parameter when both elastic modes are given
+}
+
+impl ThermalNxsArray {
+    pub fn from_PACE(mmap: &PaceMmap) -> Result<Self> {
+        let nxs_array: &[usize] = mmap.nxs_array();
+
+        Ok(Self {
+            xxs_len: nxs_array[0],
+            idpni: nxs_array[1],
+            nil: nxs_array[2],
+            nieb: nxs_array[3],
+            idpnc: nxs_array[4],
+            ncl: nxs_array[5],
+            ifeng: nxs_array[6],
+            ncli: nxs_array[7],
+        })
+    }
+}
+
+//=====================================================================
+// Represents the JXS array from a thermal scattering ACE table. Each
+// entry is the (one indexed) start of a block in the XXS array, or 0
+// if the block is not present.
+//=====================================================================
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ThermalJxsArray {
+    pub itie: usize,    // Inelastic energy table
+    pub itix: usize,    // Inelastic cross sections
+    pub itxe: usize,    // Inelastic energy/angle distributions
+    pub itce: usize,    // Elastic energy table
+    pub itcx: usize,    // Elastic cross sections
+    pub itca: usize,    // Elastic angular distributions
+    pub itcei: usize,   // Incoherent elastic energy table when both elastic modes are given
+    pub itcxi: usize,   // Incoherent elastic cross sections when both elastic modes are given
+    pub itcai: usize,   // Incoherent elastic angular distributions when both elastic modes are given
+}
+
+impl ThermalJxsArray {
+    pub fn from_PACE(mmap: &PaceMmap) -> Result<Self> {
+        let jxs_array: &[usize] = mmap.jxs_array();
+
+        Ok(Self {
+            itie: jxs_array[0],
+            itix: jxs_array[1],
+            itxe: jxs_array[2],
+            itce: jxs_array[3],
+            itcx: jxs_array[4],
+            itca: jxs_array[5],
+            itcei: jxs_array[6],
+            itcxi: jxs_array[7],
+            itcai: jxs_array[8],
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::get_parsed_thermal_test_file;
+
+    #[tokio::test]
+    async fn test_thermal_array_parsing() {
+        let parsed_ace = get_parsed_thermal_test_file().await;
+
+        // Continuous secondary energies, with both coherent and incoherent elastic scattering
+        assert_eq!(parsed_ace.nxs_array.xxs_len, 43);
+        assert_eq!(parsed_ace.nxs_array.nil, 3);
+        assert_eq!(parsed_ace.nxs_array.idpnc, 5);
+        assert_eq!(parsed_ace.nxs_array.ifeng, 2);
+        assert_eq!(parsed_ace.jxs_array.itxe, 6);
+        assert_eq!(parsed_ace.jxs_array.itca, 0);
+        assert_eq!(parsed_ace.jxs_array.itcai, 40);
+    }
+}
@@ -0,0 +1,192 @@
+use std::path::Path;
+use std::error::Error;
+
+use crate::unitf64::UnitF64;
+use crate::utils::{ensure_not_ascii, PaceMmap};
+use crate::header::Header;
+use crate::arrays::IzawArray;
+use crate::energy_distributions::{SampleEnergy, SampledEnergy, EnergyDistributionError};
+use crate::thermal::{
+    ThermalNxsArray,
+    ThermalJxsArray,
+    InelasticScattering,
+    CoherentElastic,
+    IncoherentElastic,
+};
+
+//=====================================================================
+// Thermal scattering S(α,β) data from a "t" class ACE table. The IZAW
+// array lists the ZA values of the nuclides that the table applies to.
+//=====================================================================
+#[derive(Clone, Debug)]
+pub struct ThermalScatteringData {
+    pub header: Header,
+    pub izaw_array: IzawArray,
+    pub nxs_array: ThermalNxsArray,
+    pub jxs_array: ThermalJxsArray,
+    pub inelastic: Option<InelasticScattering>,
+    pub coherent_elastic: Option<CoherentElastic>,
+    pub incoherent_elastic: Option<IncoherentElastic>,
+}
+
+impl ThermalScatteringData {
+    pub fn from_file<P: AsRef<Path>>(file_path: P) -> Result<Self, Box<dyn Error + Send + Sync>> {
+        let path = file_path.as_ref();
+
+        // If we have an ASCII file, request that it first be converted to our own binary format
+        ensure_not_ascii(path)?;
+
+        // Create a memory map of the binary file and parse the table from it
+        Self::from_PACE(&PaceMmap::from_file(path)?)
+    }
+
+    // Parse the table from a memory map of a PACE file
+    pub fn from_PACE(mmap: &PaceMmap) -> Result<Self, Box<dyn Error + Send + Sync>> {
+        // Process the header and arrays
+        let header = Header::from_PACE(mmap)?;
+        let izaw_array = IzawArray::from_PACE(mmap)?;
+        let nxs_array = ThermalNxsArray::from_PACE(mmap)?;
+        let jxs_array = ThermalJxsArray::from_PACE(mmap)?;
+
+        // Process the blocks out of the XXS array
+        let xxs_array = mmap.xxs_array();
+        let inelastic = InelasticScattering::process(xxs_array, &nxs_array, &jxs_array)?;
+        let coherent_elastic = CoherentElastic::process(xxs_array, &nxs_array, &jxs_array)?;
+        let incoherent_elastic = IncoherentElastic::process(xxs_array, &nxs_array, &jxs_array)?;
+
+        Ok(Self { header, izaw_array, nxs_array, jxs_array, inelastic, coherent_elastic, incoherent_elastic })
+    }
+
+    // ZAID of the table
+    #[inline]
+    pub fn zaid(&self) -> String {
+        self.header.zaid.clone()
+    }
+
+    // SZAID of the table (version 2.0.0 and later)
+    #[inline]
+    pub fn szaid(&self) -> Option<String> {
+        self.header.szaid.clone()
+    }
+
+    // Temperature in Kelvin
+    #[inline]
+    pub fn temperature(&self) -> f64 {
+        self.header.temperature
+    }
+
+    // ZA values of the nuclides which this table applies to
+    pub fn target_za(&self) -> Vec<usize> {
+        self.izaw_array.pairs
+            .iter()
+            .map(|pair| pair.za)
+            .filter(|&za| za != 0)
+            .collect()
+    }
+
+    // Inelastic scattering cross section at an incident energy (given in MeV)
+    pub fn inelastic_xs(&self, energy: f64) -> f64 {
+        self.inelastic.as_ref().map_or(0.0, |inelastic| inelastic.evaluate(energy))
+    }
+
+    // Elastic scattering cross section at an incident energy (given in MeV), summing the
+    // coherent and incoherent contributions
+    pub fn elastic_xs(&self, energy: f64) -> f64 {
+        self.coherent_elastic.as_ref().map_or(0.0, |coherent| coherent.evaluate(energy))
+            + self.incoherent_elastic.as_ref().map_or(0.0, |incoherent| incoherent.evaluate(energy))
+    }
+
+    // Total thermal scattering cross section at an incident energy (given in MeV)
+    pub fn total_xs(&self, energy: f64) -> f64 {
+        self.inelastic_xs(energy) + self.elastic_xs(energy)
+    }
+}
+
+// Sample a thermal scattering event, first picking inelastic, coherent elastic or incoherent
+// elastic scattering in proportion to their cross sections, then the outgoing energy and cosine.
+impl SampleEnergy for ThermalScatteringData {
+    fn sample_energy<R: FnMut() -> UnitF64>(&self, incident_energy: f64, rng: &mut R) -> Result<SampledEnergy, EnergyDistributionError> {
+        let inelastic_xs = self.inelastic_xs(incident_energy);
+        let coherent_xs = self.coherent_elastic.as_ref().map_or(0.0, |coherent| coherent.evaluate(incident_energy));
+        let incoherent_xs = self.incoherent_elastic.as_ref().map_or(0.0, |incoherent| incoherent.evaluate(incident_energy));
+        let total_xs = inelastic_xs + coherent_xs + incoherent_xs;
+        if total_xs <= 0.0 {
+            return Err(EnergyDistributionError::EmptyDistribution);
+        }
+
+        // Channels without a cross section are never picked
+        let xi = rng().0 * total_xs;
+        if let Some(inelastic) = &self.inelastic && inelastic_xs > 0.0 && (xi < inelastic_xs || coherent_xs + incoherent_xs <= 0.0) {
+            return inelastic.sample_energy(incident_energy, rng);
+        }
+        if let Some(coherent) = &self.coherent_elastic && coherent_xs > 0.0 && (xi < inelastic_xs + coherent_xs || incoherent_xs <= 0.0) {
+            return coherent.sample_energy(incident_energy, rng);
+        }
+        self.incoherent_elastic
+            .as_ref()
+            .ok_or(EnergyDistributionError::EmptyDistribution)?
+            .sample_energy(incident_energy, rng)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::utils::get_parsed_thermal_test_file;
+    use crate::pace_error::PaceError;
+
+    #[tokio::test]
+    async fn test_parse_thermal_test_file() {
+        let parsed_ace = get_parsed_thermal_test_file().await;
+        assert_eq!(parsed_ace.szaid(), Some(String::from("h-h2o.800nt")));
+        assert_eq!(parsed_ace.zaid(), String::from("h-h2o.00t"));
+        assert_eq!(parsed_ace.target_za(), vec![1001]);
+    }
+
+    #[test]
+    fn test_reject_ascii() {
+        let result = ThermalScatteringData::from_file("LICENSE");
+        assert!(result.unwrap_err().to_string().contains("convert_ACE_to_PACE"));
+    }
+
+    #[tokio::test]
+    async fn test_thermal_xs() {
+        let parsed_ace = get_parsed_thermal_test_file().await;
+        assert_eq!(parsed_ace.inelastic_xs(1.0), 20.0);
+        assert!((parsed_ace.elastic_xs(1.0) - 6.003).abs() < 1e-12);
+        assert!((parsed_ace.total_xs(1.0) - 26.003).abs() < 1e-12);
+        assert!((parsed_ace.total_xs(2.0) - 1.5E-03).abs() < 1e-12);
+    }
+
+    #[tokio::test]
+    async fn test_thermal_sampling() {
+        let parsed_ace = get_parsed_thermal_test_file().await;
+
+        // A large random number picks incoherent elastic scattering, which leaves the energy unchanged
+        let mut rng = || UnitF64(0.99);
+        let sample = parsed_ace.sample_energy(1.0, &mut rng).unwrap();
+        assert_eq!(sample.energy, 1.0);
+        assert_eq!(sample.cos_theta, Some(1.0));
+
+        // Above the tabulated range, only coherent elastic scattering remains
+        let sample = parsed_ace.sample_energy(2.0, &mut rng).unwrap();
+        assert_eq!(sample.energy, 2.0);
+
+        // Below the tabulated range and the first Bragg edge, there is nothing to sample
+        assert!(parsed_ace.sample_energy(1.0E-06, &mut rng).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_truncated_table() {
+        let parsed_ace = get_parsed_thermal_test_file().await;
+        let mmap = PaceMmap::from_file("test_nuclear_data_files/h-h2o.800nt.pace").unwrap();
+
+        // Cut the XXS array off just after the start of the ITXE block
+        let (nxs, jxs) = (&parsed_ace.nxs_array, &parsed_ace.jxs_array);
+        let xxs = mmap.xxs_array().slice(0, jxs.itxe).unwrap();
+        let error = InelasticScattering::process(xxs, nxs, jxs).unwrap_err();
+        assert!(matches!(error, PaceError::InTableBlock { block: "ITXE", .. }), "{}", error);
+    }
+
+}
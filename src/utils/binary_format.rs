@@ -1,8 +1,7 @@
 use std::{
     fs::File,
-    io::{BufRead, BufReader, Write},
-    path::Path,
-    sync::Mutex,
+    io::{BufRead, BufReader, BufWriter, ErrorKind, Read, Write},
+    path::{Path, PathBuf},
 };
 
 use rayon::prelude::*;
@@ -11,6 +10,8 @@ use anyhow::{Context, Result};
 
 use crate::utils;
 use crate::header::Header;
+use crate::arrays::XxsArray;
+use crate::pace_error::PaceError;
 
 //=====================================================================
 // Infrastructure to convert an ASCII ACE file into our own "PACE"
@@ -20,6 +21,16 @@ use crate::header::Header;
 //=====================================================================
 
 // The format as follows (from start of file to end):
+//    - Preamble section
+//        - Magic bytes identifying the file as PACE, in the style of the PNG signature so that
+//          files mangled by text mode transfers are caught.
+//        - Format version as a u32 (see PACE_FORMAT_VERSION).
+//        - Byte order mark as a u32, used to detect files written on a machine with the other
+//          byte order, as every value in the file is written in native byte order.
+//        - Checksum of the ACE file the table was converted from as a u64 (see source_checksum),
+//          zero if it is not known.
+//        - Byte offsets of the header, IZAW, NXS, JXS, XXS and XXS type sections and of the end
+//          of the file, each as a u64.
 //    - Header section
 //        - SZAID is written as ASCII bytes and padded to 16 bytes with whitespace if available,
 //          if it is not available, we simply write 16 bytes of whitespace.
@@ -34,11 +45,146 @@ use crate::header::Header;
 //        - x32 usize
 //    - XXS array
 //        - Variable size depending on the file
-//        - During the creation of a PACE file, entries in the XXS array are checked to see if they
-//          are integers or floats. If they are integers, they are stored as i64s, and if they are
-//          floats, they are stored as f64s. However, all of these stored data values are written
-//          as raw bytes in the file in f64 format. Logic elsewhere in this crate will convert to
-//          the appropriate type when reading the data from the file.
+//        - Every entry is stored as an f64. Entries which are integers in the ACE file are stored
+//          as their (exact) integral value, and are read back with the typed accessors of
+//          XxsWords.
+//    - XXS type section
+//        - A bitmap with a bit for each XXS entry, packed into u64 words, which is set if the
+//          entry was an integer in the ACE file. Type-2 binary tables store every entry as a
+//          float, so the entries with integral values are flagged instead.
+//
+// Older versions of the format are rejected by PaceMmap, and can be brought up to date with
+// migrate_PACE:
+//    - Version 1 has no preamble and no XXS type section, and stores integer XXS entries as the
+//      raw bytes of an i64.
+//    - Version 2 adds the preamble, without the offset of the XXS type section.
+
+const PACE_MAGIC: [u8; 8] = *b"\x89PACE\r\n\x1a";
+pub const PACE_FORMAT_VERSION: u32 = 3;
+const BYTE_ORDER_MARK: u32 = 0x0102_0304;
+
+// Length in bytes of each section of a PACE file, apart from the XXS array and its types
+const PREAMBLE_LENGTH: usize = 8 + 4 + 4 + 8 + 7 * 8;
+const HEADER_LENGTH: usize = 48;
+const IZAW_BYTES: usize = IZAW_LENGTH * 8;
+const NXS_BYTES: usize = NXS_LENGTH * 8;
+const JXS_BYTES: usize = JXS_LENGTH * 8;
+
+// Byte offsets of the sections of a PACE file, as given in its preamble
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SectionOffsets {
+    pub header: usize,
+    pub izaw: usize,
+    pub nxs: usize,
+    pub jxs: usize,
+    pub xxs: usize,
+    pub xxs_types: usize,
+    pub end: usize,
+}
+
+impl SectionOffsets {
+    // Offsets of the sections of a PACE file with a given number of XXS words
+    fn new(xxs_length: usize) -> Self {
+        let header = PREAMBLE_LENGTH;
+        let izaw = header + HEADER_LENGTH;
+        let nxs = izaw + IZAW_BYTES;
+        let jxs = nxs + NXS_BYTES;
+        let xxs = jxs + JXS_BYTES;
+        let xxs_types = xxs + xxs_length * 8;
+        Self { header, izaw, nxs, jxs, xxs, xxs_types, end: xxs_types + xxs_length.div_ceil(64) * 8 }
+    }
+
+    // Number of words in the XXS array
+    fn xxs_length(&self) -> usize {
+        self.xxs_types.saturating_sub(self.xxs) / 8
+    }
+
+    fn to_array(self) -> [usize; 7] {
+        [self.header, self.izaw, self.nxs, self.jxs, self.xxs, self.xxs_types, self.end]
+    }
+}
+
+// Contents of the preamble of a PACE file
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct PacePreamble {
+    version: u32,
+    source_checksum: u64,
+    offsets: SectionOffsets,
+}
+
+impl PacePreamble {
+    fn to_bytes(self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(PREAMBLE_LENGTH);
+        bytes.extend_from_slice(&PACE_MAGIC);
+        bytes.extend_from_slice(&self.version.to_ne_bytes());
+        bytes.extend_from_slice(&BYTE_ORDER_MARK.to_ne_bytes());
+        bytes.extend_from_slice(&self.source_checksum.to_ne_bytes());
+        for offset in self.offsets.to_array() {
+            bytes.extend_from_slice(&(offset as u64).to_ne_bytes());
+        }
+        bytes
+    }
+
+    // Read the preamble from the start of a PACE file, rejecting files this version of the crate
+    // can not read
+    fn from_bytes(bytes: &[u8]) -> Result<Self, PaceError> {
+        if !bytes.starts_with(&PACE_MAGIC) {
+            return Err(PaceError::MissingMagic);
+        }
+        if bytes.len() < PREAMBLE_LENGTH {
+            return Err(PaceError::TruncatedFile(format!(
+                "expected at least {} bytes for the preamble, found {}", PREAMBLE_LENGTH, bytes.len()
+            )));
+        }
+        let word = |offset: usize| u64::from_ne_bytes(std::array::from_fn(|i| bytes[offset + i]));
+
+        // The byte order has to be checked first, as the version can not be read otherwise
+        let byte_order_mark = u32::from_ne_bytes(std::array::from_fn(|i| bytes[12 + i]));
+        if byte_order_mark == BYTE_ORDER_MARK.swap_bytes() {
+            return Err(PaceError::ByteOrderMismatch);
+        } else if byte_order_mark != BYTE_ORDER_MARK {
+            return Err(PaceError::InvalidLayout(format!("unrecognised byte order mark {:#010x}", byte_order_mark)));
+        }
+        let version = u32::from_ne_bytes(std::array::from_fn(|i| bytes[8 + i]));
+        if version != PACE_FORMAT_VERSION {
+            return Err(PaceError::UnsupportedVersion { found: version, supported: PACE_FORMAT_VERSION });
+        }
+
+        let offset = |index: usize| word(24 + index * 8) as usize;
+        let offsets = SectionOffsets {
+            header: offset(0),
+            izaw: offset(1),
+            nxs: offset(2),
+            jxs: offset(3),
+            xxs: offset(4),
+            xxs_types: offset(5),
+            end: offset(6),
+        };
+        Ok(Self { version, source_checksum: word(16), offsets })
+    }
+}
+
+// Checksum of a source ACE file, stored in the preamble of the PACE files converted from it. This
+// is the 64-bit FNV-1a hash of the contents of the file.
+pub fn source_checksum<P: AsRef<Path>>(path: P) -> Result<u64> {
+    const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    let mut reader = BufReader::new(File::open(path.as_ref())?);
+    let mut hash = FNV_OFFSET_BASIS;
+    loop {
+        let buffer = reader.fill_buf()?;
+        if buffer.is_empty() {
+            break;
+        }
+        for &byte in buffer {
+            hash = (hash ^ byte as u64).wrapping_mul(FNV_PRIME);
+        }
+        let length = buffer.len();
+        reader.consume(length);
+    }
+    Ok(hash)
+}
 
 //=====================================================================
 // Memory-mapped file for the PACE binary format.
@@ -47,7 +193,10 @@ use crate::header::Header;
 // zero-copy conversions to appropriate types from the raw bytes in
 // these slices.
 //=====================================================================
-pub struct PaceMmap ( memmap2::Mmap );
+pub struct PaceMmap {
+    mmap: memmap2::Mmap,
+    preamble: PacePreamble,
+}
 
 impl PaceMmap {
     // Take a pre-existing PACE file and map it into memory.
@@ -57,23 +206,50 @@ impl PaceMmap {
 
         let mmap = unsafe { MmapOptions::new().map(&file) }
             .with_context(|| format!("Failed memory map PACE file: {:?}", path.as_ref()))?;
-        Ok(Self(mmap))
+        Self::from_mmap(mmap)
+            .with_context(|| format!("Invalid PACE file: {:?}", path.as_ref()))
+    }
+
+    // Wrap a memory map of a PACE file, checking that its layout can be safely sliced up into
+    // the header and arrays before any of them are read.
+    pub fn from_mmap(mmap: memmap2::Mmap) -> Result<Self, PaceError> {
+        let preamble = PacePreamble::from_bytes(&mmap)?;
+        validate_layout(&mmap, &preamble.offsets)?;
+        Ok(Self { mmap, preamble })
+    }
+
+    // Version of the PACE format the file was written with
+    pub fn format_version(&self) -> u32 {
+        self.preamble.version
+    }
+
+    // Checksum of the ACE file the table was converted from, zero if it is not known
+    pub fn source_checksum(&self) -> u64 {
+        self.preamble.source_checksum
+    }
+
+    // Byte offsets of the sections of the file
+    pub fn section_offsets(&self) -> SectionOffsets {
+        self.preamble.offsets
     }
 
     // Pull the bytes corresponding to the header
     pub fn header_bytes(&self) -> &[u8] {
-        &self.0[0..48]
+        let offsets = &self.preamble.offsets;
+        &self.mmap[offsets.header..offsets.izaw]
     }
 
     // Pull the bytes corresponding to the IZAW array
     pub fn izaw_bytes(&self) -> &[u8] {
-        &self.0[48..304]
+        let offsets = &self.preamble.offsets;
+        &self.mmap[offsets.izaw..offsets.nxs]
     }
 
     // Pull the NXS array
     pub fn nxs_array(&self) -> &[usize] {
-        // A JXS array consists of 16 integers
-        let nxs_array = &self.0[304..432];
+        // A NXS array consists of 16 integers
+        let offsets = &self.preamble.offsets;
+        let nxs_array = &self.mmap[offsets.nxs..offsets.jxs];
         // Zero-copy Conversion to usize
         unsafe { 
             std::slice::from_raw_parts(nxs_array.as_ptr() as *const usize, nxs_array.len() / 8)
@@ -83,21 +259,158 @@ impl PaceMmap {
     // Pull the JXS array
     pub fn jxs_array(&self) -> &[usize] {
         // A JXS array consists of 32 integers.
-        let jxs_array = &self.0[432..688];
+        let offsets = &self.preamble.offsets;
+        let jxs_array = &self.mmap[offsets.jxs..offsets.xxs];
         // Zero-copy conversion to usize
         unsafe { 
             std::slice::from_raw_parts(jxs_array.as_ptr() as *const usize, jxs_array.len() / 8)
         }
     }
     
-    // Pull the XXS array, interpreted as f64
-    pub fn xxs_array(&self) -> &[f64] {
-        let xxs_array_bytes = &self.0[688..];
-        // Zero-copy conversion to f64
-        unsafe {
-            std::slice::from_raw_parts(xxs_array_bytes.as_ptr() as *const f64, xxs_array_bytes.len() / 8)
+    // Pull the XXS array along with the types of its words
+    pub fn xxs_array(&self) -> XxsArray<'_> {
+        let offsets = &self.preamble.offsets;
+        let xxs_array_bytes = &self.mmap[offsets.xxs..offsets.xxs_types];
+        let xxs_types_bytes = &self.mmap[offsets.xxs_types..offsets.end];
+        // Zero-copy conversion to f64 and u64
+        let (words, types) = unsafe {
+            (
+                std::slice::from_raw_parts(xxs_array_bytes.as_ptr() as *const f64, xxs_array_bytes.len() / 8),
+                std::slice::from_raw_parts(xxs_types_bytes.as_ptr() as *const u64, xxs_types_bytes.len() / 8),
+            )
+        };
+        XxsArray::new(words, types)
+    }
+}
+
+// Check the raw bytes of a PACE file before they are reinterpreted as integers and floats. The
+// sections must follow each other in order with their expected lengths, the file must be aligned
+// for 8 byte words, and the XXS array must hold exactly the number of words given by NXS(1).
+fn validate_layout(bytes: &[u8], offsets: &SectionOffsets) -> Result<(), PaceError> {
+    if bytes.as_ptr().align_offset(std::mem::align_of::<f64>().max(std::mem::align_of::<usize>())) != 0 {
+        return Err(PaceError::InvalidLayout("data is not aligned to 8 bytes".to_string()));
+    }
+
+    let expected = SectionOffsets::new(offsets.xxs_length());
+    if *offsets != expected {
+        return Err(PaceError::InvalidLayout(format!(
+            "section offsets {:?} do not match the expected offsets {:?}", offsets, expected
+        )));
+    }
+    match bytes.len().cmp(&offsets.end) {
+        std::cmp::Ordering::Less => return Err(PaceError::TruncatedFile(format!(
+            "expected {} bytes, found {}", offsets.end, bytes.len()
+        ))),
+        std::cmp::Ordering::Greater => return Err(PaceError::InvalidLayout(format!(
+            "expected {} bytes, found {}", offsets.end, bytes.len()
+        ))),
+        std::cmp::Ordering::Equal => {},
+    }
+
+    // NXS(1) gives the length of the XXS array for every class of table
+    let xxs_length = usize::from_ne_bytes(std::array::from_fn(|i| bytes[offsets.nxs + i]));
+    if xxs_length != offsets.xxs_length() {
+        return Err(PaceError::InvalidLayout(format!(
+            "NXS(1) gives {} XXS words, but the XXS array holds {}", xxs_length, offsets.xxs_length()
+        )));
+    }
+    Ok(())
+}
+
+// Whether a file starts with the PACE magic bytes, regardless of its format version
+pub fn is_pace_file<P: AsRef<Path>>(path: P) -> Result<bool> {
+    let mut magic = [0u8; PACE_MAGIC.len()];
+    match File::open(path)?.read_exact(&mut magic) {
+        Ok(()) => Ok(magic == PACE_MAGIC),
+        Err(error) if error.kind() == ErrorKind::UnexpectedEof => Ok(false),
+        Err(error) => Err(error.into()),
+    }
+}
+
+// The table readers only parse PACE files, so ASCII ACE files are rejected up front with an error
+// which says how to convert them
+pub fn ensure_not_ascii<P: AsRef<Path>>(path: P) -> Result<()> {
+    let path = path.as_ref();
+    if utils::is_ascii_file(path)? {
+        return Err(anyhow::anyhow!(
+            "File {} is ASCII, this should first be converted to a PACE file with convert_ACE_to_PACE", path.display()
+        ));
+    }
+    Ok(())
+}
+
+// Bring a PACE file written with an older version of the format up to date in place. Version 1
+// files have no preamble, so are assumed to have been written on a machine with the same byte
+// order, and are given a source checksum of zero as it is not known. Both older versions stored
+// integer XXS words as raw i64 bytes, which are converted to their values and flagged as integers
+// in the type bitmap. Files which are already up to date are left alone.
+pub fn migrate_PACE<P: AsRef<Path>>(path: P) -> Result<()> {
+    let path = path.as_ref();
+    let bytes = std::fs::read(path)?;
+
+    // Split off the preamble of older versions, leaving the version 1 layout
+    let (body, checksum) = match PacePreamble::from_bytes(&bytes) {
+        Ok(_) => return Ok(()),
+        Err(PaceError::MissingMagic) => (bytes.as_slice(), 0),
+        Err(PaceError::UnsupportedVersion { found: 2, .. }) => {
+            const VERSION_2_PREAMBLE_LENGTH: usize = 8 + 4 + 4 + 8 + 6 * 8;
+            let checksum = u64::from_ne_bytes(std::array::from_fn(|i| bytes[16 + i]));
+            (&bytes[VERSION_2_PREAMBLE_LENGTH.min(bytes.len())..], checksum)
+        },
+        Err(error) => return Err(anyhow::Error::new(error).context(format!("Can not migrate PACE file {}", path.display()))),
+    };
+
+    // Check the version 1 layout, which is the header and arrays without any preamble
+    let legacy_xxs = SectionOffsets::new(0).xxs - PREAMBLE_LENGTH;
+    let legacy_nxs = SectionOffsets::new(0).nxs - PREAMBLE_LENGTH;
+    if body.len() < legacy_xxs || !(body.len() - legacy_xxs).is_multiple_of(8) {
+        return Err(anyhow::anyhow!("{} is not a PACE file of any known version", path.display()));
+    }
+    let xxs_length = (body.len() - legacy_xxs) / 8;
+    if usize::from_ne_bytes(std::array::from_fn(|i| body[legacy_nxs + i])) != xxs_length {
+        return Err(anyhow::anyhow!("{} is not a PACE file of any known version", path.display()));
+    }
+
+    // Integers were stored as the raw bytes of an i64. These can only be read as subnormal
+    // floats, which never appear in nuclear data, so anything else is taken to be a float.
+    const MAX_RAW_INTEGER: u64 = 1 << 52;
+    let mut xxs_bytes = Vec::with_capacity(xxs_length * 8);
+    let mut is_integer = Vec::with_capacity(xxs_length);
+    for word in body[legacy_xxs..].chunks_exact(8) {
+        let raw = i64::from_ne_bytes(std::array::from_fn(|i| word[i]));
+        if raw.unsigned_abs() < MAX_RAW_INTEGER {
+            xxs_bytes.extend_from_slice(&(raw as f64).to_ne_bytes());
+            is_integer.push(true);
+        } else {
+            xxs_bytes.extend_from_slice(word);
+            is_integer.push(false);
         }
     }
+
+    // Rewrite the file, through a temporary file so that it is never seen half written
+    let directory = path.parent().filter(|parent| !parent.as_os_str().is_empty()).unwrap_or(Path::new("."));
+    let temporary_file = tempfile::NamedTempFile::new_in(directory)?;
+    let mut output_file = BufWriter::new(temporary_file.as_file());
+    let offsets = SectionOffsets::new(xxs_length);
+    output_file.write_all(&PacePreamble { version: PACE_FORMAT_VERSION, source_checksum: checksum, offsets }.to_bytes())?;
+    output_file.write_all(&body[..legacy_xxs])?;
+    output_file.write_all(&xxs_bytes)?;
+    write_xxs_types(&mut output_file, &is_integer)?;
+    output_file.flush()?;
+    drop(output_file);
+    temporary_file.persist(path)?;
+    Ok(())
+}
+
+// Write the bitmap of XXS entries which are integers
+fn write_xxs_types<W: Write>(output: &mut W, is_integer: &[bool]) -> Result<()> {
+    for chunk in is_integer.chunks(64) {
+        let bits = chunk.iter()
+            .enumerate()
+            .fold(0_u64, |bits, (i, &is_integer)| bits | (u64::from(is_integer) << i));
+        output.write_all(&bits.to_ne_bytes())?;
+    }
+    Ok(())
 }
 
 
@@ -135,111 +448,557 @@ unsafe fn parse_tokens_from_line(line: &str) -> Vec<&str> {
 }
 
 
-// This function converts an ASCII ACE file into a PACE binary file.
-pub fn convert_ACE_to_PACE<P: AsRef<Path>>(input_path: P) -> Result<String> {
-    // Open ASCII ACE file
-    let input_file = File::open(input_path.as_ref())?;
-    let mut reader = BufReader::new(input_file);
+// Number of words in the IZAW, NXS and JXS arrays, and the number of lines they take up in a
+// Type-1 ASCII table
+const IZAW_LENGTH: usize = 32;
+const NXS_LENGTH: usize = 16;
+const JXS_LENGTH: usize = 32;
+const IZAW_NXS_JXS_LINES: usize = 10;
 
-    // Parse the header using the existing `from_ACE` method
-    let header = Header::from_ACE(&mut reader)
-        .with_context(|| format!("Failed to read header from ASCII ACE file {} while trying to convert to PACE file", input_path.as_ref().display()))?;
-
-    // Set the PACE file name to the SZAID if it is available. Otherwise, set it to the ZAID.
-    let output_filename = if let Some(ref val) = header.szaid {
-        format!("{}.pace", val)
-    } else {
-        format!("{}.pace", header.zaid)
+// Usual number of XXS words on each line of a Type-1 ASCII table
+const XXS_WORDS_PER_LINE: usize = 4;
+
+// Path of the PACE file for a table, placed next to the ACE file it was converted from. The PACE
+// file is named after the SZAID if it is available, and otherwise after the ZAID.
+fn pace_output_path(input_path: &Path, header: &Header) -> PathBuf {
+    let output_filename = match header.szaid {
+        Some(ref val) => format!("{}.pace", val),
+        None => format!("{}.pace", header.zaid),
     };
-    let output_filename = Path::new(&output_filename);
-    let output_path = input_path.as_ref()
-        .parent()
-        .unwrap()
-        .join(output_filename);
+    input_path.parent().unwrap().join(output_filename)
+}
+
+// Write a PACE file from a header, the raw bytes of the IZAW, NXS, JXS and XXS arrays, whether
+// each XXS entry is an integer (for the XXS type section), and the checksum of the ACE file they
+// were read from
+fn write_PACE(output_path: &Path, header: &Header, izaw_nxs_jxs_bytes: &[u8], xxs_bytes: &[u8], is_integer: &[bool], source_checksum: u64) -> Result<()> {
+    let mut output_file = BufWriter::new(File::create(output_path)?);
 
-    // Create output file for writing (mutable from the start)
-    let output_file = File::create(output_path.clone())?;
-    let output_file = Mutex::new(output_file);
+    // Write the preamble
+    let preamble = PacePreamble {
+        version: PACE_FORMAT_VERSION,
+        source_checksum,
+        offsets: SectionOffsets::new(xxs_bytes.len() / 8),
+    };
+    output_file.write_all(&preamble.to_bytes())?;
 
     // Write the header information
-    {
-        let mut output_file = output_file.lock().unwrap();
-        match header.szaid {
-            Some(ref val) => {
-                let padding_length = 16 - val.len();
-                output_file.write_all(val.as_bytes())?;
-                output_file.write_all(&vec![b' '; padding_length])?;
-            },
-            None => {
-                output_file.write_all(&vec![b' '; 16])?;
-            }
+    match header.szaid {
+        Some(ref val) => {
+            let padding_length = 16 - val.len();
+            output_file.write_all(val.as_bytes())?;
+            output_file.write_all(&vec![b' '; padding_length])?;
+        },
+        None => {
+            output_file.write_all(&[b' '; 16])?;
         }
+    }
 
-        let padding_length = 16 - header.zaid.len();
-        output_file.write_all(header.zaid.as_bytes())?;
-        output_file.write_all(&vec![b' '; padding_length])?;
+    let padding_length = 16 - header.zaid.len();
+    output_file.write_all(header.zaid.as_bytes())?;
+    output_file.write_all(&vec![b' '; padding_length])?;
 
-        output_file.write_all(&header.atomic_mass_fraction.to_ne_bytes())?;
-        output_file.write_all(&header.kT.to_ne_bytes())?;
-    }
+    output_file.write_all(&header.atomic_mass_fraction.to_ne_bytes())?;
+    output_file.write_all(&header.kT.to_ne_bytes())?;
+
+    // Write the arrays
+    output_file.write_all(izaw_nxs_jxs_bytes)?;
+    output_file.write_all(xxs_bytes)?;
+    write_xxs_types(&mut output_file, is_integer)?;
+    output_file.flush()?;
+    Ok(())
+}
+
+// Convert the Type-1 table starting at the current position of the reader into a PACE file.
+// The PACE file is written to `output_path` if given, and otherwise next to the ACE file.
+fn convert_ACE_table(reader: &mut BufReader<File>, input_path: &Path, output_path: Option<&Path>, source_checksum: u64) -> Result<String> {
+    // Parse the header using the existing `from_ACE` method
+    let header = Header::from_ACE(reader)
+        .with_context(|| format!("Failed to read header from ASCII ACE file {} while trying to convert to PACE file", input_path.display()))?;
 
     // Annoyingly, the IXS, NXS, and JXS arrays have different line lengths than the XXS array.
     // To get around this we will read the next 10 lines of the file separately and parse them.
-    const IZAW_NXS_JXS_LENGTH: usize = 10;
-    let izaw_nxs_jxs_lines = utils::read_lines(&mut reader, IZAW_NXS_JXS_LENGTH)?;
-    for line in izaw_nxs_jxs_lines {
-        // Split line into whitespace-separated tokens
-        for token in line.split_whitespace() {
-            // Try parsing as integer first
-            if let Ok(integer) = token.parse::<i64>() {
-                let mut output_file = output_file.lock().unwrap();
-                output_file.write_all(&integer.to_ne_bytes())?;
-            }
-            // Then try parsing as float
-            else if let Ok(float) = token.parse::<f64>() {
-                let mut output_file = output_file.lock().unwrap();
-                output_file.write_all(&float.to_ne_bytes())?;
-            } else {
-                return Err(anyhow::anyhow!(format!("Invalid token format: '{}'", token)));
+    let izaw_nxs_jxs_lines = utils::read_lines(reader, IZAW_NXS_JXS_LINES)?;
+    let mut izaw_nxs_jxs_bytes = Vec::with_capacity((IZAW_LENGTH + NXS_LENGTH + JXS_LENGTH) * 8);
+    let mut xxs_length = None;
+    for (index, token) in izaw_nxs_jxs_lines.iter().flat_map(|line| line.split_whitespace()).enumerate() {
+        // Try parsing as integer first
+        if let Ok(integer) = token.parse::<i64>() {
+            // NXS(1) gives the length of the XXS array
+            if index == IZAW_LENGTH {
+                xxs_length = Some(integer as usize);
             }
+            izaw_nxs_jxs_bytes.extend_from_slice(&integer.to_ne_bytes());
+        }
+        // Then try parsing as float
+        else if let Ok(float) = token.parse::<f64>() {
+            izaw_nxs_jxs_bytes.extend_from_slice(&float.to_ne_bytes());
+        } else {
+            return Err(anyhow::anyhow!(format!("Invalid token format: '{}'", token)));
+        }
+    }
+    let xxs_length = xxs_length
+        .ok_or_else(|| anyhow::anyhow!("Missing NXS array in table {} of {}", header.zaid, input_path.display()))?;
+
+    // Only the lines belonging to this table are read, so that any following tables are left in
+    // the reader. Tables normally have four words to a line, but this is not relied upon.
+    let mut lines = Vec::with_capacity(xxs_length.div_ceil(XXS_WORDS_PER_LINE));
+    let mut num_words = 0;
+    while num_words < xxs_length {
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 {
+            return Err(anyhow::anyhow!(
+                "Table {} of {} is truncated, expected {} XXS words but found {}",
+                header.zaid, input_path.display(), xxs_length, num_words
+            ));
         }
+        let line = line.trim_end_matches(['\n', '\r']).to_string();
+        num_words += line.len() / 20;
+        lines.push(line);
     }
 
     // Process XXS array lines in parallel batches
     const BATCH_SIZE: usize = 1000;
-    let lines: Vec<String> = reader.lines().collect::<Result<_, _>>()?;
-    let mut byte_batches: Vec<(usize, Vec<u8>)> = lines
+    let mut byte_batches: Vec<(usize, Vec<u8>, Vec<bool>)> = lines
         .par_chunks(BATCH_SIZE)
         .enumerate()
-        .filter_map(|(index, batch)| {
+        .map(|(index, batch)| {
             let mut local_buffer = Vec::with_capacity(BATCH_SIZE * 32);
+            let mut local_types = Vec::with_capacity(BATCH_SIZE * 4);
             for line in batch {
                 for token in unsafe { parse_tokens_from_line(line) } {
+                    // Integers are stored as their value, and flagged in the XXS types
                     if let Ok(integer) = token.parse::<i64>() {
-                        local_buffer.extend_from_slice(&integer.to_ne_bytes());
+                        local_buffer.extend_from_slice(&(integer as f64).to_ne_bytes());
+                        local_types.push(true);
                     } else if let Ok(float) = token.parse::<f64>() {
                         local_buffer.extend_from_slice(&float.to_ne_bytes());
+                        local_types.push(false);
                     } else {
-                        panic!("Invalid token \"{}\" when trying to convert ASCII to binary", token); // Skip invalid tokens
+                        return Err(PaceError::InvalidToken(token.to_string()));
                     }
                 }
             }
-            Some((index, local_buffer))
+            Ok((index, local_buffer, local_types))
         })
-        .collect();
+        .collect::<Result<_, PaceError>>()?;
 
     // Sort batches of parsed binary data by index to ensure correct order
-    byte_batches.sort_by_key(|&(index, _)| index);
-    // Write sorted results to the output file
-    let mut final_buffer = Vec::new();
-    for (_, byte_batch) in byte_batches {
-        final_buffer.extend_from_slice(&byte_batch);
+    byte_batches.sort_by_key(|&(index, _, _)| index);
+    let mut xxs_bytes = Vec::with_capacity(xxs_length * 8);
+    let mut is_integer = Vec::with_capacity(xxs_length);
+    for (_, byte_batch, type_batch) in byte_batches {
+        xxs_bytes.extend_from_slice(&byte_batch);
+        is_integer.extend_from_slice(&type_batch);
     }
-    {
-        let mut output_file = output_file.lock().unwrap();
-        output_file.write_all(&final_buffer)?;
+
+    // Write the PACE file and return its path
+    let output_path = output_path.map_or_else(|| pace_output_path(input_path, &header), Path::to_path_buf);
+    write_PACE(&output_path, &header, &izaw_nxs_jxs_bytes, &xxs_bytes, &is_integer, source_checksum)?;
+    Ok(output_path.to_string_lossy().into_owned())
+}
+
+// This function converts the first table of an ASCII ACE file into a PACE binary file.
+pub fn convert_ACE_to_PACE<P: AsRef<Path>>(input_path: P) -> Result<String> {
+    convert_ACE_to_PACE_at(input_path, 1)
+}
+
+// Convert the table of a multi-table ASCII ACE file starting at a line (one indexed). This is the
+// address given for Type-1 tables in xsdir.
+pub fn convert_ACE_to_PACE_at<P: AsRef<Path>>(input_path: P, address: usize) -> Result<String> {
+    let input_file = File::open(input_path.as_ref())?;
+    let mut reader = BufReader::new(input_file);
+
+    skip_ACE_lines(&mut reader, address, input_path.as_ref())?;
+    convert_ACE_table(&mut reader, input_path.as_ref(), None, source_checksum(input_path.as_ref())?)
+}
+
+// Skip the lines of any tables preceding the one starting at a line (one indexed)
+fn skip_ACE_lines(reader: &mut BufReader<File>, address: usize, input_path: &Path) -> Result<()> {
+    let skipped_lines = utils::read_lines(reader, address.saturating_sub(1))?;
+    if skipped_lines.len() + 1 < address {
+        return Err(anyhow::anyhow!("Address {} is past the end of ASCII ACE file {}", address, input_path.display()));
+    }
+    Ok(())
+}
+
+// Convert every table of a multi-table ASCII ACE file, returning the paths of the PACE files in
+// the order the tables appear
+pub fn convert_all_ACE_to_PACE<P: AsRef<Path>>(input_path: P) -> Result<Vec<String>> {
+    let input_file = File::open(input_path.as_ref())?;
+    let mut reader = BufReader::new(input_file);
+    let checksum = source_checksum(input_path.as_ref())?;
+
+    let mut output_paths = Vec::new();
+    loop {
+        // Stop once there is nothing but whitespace left in the file
+        let remaining = reader.fill_buf()?;
+        match remaining.iter().position(|byte| !byte.is_ascii_whitespace()) {
+            None if remaining.is_empty() => break,
+            None => {
+                let length = remaining.len();
+                reader.consume(length);
+            },
+            Some(0) => output_paths.push(convert_ACE_table(&mut reader, input_path.as_ref(), None, checksum)?),
+            Some(_) => {
+                // Skip over any blank lines between tables
+                let mut blank_line = String::new();
+                reader.read_line(&mut blank_line)?;
+                if !blank_line.trim().is_empty() {
+                    return Err(anyhow::anyhow!("Unexpected line \"{}\" between tables in {}", blank_line.trim_end(), input_path.as_ref().display()));
+                }
+            },
+        }
+    }
+    Ok(output_paths)
+}
+
+
+//=====================================================================
+// Support for Type-2 (binary) ACE tables, as written by older MCNP
+// libraries with Fortran unformatted sequential I/O. Each record is
+// surrounded by 4-byte markers holding the record length in bytes.
+// The first record of a table holds the legacy header and the IZAW,
+// NXS and JXS arrays, and the XXS array follows in as many records as
+// needed. Every XXS word is stored as a float, including those that
+// hold integers, so the words with integral values are flagged as
+// integers in the PACE file.
+//
+// The first record is laid out as (with integers of 4 or 8 bytes):
+//    - HZ (10 characters), AW (f64), TZ (f64), HD (10 characters),
+//      HK (70 characters), HM (10 characters)
+//    - 16 pairs of integer / f64 values for the IZAW array
+//    - 16 integers for the NXS array
+//    - 32 integers for the JXS array
+//=====================================================================
+
+const TYPE2_HEADER_LENGTH: usize = 10 + 8 + 8 + 10 + 70 + 10;
+
+// Read the next Fortran record, or None at the end of the file
+fn read_fortran_record<R: Read>(reader: &mut R) -> Result<Option<Vec<u8>>> {
+    let mut marker = [0u8; 4];
+    match reader.read_exact(&mut marker) {
+        Ok(()) => {},
+        Err(error) if error.kind() == ErrorKind::UnexpectedEof => return Ok(None),
+        Err(error) => return Err(error.into()),
+    }
+    let length = u32::from_ne_bytes(marker) as usize;
+
+    let mut record = vec![0u8; length];
+    reader.read_exact(&mut record).context("Truncated record in Type-2 ACE file")?;
+    reader.read_exact(&mut marker).context("Truncated record in Type-2 ACE file")?;
+    if u32::from_ne_bytes(marker) as usize != length {
+        return Err(anyhow::anyhow!("Mismatched record markers in Type-2 ACE file"));
+    }
+    Ok(Some(record))
+}
+
+// Convert the Type-2 table whose first record has just been read into a PACE file. The PACE file
+// is written to `output_path` if given, and otherwise next to the ACE file.
+fn convert_type2_ACE_table<R: Read>(reader: &mut R, first_record: Vec<u8>, input_path: &Path, output_path: Option<&Path>, source_checksum: u64) -> Result<String> {
+    // The size of the integers is given by the length of the first record
+    let integer_size = match first_record.len() {
+        length if length == TYPE2_HEADER_LENGTH + 16 * (4 + 8) + (NXS_LENGTH + JXS_LENGTH) * 4 => 4,
+        length if length == TYPE2_HEADER_LENGTH + 16 * (8 + 8) + (NXS_LENGTH + JXS_LENGTH) * 8 => 8,
+        length => return Err(anyhow::anyhow!("Unexpected first record length {} in Type-2 ACE file {}", length, input_path.display())),
+    };
+    let read_integer = |bytes: &[u8]| -> i64 {
+        match integer_size {
+            4 => i32::from_ne_bytes(bytes.try_into().unwrap()) as i64,
+            _ => i64::from_ne_bytes(bytes.try_into().unwrap()),
+        }
+    };
+    let read_float = |bytes: &[u8]| f64::from_ne_bytes(bytes.try_into().unwrap());
+
+    // Header
+    let zaid = String::from_utf8_lossy(&first_record[0..10]).trim().to_string();
+    let atomic_mass_fraction = read_float(&first_record[10..18]);
+    let kT = read_float(&first_record[18..26]);
+    let header = Header {
+        zaid,
+        szaid: None,
+        atomic_mass_fraction,
+        kT,
+        temperature: utils::compute_temperature_from_kT(kT),
+    };
+
+    // IZAW, NXS and JXS arrays
+    let mut offset = TYPE2_HEADER_LENGTH;
+    let mut izaw_nxs_jxs_bytes = Vec::with_capacity((IZAW_LENGTH + NXS_LENGTH + JXS_LENGTH) * 8);
+    for _ in 0..IZAW_LENGTH / 2 {
+        izaw_nxs_jxs_bytes.extend_from_slice(&read_integer(&first_record[offset..offset + integer_size]).to_ne_bytes());
+        offset += integer_size;
+        izaw_nxs_jxs_bytes.extend_from_slice(&first_record[offset..offset + 8]);
+        offset += 8;
     }
+    let nxs_start = offset;
+    for _ in 0..NXS_LENGTH + JXS_LENGTH {
+        izaw_nxs_jxs_bytes.extend_from_slice(&read_integer(&first_record[offset..offset + integer_size]).to_ne_bytes());
+        offset += integer_size;
+    }
+    let xxs_length = read_integer(&first_record[nxs_start..nxs_start + integer_size]) as usize;
+
+    // XXS array, where the last record may be padded past the end of the table
+    let mut xxs_bytes = Vec::with_capacity(xxs_length * 8);
+    while xxs_bytes.len() < xxs_length * 8 {
+        let record = read_fortran_record(reader)?
+            .ok_or_else(|| anyhow::anyhow!("Table {} of Type-2 ACE file {} is truncated", header.zaid, input_path.display()))?;
+        xxs_bytes.extend_from_slice(&record);
+    }
+    xxs_bytes.truncate(xxs_length * 8);
 
-    // Return the path to the PACE file
+    // Write the PACE file and return its path, flagging the XXS entries with integral values as
+    // integers as the types of the entries are not recorded
+    let is_integer: Vec<bool> = xxs_bytes
+        .chunks_exact(8)
+        .map(|word| f64::from_ne_bytes(std::array::from_fn(|i| word[i])).fract() == 0.0)
+        .collect();
+    let output_path = output_path.map_or_else(|| pace_output_path(input_path, &header), Path::to_path_buf);
+    write_PACE(&output_path, &header, &izaw_nxs_jxs_bytes, &xxs_bytes, &is_integer, source_checksum)?;
     Ok(output_path.to_string_lossy().into_owned())
 }
+
+// Convert the table of a Type-2 binary ACE file starting at a record (one indexed). This is the
+// address given for Type-2 tables in xsdir.
+pub fn convert_type2_ACE_to_PACE<P: AsRef<Path>>(input_path: P, record: usize) -> Result<String> {
+    let input_file = File::open(input_path.as_ref())?;
+    let mut reader = BufReader::new(input_file);
+
+    let first_record = read_type2_record_at(&mut reader, record, input_path.as_ref())?;
+    convert_type2_ACE_table(&mut reader, first_record, input_path.as_ref(), None, source_checksum(input_path.as_ref())?)
+}
+
+// Skip the records of any preceding tables and read the record (one indexed) a table starts at
+fn read_type2_record_at<R: Read>(reader: &mut R, record: usize, input_path: &Path) -> Result<Vec<u8>> {
+    for _ in 1..record {
+        read_fortran_record(reader)?
+            .ok_or_else(|| anyhow::anyhow!("Record {} is past the end of Type-2 ACE file {}", record, input_path.display()))?;
+    }
+
+    read_fortran_record(reader)?
+        .ok_or_else(|| anyhow::anyhow!("Record {} is past the end of Type-2 ACE file {}", record, input_path.display()))
+}
+
+// Convert every table of a Type-2 binary ACE file, returning the paths of the PACE files in the
+// order the tables appear
+pub fn convert_all_type2_ACE_to_PACE<P: AsRef<Path>>(input_path: P) -> Result<Vec<String>> {
+    let input_file = File::open(input_path.as_ref())?;
+    let mut reader = BufReader::new(input_file);
+    let checksum = source_checksum(input_path.as_ref())?;
+
+    let mut output_paths = Vec::new();
+    while let Some(first_record) = read_fortran_record(&mut reader)? {
+        output_paths.push(convert_type2_ACE_table(&mut reader, first_record, input_path.as_ref(), None, checksum)?);
+    }
+    Ok(output_paths)
+}
+
+// Convert a table located as in xsdir, by its file type (1 for ASCII, 2 for binary) and address,
+// writing the PACE file to a chosen path
+pub(crate) fn convert_table_to_PACE(input_path: &Path, file_type: usize, address: usize, output_path: &Path) -> Result<String> {
+    let input_file = File::open(input_path)?;
+    let mut reader = BufReader::new(input_file);
+    let checksum = source_checksum(input_path)?;
+
+    match file_type {
+        1 => {
+            skip_ACE_lines(&mut reader, address, input_path)?;
+            convert_ACE_table(&mut reader, input_path, Some(output_path), checksum)
+        },
+        2 => {
+            let first_record = read_type2_record_at(&mut reader, address, input_path)?;
+            convert_type2_ACE_table(&mut reader, first_record, input_path, Some(output_path), checksum)
+        },
+        _ => Err(anyhow::anyhow!("Unsupported ACE file type {} for {}", file_type, input_path.display())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use tempfile::tempdir;
+
+    use crate::{DosimetryData, PhotonuclearData};
+    use crate::arrays::XxsWords;
+    use crate::utils::{get_parsed_dosimetry_test_file, get_parsed_photonuclear_test_file};
+    use crate::utils::testing::{TEST_DOSIMETRY_ACE_UNCOMMENTED, TEST_DOSIMETRY_PACE};
+
+    // Write a Fortran record with its length markers
+    fn write_fortran_record(output: &mut Vec<u8>, record: &[u8]) {
+        output.extend_from_slice(&(record.len() as u32).to_ne_bytes());
+        output.extend_from_slice(record);
+        output.extend_from_slice(&(record.len() as u32).to_ne_bytes());
+    }
+
+    // Rewrite an existing PACE file as a Type-2 table with 4-byte integers and XXS records of a given length
+    fn pace_to_type2(pace_path: &str, words_per_record: usize) -> Vec<u8> {
+        let mmap = PaceMmap::from_file(pace_path).unwrap();
+        let header = Header::from_PACE(&mmap).unwrap();
+
+        let mut first_record = Vec::new();
+        first_record.extend_from_slice(format!("{:<10}", header.zaid).as_bytes());
+        first_record.extend_from_slice(&header.atomic_mass_fraction.to_ne_bytes());
+        first_record.extend_from_slice(&header.kT.to_ne_bytes());
+        first_record.extend_from_slice(&[b' '; 10 + 70 + 10]);
+        for pair in mmap.izaw_bytes().chunks(16) {
+            first_record.extend_from_slice(&(i64::from_ne_bytes(pair[0..8].try_into().unwrap()) as i32).to_ne_bytes());
+            first_record.extend_from_slice(&pair[8..16]);
+        }
+        for &value in mmap.nxs_array().iter().chain(mmap.jxs_array()) {
+            first_record.extend_from_slice(&(value as i32).to_ne_bytes());
+        }
+
+        let mut output = Vec::new();
+        write_fortran_record(&mut output, &first_record);
+        for chunk in mmap.xxs_array().chunks(words_per_record) {
+            // The last record is padded to the full length
+            let mut record: Vec<u8> = chunk.iter().flat_map(|word| word.to_ne_bytes()).collect();
+            record.resize(words_per_record * 8, 0);
+            write_fortran_record(&mut output, &record);
+        }
+        output
+    }
+
+    #[tokio::test]
+    async fn test_pace_layout_validation() {
+        get_parsed_dosimetry_test_file().await;
+        let bytes = std::fs::read(*TEST_DOSIMETRY_PACE).unwrap();
+        let directory = tempdir().unwrap();
+        let path = directory.path().join("test.pace");
+        let layout_error = |bytes: &[u8]| {
+            std::fs::write(&path, bytes).unwrap();
+            match PaceMmap::from_file(&path) {
+                Ok(_) => None,
+                Err(error) => Some(error.downcast::<PaceError>().unwrap()),
+            }
+        };
+
+        assert!(layout_error(&bytes).is_none());
+        // Conversions interrupted partway through the preamble, the arrays or the XXS array
+        assert!(matches!(layout_error(&bytes[..40]), Some(PaceError::TruncatedFile(_))));
+        assert!(matches!(layout_error(&bytes[..400]), Some(PaceError::TruncatedFile(_))));
+        assert!(matches!(layout_error(&bytes[..bytes.len() - 4]), Some(PaceError::TruncatedFile(_))));
+        // More XXS words than the preamble and NXS(1) give
+        let extended = [bytes.as_slice(), &[0; 8]].concat();
+        assert!(matches!(layout_error(&extended), Some(PaceError::InvalidLayout(_))));
+    }
+
+    #[tokio::test]
+    async fn test_pace_preamble() {
+        let dosimetry = get_parsed_dosimetry_test_file().await;
+        let mmap = PaceMmap::from_file(*TEST_DOSIMETRY_PACE).unwrap();
+        assert_eq!(mmap.format_version(), PACE_FORMAT_VERSION);
+        assert_eq!(mmap.source_checksum(), source_checksum(*TEST_DOSIMETRY_ACE_UNCOMMENTED).unwrap());
+        assert_eq!(mmap.section_offsets().xxs_types, mmap.section_offsets().xxs + 8 * dosimetry.nxs_array.xxs_len);
+        assert_eq!(mmap.section_offsets().end - mmap.section_offsets().xxs_types, 8 * dosimetry.nxs_array.xxs_len.div_ceil(64));
+        // Integer words are flagged in the type bitmap and stored with their values
+        let xxs = mmap.xxs_array();
+        assert!((0..xxs.len()).any(|i| xxs.is_integer(i)));
+        assert!((0..xxs.len()).filter(|&i| xxs.is_integer(i)).all(|i| xxs[i].fract() == 0.0));
+        // Float words are not read as integers, even when they hold an integral value
+        let float_index = (0..xxs.len()).find(|&i| !xxs.is_integer(i)).unwrap();
+        assert!(matches!(xxs.int(float_index), Err(PaceError::FloatAsInteger { .. })));
+        assert!(is_pace_file(*TEST_DOSIMETRY_PACE).unwrap());
+        assert!(!is_pace_file(*TEST_DOSIMETRY_ACE_UNCOMMENTED).unwrap());
+
+        let bytes = std::fs::read(*TEST_DOSIMETRY_PACE).unwrap();
+        let directory = tempdir().unwrap();
+        let path = directory.path().join("test.pace");
+        let layout_error = |bytes: &[u8]| {
+            std::fs::write(&path, bytes).unwrap();
+            PaceMmap::from_file(&path).err().map(|error| error.downcast::<PaceError>().unwrap())
+        };
+
+        // A newer version of the format, and a file from a machine with the other byte order
+        let mut newer = bytes.clone();
+        newer[8..12].copy_from_slice(&(PACE_FORMAT_VERSION + 1).to_ne_bytes());
+        assert!(matches!(
+            layout_error(&newer),
+            Some(PaceError::UnsupportedVersion { found, supported: PACE_FORMAT_VERSION }) if found == PACE_FORMAT_VERSION + 1
+        ));
+        let mut swapped = bytes.clone();
+        swapped[12..16].copy_from_slice(&BYTE_ORDER_MARK.swap_bytes().to_ne_bytes());
+        assert!(matches!(layout_error(&swapped), Some(PaceError::ByteOrderMismatch)));
+
+        // A version 1 file, without the preamble or type bitmap and with integers stored as raw
+        // i64 bytes, is rejected until it is migrated
+        let offsets = mmap.section_offsets();
+        let mut legacy = bytes[offsets.header..offsets.xxs].to_vec();
+        for (i, &word) in mmap.xxs_array().iter().enumerate() {
+            match xxs.is_integer(i) {
+                true => legacy.extend_from_slice(&(word as i64).to_ne_bytes()),
+                false => legacy.extend_from_slice(&word.to_ne_bytes()),
+            }
+        }
+        assert!(matches!(layout_error(&legacy), Some(PaceError::MissingMagic)));
+        migrate_PACE(&path).unwrap();
+        let migrated = std::fs::read(&path).unwrap();
+        let migrated_mmap = PaceMmap::from_file(&path).unwrap();
+        assert_eq!(migrated_mmap.source_checksum(), 0);
+        assert_eq!(migrated_mmap.section_offsets(), offsets);
+        let migrated_xxs = migrated_mmap.xxs_array();
+        assert_eq!(migrated_xxs.words(), xxs.words());
+        assert!((0..offsets.xxs_length()).all(|i| !xxs.is_integer(i) || migrated_xxs.is_integer(i)));
+        assert_eq!(Header::from_PACE(&migrated_mmap).unwrap().zaid, dosimetry.zaid());
+        drop(migrated_mmap);
+
+        // Migrating an up to date file leaves it alone, and anything else is an error
+        migrate_PACE(&path).unwrap();
+        assert_eq!(std::fs::read(&path).unwrap(), migrated);
+        std::fs::write(&path, &legacy[..legacy.len() - 8]).unwrap();
+        assert!(migrate_PACE(&path).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_multi_table_conversion() {
+        let dosimetry = get_parsed_dosimetry_test_file().await;
+        let photonuclear = get_parsed_photonuclear_test_file().await;
+
+        // Concatenate two tables into a single library file
+        let directory = tempdir().unwrap();
+        let library_path = directory.path().join("library_ace");
+        let first_table = std::fs::read_to_string("test_nuclear_data_files/test_dosimetry_ace.no_comment").unwrap();
+        let second_table = std::fs::read_to_string("test_nuclear_data_files/test_photonuclear_ace.no_comment").unwrap();
+        std::fs::write(&library_path, format!("{first_table}{second_table}")).unwrap();
+
+        let output_paths = convert_all_ACE_to_PACE(&library_path).unwrap();
+        assert_eq!(output_paths.len(), 2);
+        let parsed_dosimetry = DosimetryData::from_file(&output_paths[0]).unwrap();
+        assert_eq!(parsed_dosimetry.szaid(), dosimetry.szaid());
+        assert_eq!(parsed_dosimetry.reaction_xs(102, 1.0), dosimetry.reaction_xs(102, 1.0));
+        let parsed_photonuclear = PhotonuclearData::from_file(&output_paths[1]).unwrap();
+        assert_eq!(parsed_photonuclear.szaid(), photonuclear.szaid());
+
+        // Select the second table by its address, as xsdir would give it
+        let address = first_table.lines().count() + 1;
+        let output_path = convert_ACE_to_PACE_at(&library_path, address).unwrap();
+        assert_eq!(output_path, output_paths[1]);
+        assert!(convert_ACE_to_PACE_at(&library_path, 100_000).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_type2_conversion() {
+        let dosimetry = get_parsed_dosimetry_test_file().await;
+
+        // Two copies of the same table, the second starting after the records of the first
+        let directory = tempdir().unwrap();
+        let library_path = directory.path().join("library_type2");
+        let table = pace_to_type2("test_nuclear_data_files/13027.800y.pace", 10);
+        std::fs::write(&library_path, [table.clone(), table].concat()).unwrap();
+
+        let second_record = 1 + (1 + dosimetry.nxs_array.xxs_len.div_ceil(10));
+        let output_path = convert_type2_ACE_to_PACE(&library_path, second_record).unwrap();
+        let parsed_ace = DosimetryData::from_file(&output_path).unwrap();
+        assert_eq!(parsed_ace.zaid(), dosimetry.zaid());
+        assert_eq!(parsed_ace.szaid(), None);
+        assert_eq!(parsed_ace.mtr, dosimetry.mtr);
+        for energy in [1.0E-11, 1.0, 5.0, 20.0] {
+            assert_eq!(parsed_ace.reaction_xs(102, energy), dosimetry.reaction_xs(102, energy));
+            assert_eq!(parsed_ace.reaction_xs(107, energy), dosimetry.reaction_xs(107, energy));
+        }
+
+        assert_eq!(convert_all_type2_ACE_to_PACE(&library_path).unwrap().len(), 2);
+        assert!(convert_type2_ACE_to_PACE(&library_path, 2 * second_record).is_err());
+    }
+}
@@ -2,9 +2,26 @@ mod binary_format;
 mod helper_functions;
 mod testing;
 
-pub use binary_format::PaceMmap;
+pub use binary_format::{
+    PaceMmap,
+    SectionOffsets,
+    PACE_FORMAT_VERSION,
+    is_pace_file,
+    ensure_not_ascii,
+    migrate_PACE,
+    source_checksum,
+    convert_ACE_to_PACE,
+    convert_ACE_to_PACE_at,
+    convert_all_ACE_to_PACE,
+    convert_type2_ACE_to_PACE,
+    convert_all_type2_ACE_to_PACE,
+};
+pub(crate) use binary_format::convert_table_to_PACE;
 
 pub use helper_functions::read_lines;
 pub use helper_functions::compute_temperature_from_kT;
 
-pub use testing::{is_ascii_file, get_parsed_test_file, local_get_parsed_test_file};
\ No newline at end of file
+pub use testing::is_ascii_file;
+#[cfg(test)]
+#[allow(unused_imports)]
+pub use testing::{get_parsed_test_file, get_parsed_thermal_test_file, get_parsed_photoatomic_test_file, get_parsed_electron_test_file, get_parsed_photonuclear_test_file, get_parsed_dosimetry_test_file, get_parsed_charged_particle_test_file, local_get_parsed_test_file};
\ No newline at end of file
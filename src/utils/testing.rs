@@ -15,6 +15,11 @@ use lazy_static::lazy_static;
 use anyhow::{Context, Result};
 
 use crate::pace_data::PaceData;
+use crate::thermal::ThermalScatteringData;
+use crate::photoatomic::PhotoatomicData;
+use crate::electron::ElectronData;
+use crate::photonuclear::PhotonuclearData;
+use crate::dosimetry::DosimetryData;
 use crate::utils::binary_format::convert_ACE_to_PACE;
 
 // These variables are used to hold filepaths in a way where
@@ -25,7 +30,40 @@ lazy_static! {
     pub static ref TEST_PACE_DATA: Mutex<Option<PaceData>> = Mutex::new(None);
     pub static ref TEST_ACE_COMMENTED: &'static str = "test_nuclear_data_files/test_ascii_ace";
     pub static ref TEST_ACE_UNCOMMENTED: &'static str = "test_nuclear_data_files/test_ascii_ace.no_comment";
-    pub static ref TEST_PACE: &'static str = "test_nuclear_data_files/binary_1100.800nc";
+    pub static ref TEST_PACE: &'static str = "test_nuclear_data_files/1100.800nc.pace";
+
+    // For custom thermal scattering ACE file available to all tests
+    pub static ref TEST_THERMAL_DATA: Mutex<Option<ThermalScatteringData>> = Mutex::new(None);
+    pub static ref TEST_THERMAL_ACE_COMMENTED: &'static str = "test_nuclear_data_files/test_thermal_ace";
+    pub static ref TEST_THERMAL_ACE_UNCOMMENTED: &'static str = "test_nuclear_data_files/test_thermal_ace.no_comment";
+    pub static ref TEST_THERMAL_PACE: &'static str = "test_nuclear_data_files/h-h2o.800nt.pace";
+
+    // For custom photoatomic ACE file available to all tests
+    pub static ref TEST_PHOTOATOMIC_DATA: Mutex<Option<PhotoatomicData>> = Mutex::new(None);
+    pub static ref TEST_PHOTOATOMIC_ACE_COMMENTED: &'static str = "test_nuclear_data_files/test_photoatomic_ace";
+    pub static ref TEST_PHOTOATOMIC_ACE_UNCOMMENTED: &'static str = "test_nuclear_data_files/test_photoatomic_ace.no_comment";
+    pub static ref TEST_PHOTOATOMIC_PACE: &'static str = "test_nuclear_data_files/1000.14p.pace";
+
+    // The electron data shares the photoatomic test file
+    pub static ref TEST_ELECTRON_DATA: Mutex<Option<ElectronData>> = Mutex::new(None);
+
+    // For custom photonuclear ACE file available to all tests
+    pub static ref TEST_PHOTONUCLEAR_DATA: Mutex<Option<PhotonuclearData>> = Mutex::new(None);
+    pub static ref TEST_PHOTONUCLEAR_ACE_COMMENTED: &'static str = "test_nuclear_data_files/test_photonuclear_ace";
+    pub static ref TEST_PHOTONUCLEAR_ACE_UNCOMMENTED: &'static str = "test_nuclear_data_files/test_photonuclear_ace.no_comment";
+    pub static ref TEST_PHOTONUCLEAR_PACE: &'static str = "test_nuclear_data_files/6012.800u.pace";
+
+    // For custom dosimetry ACE file available to all tests
+    pub static ref TEST_DOSIMETRY_DATA: Mutex<Option<DosimetryData>> = Mutex::new(None);
+    pub static ref TEST_DOSIMETRY_ACE_COMMENTED: &'static str = "test_nuclear_data_files/test_dosimetry_ace";
+    pub static ref TEST_DOSIMETRY_ACE_UNCOMMENTED: &'static str = "test_nuclear_data_files/test_dosimetry_ace.no_comment";
+    pub static ref TEST_DOSIMETRY_PACE: &'static str = "test_nuclear_data_files/13027.800y.pace";
+
+    // For custom incident charged-particle ACE file available to all tests
+    pub static ref TEST_CHARGED_PARTICLE_DATA: Mutex<Option<PaceData>> = Mutex::new(None);
+    pub static ref TEST_CHARGED_PARTICLE_ACE_COMMENTED: &'static str = "test_nuclear_data_files/test_charged_particle_ace";
+    pub static ref TEST_CHARGED_PARTICLE_ACE_UNCOMMENTED: &'static str = "test_nuclear_data_files/test_charged_particle_ace.no_comment";
+    pub static ref TEST_CHARGED_PARTICLE_PACE: &'static str = "test_nuclear_data_files/1003.800o.pace";
 
     // For local testing
     pub static ref LOCAL_TEST_PACE_DATA: Mutex<Option<PaceData>> = Mutex::new(None);
@@ -49,10 +87,10 @@ pub fn is_ascii_file<P: AsRef<Path>>(path: P) -> Result<bool> {
     }
 }
 
-// This function simply removes comments from the specially-constructed ASCII ACE test file
-fn uncomment_ace_test_file() -> Result<()> {
-    let commented_filename: &Path = Path::new(*TEST_ACE_COMMENTED);
-    let uncommented_filename: &Path = Path::new(*TEST_ACE_UNCOMMENTED);
+// This function simply removes comments from the specially-constructed ASCII ACE test files
+fn uncomment_ace_test_file(commented_filename: &str, uncommented_filename: &str) -> Result<()> {
+    let commented_filename: &Path = Path::new(commented_filename);
+    let uncommented_filename: &Path = Path::new(uncommented_filename);
     // Open test ASCII ACE
     let commented_file = File::open(commented_filename).unwrap();
     let reader = BufReader::new(commented_file);
@@ -109,7 +147,7 @@ pub async fn get_parsed_test_file() -> PaceData {
     // Only parse the ACE file if it is not already parsed
     if data.is_none() {
         // Convert the ACE file to PACE
-        uncomment_ace_test_file();
+        uncomment_ace_test_file(*TEST_ACE_COMMENTED, *TEST_ACE_UNCOMMENTED);
         let mut start = Instant::now();
         let _ = convert_ACE_to_PACE(*TEST_ACE_UNCOMMENTED);
         println!(
@@ -129,3 +167,108 @@ pub async fn get_parsed_test_file() -> PaceData {
     // Otherwise, return the already parsed data
     data.as_ref().unwrap().clone()
 }
+
+pub async fn get_parsed_thermal_test_file() -> ThermalScatteringData {
+    // As above, but for the thermal scattering test file
+    let mut data: std::sync::MutexGuard<'_, Option<ThermalScatteringData>> = TEST_THERMAL_DATA.lock().unwrap();
+
+    // Only parse the ACE file if it is not already parsed
+    if data.is_none() {
+        // Convert the ACE file to PACE
+        uncomment_ace_test_file(*TEST_THERMAL_ACE_COMMENTED, *TEST_THERMAL_ACE_UNCOMMENTED);
+        let _ = convert_ACE_to_PACE(*TEST_THERMAL_ACE_UNCOMMENTED);
+
+        // Parse the PACE file
+        let parsed_ace = ThermalScatteringData::from_file(*TEST_THERMAL_PACE).unwrap();
+        *data = Some(parsed_ace);
+    }
+    // Otherwise, return the already parsed data
+    data.as_ref().unwrap().clone()
+}
+
+pub async fn get_parsed_photoatomic_test_file() -> PhotoatomicData {
+    // As above, but for the photoatomic test file
+    let mut data: std::sync::MutexGuard<'_, Option<PhotoatomicData>> = TEST_PHOTOATOMIC_DATA.lock().unwrap();
+
+    // Only parse the ACE file if it is not already parsed
+    if data.is_none() {
+        // Convert the ACE file to PACE
+        uncomment_ace_test_file(*TEST_PHOTOATOMIC_ACE_COMMENTED, *TEST_PHOTOATOMIC_ACE_UNCOMMENTED);
+        let _ = convert_ACE_to_PACE(*TEST_PHOTOATOMIC_ACE_UNCOMMENTED);
+
+        // Parse the PACE file
+        let parsed_ace = PhotoatomicData::from_file(*TEST_PHOTOATOMIC_PACE).unwrap();
+        *data = Some(parsed_ace);
+    }
+    // Otherwise, return the already parsed data
+    data.as_ref().unwrap().clone()
+}
+
+pub async fn get_parsed_electron_test_file() -> ElectronData {
+    // As above, but for the electron data in the photoatomic test file. The photoatomic file is
+    // parsed first so that the shared PACE file is only converted once.
+    let _ = get_parsed_photoatomic_test_file().await;
+    let mut data: std::sync::MutexGuard<'_, Option<ElectronData>> = TEST_ELECTRON_DATA.lock().unwrap();
+
+    // Only parse the PACE file if it is not already parsed
+    if data.is_none() {
+        let parsed_ace = ElectronData::from_file(*TEST_PHOTOATOMIC_PACE).unwrap();
+        *data = Some(parsed_ace);
+    }
+    // Otherwise, return the already parsed data
+    data.as_ref().unwrap().clone()
+}
+
+pub async fn get_parsed_photonuclear_test_file() -> PhotonuclearData {
+    // As above, but for the photonuclear test file
+    let mut data: std::sync::MutexGuard<'_, Option<PhotonuclearData>> = TEST_PHOTONUCLEAR_DATA.lock().unwrap();
+
+    // Only parse the ACE file if it is not already parsed
+    if data.is_none() {
+        // Convert the ACE file to PACE
+        uncomment_ace_test_file(*TEST_PHOTONUCLEAR_ACE_COMMENTED, *TEST_PHOTONUCLEAR_ACE_UNCOMMENTED);
+        let _ = convert_ACE_to_PACE(*TEST_PHOTONUCLEAR_ACE_UNCOMMENTED);
+
+        // Parse the PACE file
+        let parsed_ace = PhotonuclearData::from_file(*TEST_PHOTONUCLEAR_PACE).unwrap();
+        *data = Some(parsed_ace);
+    }
+    // Otherwise, return the already parsed data
+    data.as_ref().unwrap().clone()
+}
+
+pub async fn get_parsed_dosimetry_test_file() -> DosimetryData {
+    // As above, but for the dosimetry test file
+    let mut data: std::sync::MutexGuard<'_, Option<DosimetryData>> = TEST_DOSIMETRY_DATA.lock().unwrap();
+
+    // Only parse the ACE file if it is not already parsed
+    if data.is_none() {
+        // Convert the ACE file to PACE
+        uncomment_ace_test_file(*TEST_DOSIMETRY_ACE_COMMENTED, *TEST_DOSIMETRY_ACE_UNCOMMENTED);
+        let _ = convert_ACE_to_PACE(*TEST_DOSIMETRY_ACE_UNCOMMENTED);
+
+        // Parse the PACE file
+        let parsed_ace = DosimetryData::from_file(*TEST_DOSIMETRY_PACE).unwrap();
+        *data = Some(parsed_ace);
+    }
+    // Otherwise, return the already parsed data
+    data.as_ref().unwrap().clone()
+}
+
+pub async fn get_parsed_charged_particle_test_file() -> PaceData {
+    // As above, but for the incident charged-particle test file
+    let mut data: std::sync::MutexGuard<'_, Option<PaceData>> = TEST_CHARGED_PARTICLE_DATA.lock().unwrap();
+
+    // Only parse the ACE file if it is not already parsed
+    if data.is_none() {
+        // Convert the ACE file to PACE
+        uncomment_ace_test_file(*TEST_CHARGED_PARTICLE_ACE_COMMENTED, *TEST_CHARGED_PARTICLE_ACE_UNCOMMENTED);
+        let _ = convert_ACE_to_PACE(*TEST_CHARGED_PARTICLE_ACE_UNCOMMENTED);
+
+        // Parse the PACE file
+        let parsed_ace = PaceData::from_file(*TEST_CHARGED_PARTICLE_PACE).await.unwrap();
+        *data = Some(parsed_ace);
+    }
+    // Otherwise, return the already parsed data
+    data.as_ref().unwrap().clone()
+}